@@ -290,11 +290,19 @@ async function createOffer() {
       systemProgram: SystemProgram.programId
     }
     const createOfferAndSendTokensToVaultInstruction = await program.methods.createOfferAndSendTokensToVault(
-      new BN(offerId1), // Offer ID
+      new BN(offerId1), // Offer ID, must be above the maker's previous offer ids
       new BN('500'), //amout of token_a for sale
       new BN('250'), //total amount of token_b expected
-      new BN('1765589361') //deadline as unix timestamp 
-    ).accounts(accounts)
+      new BN('1765589361'), //deadline as unix timestamp 
+      { restricted: {} }, // only whitelisted takers can take the offer
+      false, // keep the offer account open once it is filled
+      new BN('0'), // start time, 0 makes the offer takeable right away
+      new BN('0'), // minimum fill amount
+      false, // all or none
+      { vault: {} }, // tokens are held in the offer vault
+      null, // no acceptance window, fills settle immediately
+      null // no arbitrator
+    ).accountsPartial(accounts)
     // .signers([maker_wallet.payer])
     .instruction()
 
@@ -311,8 +319,8 @@ async function createOffer() {
     }
 
     const whitelistInstruction = await program.methods.manageWhitelist(
-      [taker_wallet.publicKey]
-    ).accounts(whitelistAccounts)
+      [{ taker: taker_wallet.publicKey, allocation: null }]
+    ).accountsPartial(whitelistAccounts)
     // .signers([maker_wallet.payer])
     .instruction();
 
@@ -322,8 +330,8 @@ async function createOffer() {
     ];
     await createAndSendV0Tx(instructions, maker_provider, maker_wallet.publicKey);
     const takerWhitelistFetched = await program.account.whitelist.fetch(whitelist);
-    takerWhitelistFetched.takers.forEach((taker: web3.PublicKey, index: number) => {
-      console.log(`Taker ${index + 1}:`, taker.toBase58());
+    takerWhitelistFetched.takers.forEach((entry, index: number) => {
+      console.log(`Taker ${index + 1}:`, entry.taker.toBase58());
     });
   } catch (error) {
     console.error("Error in creating offer:", error);
@@ -375,7 +383,8 @@ async function cancelOffer() {
       inputTokenMint: USDT,
       tokenProgram: TOKEN_2022_PROGRAM_ID,
     }
-    const cancelOfferInstruction = await program.methods.cancelOffer()
+    // The whitelist PDA is always passed so it is closed together with the offer
+    const cancelOfferInstruction = await program.methods.cancelOffer(false)
           .accountsPartial(accounts)
           .signers([maker_wallet.payer])
           .instruction();
@@ -410,9 +419,10 @@ async function manageWhitelistTakers() {
       systemProgram: SystemProgram.programId
     }
 
-    const addWhitelistTakersInstruction = await program.methods.manageWhitelist(
-      [taker_wallet.publicKey, new PublicKey("ApyERY2be2Qzm3M2Qhf2uofsmKjzUYgrbQjcoPaGxqbN")]
-    ).accounts(accounts)
+    const addWhitelistTakersInstruction = await program.methods.manageWhitelist([
+      { taker: taker_wallet.publicKey, allocation: null },
+      { taker: new PublicKey("ApyERY2be2Qzm3M2Qhf2uofsmKjzUYgrbQjcoPaGxqbN"), allocation: null },
+    ]).accountsPartial(accounts)
     // .signers([maker_wallet.payer])
     .instruction()
 
@@ -422,8 +432,8 @@ async function manageWhitelistTakers() {
     await createAndSendV0Tx(instructions, maker_provider, maker_wallet.publicKey);
     const takerWhitelistFetched = await program.account.whitelist.fetch(whitelist);
     console.log("Whitelisted Public Taker Keys:");
-    takerWhitelistFetched.takers.forEach((taker: web3.PublicKey, index: number) => {
-      console.log(`Taker ${index + 1}:`, taker.toBase58());
+    takerWhitelistFetched.takers.forEach((entry, index: number) => {
+      console.log(`Taker ${index + 1}:`, entry.taker.toBase58());
     });
   } catch (error) {
     console.log("Error in adding whitelist takers: ", error);
@@ -493,6 +503,9 @@ async function takeOffer() {
           offer: offer,
           maker: maker_wallet.publicKey,
           whitelist: whitelist,
+          takerFill: null,
+          instructionsSysvar: null,
+          fill: null,
       },
       token: {
           makerReceiveTokenAccount: makerRecieveTokenAccount,
@@ -500,6 +513,7 @@ async function takeOffer() {
           takerReceiveTokenAccount: takerReceiveTokenAccount,
           feeTokenAccount: feeTokenAccount,
           vaultTokenAccount: vaultTokenAccount,
+          paymentEscrowTokenAccount: null,
           inputTokenMint: USDT,
           outputTokenMint: USDC,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
@@ -513,8 +527,12 @@ async function takeOffer() {
   }
 
     const takeOfferInstruction = await program.methods.takeOffer(
-      new BN('300')
-    ).accounts(accounts).signers(
+      new BN('300'), // amount of token_a to take
+      new BN('150'), // maximum token_b payment to the maker
+      new BN('5'), // maximum token_b protocol fee
+      null, // no Merkle proof, the taker is on the offer whitelist
+      null // no allow ticket
+    ).accountsPartial(accounts).signers(
       [taker_wallet.payer]
     ).instruction()
 
@@ -13,6 +13,90 @@ export type Swap = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "acceptAdmin",
+      "discriminator": [
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "newAdmin",
+          "docs": [
+            "Proposed admin signer, pays for the roles registry on first use"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "PDA storing the admin"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA storing the role holders, roles held by the outgoing admin are revoked",
+            "Space breakdown in AdminRoles::SPACE"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
     {
       "name": "addMintsToWhitelist",
       "discriminator": [
@@ -29,15 +113,15 @@ export type Swap = {
         {
           "name": "admin",
           "docs": [
-            "Admin signer"
+            "Listing manager signer"
           ],
           "writable": true,
           "signer": true
         },
         {
-          "name": "adminConfig",
+          "name": "adminRoles",
           "docs": [
-            "PDA verifying admin authority"
+            "PDA verifying the signer holds the listing manager role"
           ],
           "pda": {
             "seeds": [
@@ -50,12 +134,11 @@ export type Swap = {
                   105,
                   110,
                   95,
-                  99,
+                  114,
                   111,
-                  110,
-                  102,
-                  105,
-                  103
+                  108,
+                  101,
+                  115
                 ]
               }
             ]
@@ -105,6 +188,210 @@ export type Swap = {
         }
       ]
     },
+    {
+      "name": "addTakers",
+      "discriminator": [
+        23,
+        110,
+        159,
+        240,
+        157,
+        232,
+        141,
+        53
+      ],
+      "accounts": [
+        {
+          "name": "maker",
+          "docs": [
+            "Original offer maker, must sign whitelist operations"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "whitelist",
+          "docs": [
+            "The whitelist PDA storing allowed takers",
+            "Created empty on first use with init_if_needed,",
+            "then resized to fit its takers with the maker paying the rent"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The offer this whitelist belongs to",
+            "Used to verify maker authority"
+          ]
+        },
+        {
+          "name": "takerWhitelistConfig",
+          "docs": [
+            "MAX_WHITELIST_TAKERS applies until the admin configures it"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  107,
+                  101,
+                  114,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "takers",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "whitelistEntry"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveAdminProposal",
+      "discriminator": [
+        69,
+        210,
+        165,
+        107,
+        217,
+        184,
+        67,
+        24
+      ],
+      "accounts": [
+        {
+          "name": "signer",
+          "docs": [
+            "Multisig member approving the proposal"
+          ],
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "PDA verifying multisig membership"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "docs": [
+            "Proposal being approved"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "adminProposal"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
     {
       "name": "cancelOffer",
       "docs": [
@@ -128,6 +415,7 @@ export type Swap = {
             "- Sign the cancellation transaction",
             "- Receive returned tokens",
             "- Receive rent from closed accounts",
+            "",
             "Must match the maker stored in the offer"
           ],
           "writable": true,
@@ -175,8 +463,7 @@ export type Swap = {
         {
           "name": "whitelist",
           "docs": [
-            "The whitelist PDA storing allowed takers",
-            "Will be closed and rent returned to maker",
+            "Public offers and offers without takers never create it",
             "",
             "Seeds: [\"whitelist\", maker_pubkey, offer_id]"
           ],
@@ -227,6 +514,7 @@ export type Swap = {
             "The vault token account holding the offered tokens",
             "Created as an Associated Token Account owned by offer PDA",
             "Will be closed after returning tokens",
+            "Only passed for vault offers",
             "",
             "Constraints:",
             "- Must be an ATA",
@@ -234,6 +522,7 @@ export type Swap = {
             "- Must match input token mint"
           ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -344,8 +633,256 @@ export type Swap = {
           "address": "11111111111111111111111111111111"
         }
       ],
+      "args": [
+        {
+          "name": "closeAccounts",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "cancelSignedOrder",
+      "discriminator": [
+        205,
+        211,
+        33,
+        42,
+        18,
+        8,
+        59,
+        94
+      ],
+      "accounts": [
+        {
+          "name": "maker",
+          "docs": [
+            "Maker who signed the order"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "orderNonces",
+          "docs": [
+            "Used nonces of the maker for the nonce's page",
+            "Seeds: [\"order_nonces\", maker, page]"
+          ],
+          "writable": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeOffer",
+      "discriminator": [
+        191,
+        72,
+        67,
+        35,
+        239,
+        209,
+        97,
+        132
+      ],
+      "accounts": [
+        {
+          "name": "maker",
+          "docs": [
+            "Original offer maker who receives the reclaimed rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The offer being closed",
+            "",
+            "Constraints:",
+            "- Must be signed by original maker",
+            "- Must be Completed, Cancelled or Expired",
+            "",
+            "Seeds: [\"offer\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "whitelist",
+          "docs": [
+            "",
+            "Seeds: [\"whitelist\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
       "args": []
     },
+    {
+      "name": "createAdminProposal",
+      "discriminator": [
+        225,
+        104,
+        68,
+        10,
+        114,
+        133,
+        212,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "proposer",
+          "docs": [
+            "Multisig member creating the proposal, pays for the proposal PDA"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "PDA verifying multisig membership and numbering proposals"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "proposal",
+          "docs": [
+            "PDA recording the proposed action and its approvals",
+            "Space breakdown in AdminProposal::space",
+            "",
+            "Seeds: [\"admin_proposal\", proposal_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "admin_config.proposal_count",
+                "account": "adminConfig"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "action",
+          "type": {
+            "defined": {
+              "name": "adminAction"
+            }
+          }
+        }
+      ]
+    },
     {
       "name": "createOfferAndSendTokensToVault",
       "discriminator": [
@@ -370,6 +907,40 @@ export type Swap = {
           "writable": true,
           "signer": true
         },
+        {
+          "name": "makerSequence",
+          "docs": [
+            "Offer ids used by the maker, rejects ids that were already used"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  97,
+                  107,
+                  101,
+                  114,
+                  95,
+                  115,
+                  101,
+                  113,
+                  117,
+                  101,
+                  110,
+                  99,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "maker"
+              }
+            ]
+          }
+        },
         {
           "name": "offer",
           "docs": [
@@ -385,9 +956,22 @@ export type Swap = {
             "- 8 bytes for token amount remaining",
             "- 8 bytes for expected fulfilled amount",
             "- 8 bytes deadline",
+            "- 8 bytes start time",
             "- 1 byte offer status",
             "- 8 bytes fee percentage",
-            "- 32 bytes fee wallet"
+            "- 32 bytes fee wallet",
+            "- 1 byte offer visibility",
+            "- 1 byte close on completion flag",
+            "- 8 bytes minimum fill amount",
+            "- 1 byte all or none flag",
+            "- 33 bytes optional taker Merkle root",
+            "- 33 bytes optional ticket approver",
+            "- 1 byte offer custody",
+            "- 9 bytes optional acceptance window",
+            "- 8 bytes fill count",
+            "- 8 bytes pending fills",
+            "- 33 bytes optional arbitrator",
+            "- 8 bytes expiry bounty"
           ],
           "writable": true,
           "pda": {
@@ -462,33 +1046,120 @@ export type Swap = {
           }
         },
         {
-          "name": "makerTokenAccount",
-          "docs": [
-            "Maker's token account containing tokens to be offered",
-            "Must match the input token mint"
-          ],
-          "writable": true
-        },
-        {
-          "name": "vaultTokenAccount",
-          "docs": [
-            "Vault token account created as an Associated Token Account",
-            "Will hold the offered tokens until trade completion",
-            "Authority is the offer PDA"
-          ],
-          "writable": true,
+          "name": "expiryConfig",
           "pda": {
             "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  112,
+                  105,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "whitelistConfig",
+          "docs": [
+            "Whitelist configuration deciding if mint whitelist is enforced"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintWhitelist",
+          "docs": [
+            "Mint whitelist both offer mints are checked against"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "makerTokenAccount",
+          "docs": [
+            "Maker's token account containing tokens to be offered",
+            "Must match the input token mint"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vaultTokenAccount",
+          "docs": [
+            "Vault token account created as an Associated Token Account",
+            "Will hold the offered tokens until trade completion",
+            "Authority is the offer PDA",
+            "Only passed for vault offers, delegated offers keep the tokens in maker_token_account"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
               {
                 "kind": "account",
-                "path": "offer"
-              },
-              {
-                "kind": "account",
-                "path": "tokenProgram"
-              },
-              {
-                "kind": "account",
                 "path": "inputTokenMint"
               }
             ],
@@ -544,82 +1215,16 @@ export type Swap = {
           ]
         },
         {
-          "name": "tokenProgram",
-          "docs": [
-            "Token interface program for Token-2022 support"
-          ]
-        },
-        {
-          "name": "associatedTokenProgram",
-          "docs": [
-            "Required for ATA initialization"
-          ],
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "offerId",
-          "type": "u64"
-        },
-        {
-          "name": "tokenAmount",
-          "type": "u64"
-        },
-        {
-          "name": "expectedTotalAmount",
-          "type": "u64"
-        },
-        {
-          "name": "deadline",
-          "type": "i64"
-        }
-      ]
-    },
-    {
-      "name": "initializeAdmin",
-      "discriminator": [
-        35,
-        176,
-        8,
-        143,
-        42,
-        160,
-        61,
-        158
-      ],
-      "accounts": [
-        {
-          "name": "admin",
-          "docs": [
-            "The admin account that will control protocol settings"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "adminConfig",
-          "docs": [
-            "PDA storing admin details and protocol statistics",
-            "Space breakdown:",
-            "- 8 bytes discriminator",
-            "- 32 bytes admin pubkey"
-          ],
-          "writable": true,
+          "name": "inputMintConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  97,
-                  100,
                   109,
                   105,
                   110,
+                  116,
                   95,
                   99,
                   111,
@@ -628,158 +1233,207 @@ export type Swap = {
                   105,
                   103
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
               }
             ]
           }
         },
         {
-          "name": "feeConfig",
-          "docs": [
-            "PDA storing fee configuration",
-            "Space breakdown:",
-            "- 8 bytes discriminator",
-            "- 8 bytes fee percentage",
-            "- 32 bytes fee wallet address"
-          ],
-          "writable": true,
+          "name": "outputMintConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
                   102,
-                  101,
-                  101
+                  105,
+                  103
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
               }
             ]
           }
         },
         {
-          "name": "whitelistConfig",
-          "docs": [
-            "PDA storing whitelist enforcement configuration",
-            "Space breakdown:",
-            "- 8 bytes discriminator",
-            "- 1 byte boolean flag"
-          ],
-          "writable": true,
+          "name": "inputListedMint",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  119,
-                  104,
-                  105,
-                  116,
-                  101,
                   108,
                   105,
                   115,
                   116,
+                  101,
+                  100,
                   95,
-                  99,
-                  111,
-                  110,
-                  102,
+                  109,
                   105,
-                  103
+                  110,
+                  116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
               }
             ]
           }
         },
         {
-          "name": "mintWhitelist",
-          "docs": [
-            "PDA storing whitelisted token mints",
-            "Space breakdown:",
-            "- 8 bytes discriminator",
-            "- 4 bytes vector length",
-            "- 32 * 50 bytes for pubkeys (max 50 mints)"
-          ],
-          "writable": true,
+          "name": "outputListedMint",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  109,
-                  105,
-                  110,
-                  116,
-                  95,
-                  119,
-                  104,
+                  108,
                   105,
+                  115,
                   116,
                   101,
-                  108,
+                  100,
+                  95,
+                  109,
                   105,
-                  115,
+                  110,
                   116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
               }
             ]
           }
         },
         {
-          "name": "systemProgram",
+          "name": "tokenProgram",
           "docs": [
-            "Global PDA for tracking maker sequences"
-          ],
-          "address": "11111111111111111111111111111111"
-        }
+            "Token interface program for Token-2022 support"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "docs": [
+            "Required for ATA initialization"
+          ],
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
       ],
       "args": [
         {
-          "name": "feePercentage",
+          "name": "offerId",
           "type": "u64"
         },
         {
-          "name": "feeWallet",
-          "type": "pubkey"
+          "name": "tokenAmount",
+          "type": "u64"
         },
         {
-          "name": "requireWhitelist",
+          "name": "expectedTotalAmount",
+          "type": "u64"
+        },
+        {
+          "name": "deadline",
+          "type": "i64"
+        },
+        {
+          "name": "visibility",
+          "type": {
+            "defined": {
+              "name": "offerVisibility"
+            }
+          }
+        },
+        {
+          "name": "closeOnCompletion",
           "type": "bool"
         },
         {
-          "name": "initialMints",
+          "name": "startTime",
+          "type": "i64"
+        },
+        {
+          "name": "minFillAmount",
+          "type": "u64"
+        },
+        {
+          "name": "allOrNone",
+          "type": "bool"
+        },
+        {
+          "name": "custody",
           "type": {
-            "vec": "pubkey"
+            "defined": {
+              "name": "offerCustody"
+            }
+          }
+        },
+        {
+          "name": "acceptanceWindow",
+          "type": {
+            "option": "i64"
+          }
+        },
+        {
+          "name": "arbitrator",
+          "type": {
+            "option": "pubkey"
           }
         }
       ]
     },
     {
-      "name": "manageWhitelist",
+      "name": "decreaseOffer",
       "discriminator": [
-        252,
-        197,
-        13,
-        121,
-        14,
-        54,
-        39,
-        93
+        115,
+        220,
+        138,
+        163,
+        163,
+        21,
+        9,
+        32
       ],
       "accounts": [
         {
           "name": "maker",
           "docs": [
-            "Original offer maker, must sign whitelist operations"
+            "Original offer maker who sends or receives the tokens"
           ],
           "writable": true,
           "signer": true
         },
         {
-          "name": "whitelist",
+          "name": "offer",
           "docs": [
-            "The whitelist PDA storing allowed takers",
-            "Created on first use with init_if_needed",
-            "Space for up to 50 taker addresses"
+            "The offer being adjusted",
+            "",
+            "Constraints:",
+            "- Must be signed by original maker",
+            "- Must be in Ongoing status",
+            "",
+            "Seeds: [\"offer\", maker_pubkey, offer_id]"
           ],
           "writable": true,
           "pda": {
@@ -787,15 +1441,11 @@ export type Swap = {
               {
                 "kind": "const",
                 "value": [
-                  119,
-                  104,
-                  105,
-                  116,
+                  111,
+                  102,
+                  102,
                   101,
-                  108,
-                  105,
-                  115,
-                  116
+                  114
                 ]
               },
               {
@@ -811,51 +1461,128 @@ export type Swap = {
           }
         },
         {
-          "name": "offer",
+          "name": "makerTokenAccount",
           "docs": [
-            "The offer this whitelist belongs to",
-            "Used to verify maker authority"
+            "Maker's token account for the input token",
+            "Source of top-ups and destination of withdrawals"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vaultTokenAccount",
+          "docs": [
+            "The vault token account holding the offered tokens",
+            "Authority is the offer PDA",
+            "Only passed for vault offers"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "inputTokenMint",
+          "docs": [
+            "Input token mint (token being offered)"
           ]
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "tokenProgram",
+          "docs": [
+            "Token interface program for Token-2022 support"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "docs": [
+            "Required for ATA validation"
+          ],
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         }
       ],
       "args": [
         {
-          "name": "takers",
-          "type": {
-            "vec": "pubkey"
-          }
+          "name": "amount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "removeMintsFromWhitelist",
+      "name": "delistMint",
       "discriminator": [
-        214,
-        232,
-        203,
-        229,
-        255,
-        239,
-        201,
-        97
+        106,
+        152,
+        81,
+        164,
+        163,
+        41,
+        126,
+        215
       ],
       "accounts": [
         {
           "name": "admin",
           "docs": [
-            "Admin signer"
+            "Listing manager signer, receives the listing rent"
           ],
           "writable": true,
           "signer": true
         },
         {
-          "name": "adminConfig",
+          "name": "adminRoles",
           "docs": [
-            "PDA verifying admin authority"
+            "PDA verifying the signer holds the listing manager role"
           ],
           "pda": {
             "seeds": [
@@ -868,21 +1595,20 @@ export type Swap = {
                   105,
                   110,
                   95,
-                  99,
+                  114,
                   111,
-                  110,
-                  102,
-                  105,
-                  103
+                  108,
+                  101,
+                  115
                 ]
               }
             ]
           }
         },
         {
-          "name": "mintWhitelist",
+          "name": "listedMint",
           "docs": [
-            "Mint whitelist to modify"
+            "Listing being closed"
           ],
           "writable": true,
           "pda": {
@@ -890,1073 +1616,9373 @@ export type Swap = {
               {
                 "kind": "const",
                 "value": [
-                  109,
-                  105,
-                  110,
-                  116,
-                  95,
-                  119,
-                  104,
+                  108,
                   105,
+                  115,
                   116,
                   101,
-                  108,
+                  100,
+                  95,
+                  109,
                   105,
-                  115,
+                  110,
                   116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "listed_mint.mint",
+                "account": "listedMint"
               }
             ]
           }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
         }
       ],
-      "args": [
-        {
-          "name": "removeMints",
-          "type": {
-            "vec": "pubkey"
-          }
-        }
-      ]
+      "args": []
     },
     {
-      "name": "takeOffer",
+      "name": "enableAdminMultisig",
       "discriminator": [
-        128,
-        156,
-        242,
-        207,
-        237,
-        192,
-        103,
-        240
+        80,
+        13,
+        170,
+        54,
+        79,
+        97,
+        51,
+        25
       ],
       "accounts": [
         {
-          "name": "core",
-          "accounts": [
-            {
-              "name": "taker",
-              "docs": [
-                "Transaction signer who will take the offer.",
-                "Responsible for:",
-                "- Paying for account initialization",
-                "- Sending payment tokens",
-                "- Paying protocol fees",
-                "- Receiving offered tokens"
-              ],
-              "writable": true,
-              "signer": true
-            },
-            {
-              "name": "adminConfig",
-              "docs": [
-                "Protocol admin configuration.",
-                "Tracks global settings and statistics.",
-                "PDA with seeds: [\"admin_config\"]"
-              ],
-              "writable": true,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "value": [
-                      97,
-                      100,
-                      109,
-                      105,
-                      110,
-                      95,
-                      99,
-                      111,
-                      110,
-                      102,
-                      105,
-                      103
-                    ]
-                  }
+          "name": "admin",
+          "docs": [
+            "Current admin signer, pays for the roles registry on first use"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "PDA storing the multisig signers and threshold"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
               }
-            },
-            {
-              "name": "offer",
-              "docs": [
-                "The offer being taken.",
-                "PDA with seeds: [\"offer\", maker_pubkey, offer_id]",
-                "Constraints:",
-                "- Must be in Ongoing status",
-                "- Closed on completion with rent returned to maker"
-              ],
-              "writable": true,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "value": [
-                      111,
-                      102,
-                      102,
-                      101,
-                      114
-                    ]
-                  },
-                  {
-                    "kind": "account",
-                    "path": "offer.maker",
-                    "account": "offer"
-                  },
-                  {
-                    "kind": "account",
-                    "path": "offer.offer_id",
-                    "account": "offer"
-                  }
+            ]
+          }
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA storing the role holders, every role is revoked",
+            "Space breakdown in AdminRoles::SPACE"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
                 ]
               }
-            },
-            {
-              "name": "maker",
-              "writable": true
-            },
-            {
-              "name": "whitelist",
-              "docs": [
-                "Whitelist of authorized takers.",
-                "PDA with seeds: [\"whitelist\", maker_pubkey, offer_id]",
-                "Constraints:",
-                "- Must include taker's public key",
-                "- Closed on offer completion"
-              ],
-              "writable": true,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "const",
-                    "value": [
-                      119,
-                      104,
-                      105,
-                      116,
-                      101,
-                      108,
-                      105,
-                      115,
-                      116
-                    ]
-                  },
-                  {
-                    "kind": "account",
-                    "path": "offer.maker",
-                    "account": "offer"
-                  },
-                  {
-                    "kind": "account",
-                    "path": "offer.offer_id",
-                    "account": "offer"
-                  }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "signers",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "executeAdminProposal",
+      "discriminator": [
+        157,
+        50,
+        31,
+        216,
+        56,
+        72,
+        129,
+        159
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "docs": [
+            "Multisig member executing the proposal",
+            "Pays for the taker whitelist config on first use"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "PDA verifying multisig membership and threshold"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
               }
-            }
-          ]
+            ]
+          }
         },
         {
-          "name": "token",
-          "accounts": [
-            {
-              "name": "makerReceiveTokenAccount",
-              "docs": [
-                "Maker's token account for receiving payment.",
-                "Automatically created as an ATA if it doesn't exist."
-              ],
-              "writable": true,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "account",
-                    "path": "maker"
-                  },
-                  {
-                    "kind": "account",
-                    "path": "tokenProgram"
-                  },
-                  {
-                    "kind": "account",
-                    "path": "outputTokenMint"
-                  }
-                ],
-                "program": {
-                  "kind": "const",
-                  "value": [
-                    140,
-                    151,
-                    37,
-                    143,
-                    78,
-                    36,
-                    137,
-                    241,
-                    187,
-                    61,
-                    16,
-                    41,
-                    20,
-                    142,
-                    13,
-                    131,
-                    11,
-                    90,
-                    19,
-                    153,
-                    218,
-                    255,
-                    16,
-                    132,
-                    4,
-                    142,
-                    123,
-                    216,
-                    219,
-                    233,
-                    248,
-                    89
-                  ]
-                }
-              }
-            },
-            {
-              "name": "takerPaymentTokenAccount",
-              "docs": [
-                "Taker's token account for sending payment.",
-                "Automatically created as an ATA if it doesn't exist."
-              ],
-              "writable": true
-            },
-            {
-              "name": "takerReceiveTokenAccount",
-              "docs": [
-                "Taker's token account for receiving offered tokens.",
-                "Automatically created as an ATA if it doesn't exist."
-              ],
-              "writable": true,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "account",
-                    "path": "taker"
-                  },
-                  {
-                    "kind": "account",
-                    "path": "tokenProgram"
-                  },
-                  {
-                    "kind": "account",
-                    "path": "inputTokenMint"
-                  }
-                ],
-                "program": {
-                  "kind": "const",
-                  "value": [
-                    140,
-                    151,
-                    37,
-                    143,
-                    78,
-                    36,
-                    137,
-                    241,
-                    187,
-                    61,
-                    16,
-                    41,
-                    20,
-                    142,
-                    13,
-                    131,
-                    11,
-                    90,
-                    19,
-                    153,
-                    218,
-                    255,
-                    16,
-                    132,
-                    4,
-                    142,
-                    123,
-                    216,
-                    219,
-                    233,
-                    248,
-                    89
-                  ]
-                }
+          "name": "proposal",
+          "docs": [
+            "Proposal being executed"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  112,
+                  114,
+                  111,
+                  112,
+                  111,
+                  115,
+                  97,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "proposal.proposal_id",
+                "account": "adminProposal"
               }
-            },
-            {
-              "name": "feeTokenAccount",
-              "docs": [
-                "Protocol fee receiving account.",
-                "Constraints:",
-                "- Must match configured fee wallet",
-                "- Must use correct token mint"
-              ],
-              "writable": true,
-              "pda": {
-                "seeds": [
-                  {
-                    "kind": "account",
-                    "path": "feeWallet"
-                  },
-                  {
-                    "kind": "account",
-                    "path": "tokenProgram"
-                  },
-                  {
-                    "kind": "account",
-                    "path": "outputTokenMint"
-                  }
-                ],
-                "program": {
-                  "kind": "const",
-                  "value": [
-                    140,
-                    151,
-                    37,
-                    143,
-                    78,
-                    36,
-                    137,
-                    241,
-                    187,
-                    61,
-                    16,
-                    41,
-                    20,
-                    142,
-                    13,
-                    131,
-                    11,
-                    90,
-                    19,
-                    153,
-                    218,
-                    255,
-                    16,
-                    132,
-                    4,
-                    142,
-                    123,
-                    216,
-                    219,
-                    233,
-                    248,
-                    89
-                  ]
-                }
+            ]
+          }
+        },
+        {
+          "name": "feeConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101
+                ]
               }
-            },
-            {
-              "name": "vaultTokenAccount",
-              "docs": [
-                "Vault holding the offered tokens.",
-                "Constraints:",
-                "- Must be owned by offer PDA",
-                "- Must match input token mint"
-              ],
-              "writable": true
-            },
-            {
-              "name": "inputTokenMint",
-              "docs": [
-                "Mint of the token being offered"
-              ]
-            },
-            {
-              "name": "outputTokenMint",
-              "docs": [
-                "Mint of the token being requested"
-              ]
-            },
-            {
-              "name": "tokenProgram",
-              "docs": [
-                "Required program interfaces"
-              ]
-            },
-            {
-              "name": "associatedTokenProgram",
-              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
-            },
-            {
-              "name": "systemProgram",
-              "address": "11111111111111111111111111111111"
-            },
-            {
-              "name": "feeWallet"
-            },
-            {
-              "name": "taker",
-              "writable": true
-            },
-            {
-              "name": "maker"
-            },
-            {
-              "name": "offer"
-            }
-          ]
-        }
-      ],
-      "args": [
+            ]
+          }
+        },
         {
-          "name": "inputTokenAmount",
-          "type": "u64"
-        }
-      ]
+          "name": "whitelistConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintWhitelist",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "takerWhitelistConfig",
+          "docs": [
+            "PDA storing taker whitelist limits",
+            "Only required for UpdateMaxWhitelistTakers proposals",
+            "Space breakdown:",
+            "- 8 bytes discriminator",
+            "- 4 bytes maximum takers"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  107,
+                  101,
+                  114,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "expiryConfig",
+          "docs": [
+            "PDA storing the expiry bounty",
+            "Only required for UpdateExpiryBounty proposals",
+            "Space breakdown:",
+            "- 8 bytes discriminator",
+            "- 8 bytes expiry bounty"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  112,
+                  105,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA storing the role holders",
+            "Only required for GrantRole and RevokeRole proposals",
+            "Space breakdown in AdminRoles::SPACE"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "expireOffer",
+      "discriminator": [
+        161,
+        133,
+        120,
+        51,
+        0,
+        201,
+        224,
+        248
+      ],
+      "accounts": [
+        {
+          "name": "cranker",
+          "docs": [
+            "Anyone calling the crank, pays for token account creation",
+            "and receives the expiry bounty if one is configured"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The expired offer",
+            "",
+            "Constraints:",
+            "- Must be in Ongoing status",
+            "",
+            "Seeds: [\"offer\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.maker",
+                "account": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "maker",
+          "writable": true
+        },
+        {
+          "name": "makerTokenAccount",
+          "docs": [
+            "Maker's token account receiving the returned tokens",
+            "For delegated offers, the account whose delegation to the offer is revoked",
+            "Automatically created as an ATA if it doesn't exist"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "crankerTokenAccount",
+          "docs": [
+            "Cranker's token account receiving the bounty",
+            "Automatically created as an ATA if it doesn't exist"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "cranker"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "docs": [
+            "The vault token account holding the offered tokens",
+            "Closed after returning tokens",
+            "Only passed for vault offers"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "inputTokenMint",
+          "docs": [
+            "The mint of the token being returned"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "Token interface program for Token-2022 support"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "docs": [
+            "Required for ATA initialization"
+          ],
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "fillSignedOrder",
+      "discriminator": [
+        180,
+        172,
+        68,
+        193,
+        225,
+        150,
+        228,
+        174
+      ],
+      "accounts": [
+        {
+          "name": "taker",
+          "docs": [
+            "Taker filling the order, pays for account initialization"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "maker"
+        },
+        {
+          "name": "orderDelegate",
+          "docs": [
+            "Seeds: [\"order_delegate\", maker]"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  114,
+                  100,
+                  101,
+                  114,
+                  95,
+                  100,
+                  101,
+                  108,
+                  101,
+                  103,
+                  97,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "maker"
+              }
+            ]
+          }
+        },
+        {
+          "name": "orderNonces",
+          "docs": [
+            "Used nonces of the maker for the order's nonce page",
+            "Seeds: [\"order_nonces\", maker, page]"
+          ],
+          "writable": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "Admin configuration providing the take pause flag"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeConfig",
+          "docs": [
+            "Fee configuration providing the fee percentage and wallet"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "whitelistConfig",
+          "docs": [
+            "Whitelist configuration deciding if mint whitelist is enforced"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintWhitelist",
+          "docs": [
+            "Mint whitelist, checked like for vault offers"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "makerTokenAccount",
+          "docs": [
+            "Maker's token account the input tokens are taken from",
+            "",
+            "Constraints:",
+            "- Must be owned by maker",
+            "- Must match the order's input mint"
+          ],
+          "writable": true
+        },
+        {
+          "name": "makerReceiveTokenAccount",
+          "docs": [
+            "Maker's token account for receiving payment",
+            "Automatically created as an ATA if it doesn't exist"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "takerPaymentTokenAccount",
+          "docs": [
+            "Taker's token account for sending payment"
+          ],
+          "writable": true
+        },
+        {
+          "name": "takerReceiveTokenAccount",
+          "docs": [
+            "Taker's token account for receiving the input tokens",
+            "Automatically created as an ATA if it doesn't exist"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "taker"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "feeTokenAccount",
+          "docs": [
+            "Protocol fee receiving account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "feeWallet"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "feeWallet"
+        },
+        {
+          "name": "inputTokenMint",
+          "docs": [
+            "Mint of the token being sold"
+          ]
+        },
+        {
+          "name": "outputTokenMint",
+          "docs": [
+            "Mint of the token being requested"
+          ]
+        },
+        {
+          "name": "inputMintConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "outputMintConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "inputListedMint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "outputListedMint",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "instructionsSysvar",
+          "address": "Sysvar1nstructions1111111111111111111111111"
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "Required program interfaces"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "order",
+          "type": {
+            "defined": {
+              "name": "signedOrder"
+            }
+          }
+        },
+        {
+          "name": "maxFeeAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "grantRole",
+      "discriminator": [
+        218,
+        234,
+        128,
+        15,
+        82,
+        33,
+        236,
+        253
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Super-admin signer, pays for the roles registry on first use"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "PDA verifying super-admin authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA storing the role holders",
+            "Created on first use for configs initialized before roles existed",
+            "Space breakdown in AdminRoles::SPACE"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "adminRole"
+            }
+          }
+        },
+        {
+          "name": "holder",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "increaseOffer",
+      "discriminator": [
+        230,
+        8,
+        168,
+        85,
+        58,
+        135,
+        35,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "maker",
+          "docs": [
+            "Original offer maker who sends or receives the tokens"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The offer being adjusted",
+            "",
+            "Constraints:",
+            "- Must be signed by original maker",
+            "- Must be in Ongoing status",
+            "",
+            "Seeds: [\"offer\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "makerTokenAccount",
+          "docs": [
+            "Maker's token account for the input token",
+            "Source of top-ups and destination of withdrawals"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vaultTokenAccount",
+          "docs": [
+            "The vault token account holding the offered tokens",
+            "Authority is the offer PDA",
+            "Only passed for vault offers"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "inputTokenMint",
+          "docs": [
+            "Input token mint (token being offered)"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "Token interface program for Token-2022 support"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "docs": [
+            "Required for ATA validation"
+          ],
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initializeAdmin",
+      "discriminator": [
+        35,
+        176,
+        8,
+        143,
+        42,
+        160,
+        61,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "The admin account that will control protocol settings"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "PDA storing admin details and protocol statistics",
+            "Space breakdown in AdminConfig::SPACE"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeConfig",
+          "docs": [
+            "PDA storing fee configuration",
+            "Space breakdown:",
+            "- 8 bytes discriminator",
+            "- 8 bytes fee percentage",
+            "- 32 bytes fee wallet address"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "whitelistConfig",
+          "docs": [
+            "PDA storing whitelist enforcement configuration",
+            "Space breakdown:",
+            "- 8 bytes discriminator",
+            "- 1 byte boolean flag"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintWhitelist",
+          "docs": [
+            "PDA storing whitelisted token mints",
+            "Space breakdown:",
+            "- 8 bytes discriminator",
+            "- 4 bytes vector length",
+            "- 32 * 50 bytes for pubkeys (max 50 mints)"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA storing the holders of the delegated admin roles",
+            "Space breakdown in AdminRoles::SPACE"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "docs": [
+            "Global PDA for tracking maker sequences"
+          ],
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "feePercentage",
+          "type": "u64"
+        },
+        {
+          "name": "feeWallet",
+          "type": "pubkey"
+        },
+        {
+          "name": "requireWhitelist",
+          "type": "bool"
+        },
+        {
+          "name": "initialMints",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "listMint",
+      "discriminator": [
+        16,
+        60,
+        25,
+        233,
+        125,
+        230,
+        218,
+        100
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Listing manager signer, pays for the listing"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA verifying the signer holds the listing manager role"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint being listed"
+          ]
+        },
+        {
+          "name": "listedMint",
+          "docs": [
+            "PDA storing the listing",
+            "Space breakdown:",
+            "- 8 bytes discriminator",
+            "- 32 bytes mint pubkey",
+            "- 8 bytes listing time",
+            "- 32 bytes listing manager pubkey",
+            "- 8 bytes minimum trade amount",
+            "- 8 bytes maximum trade amount"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  108,
+                  105,
+                  115,
+                  116,
+                  101,
+                  100,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "minTradeAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxTradeAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "manageWhitelist",
+      "discriminator": [
+        252,
+        197,
+        13,
+        121,
+        14,
+        54,
+        39,
+        93
+      ],
+      "accounts": [
+        {
+          "name": "maker",
+          "docs": [
+            "Original offer maker, must sign whitelist operations"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "whitelist",
+          "docs": [
+            "The whitelist PDA storing allowed takers",
+            "Created empty on first use with init_if_needed,",
+            "then resized to fit its takers with the maker paying the rent"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The offer this whitelist belongs to",
+            "Used to verify maker authority"
+          ]
+        },
+        {
+          "name": "takerWhitelistConfig",
+          "docs": [
+            "MAX_WHITELIST_TAKERS applies until the admin configures it"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  107,
+                  101,
+                  114,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "takers",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "whitelistEntry"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "migrateAdminConfig",
+      "discriminator": [
+        86,
+        172,
+        10,
+        123,
+        55,
+        3,
+        232,
+        199
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Admin signer, pays for the extra rent"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "its discriminator and admin are checked in the handler"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pause",
+      "discriminator": [
+        211,
+        22,
+        221,
+        251,
+        74,
+        121,
+        193,
+        47
+      ],
+      "accounts": [
+        {
+          "name": "pauser",
+          "docs": [
+            "Pauser signer"
+          ],
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "PDA storing the pause flags"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA verifying the signer holds the pauser role"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "create",
+          "type": "bool"
+        },
+        {
+          "name": "take",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "proposeAdmin",
+      "discriminator": [
+        121,
+        214,
+        199,
+        212,
+        87,
+        39,
+        117,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Current admin signer"
+          ],
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "PDA storing the pending admin"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "raiseDispute",
+      "discriminator": [
+        41,
+        243,
+        1,
+        51,
+        150,
+        95,
+        246,
+        73
+      ],
+      "accounts": [
+        {
+          "name": "party",
+          "docs": [
+            "The maker or the taker of the fill"
+          ],
+          "signer": true
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The offer the fill was made on",
+            "",
+            "Seeds: [\"offer\", maker_pubkey, offer_id]"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.maker",
+                "account": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "fill",
+          "docs": [
+            "The escrowed fill being disputed",
+            "",
+            "Seeds: [\"fill\", offer, fill_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  105,
+                  108,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "fill.fill_id",
+                "account": "fill"
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "rejectFill",
+      "discriminator": [
+        194,
+        55,
+        209,
+        141,
+        84,
+        183,
+        141,
+        82
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "The maker rejecting the fill, or anyone once its acceptance deadline has passed",
+            "Pays for the taker's refund account if it doesn't exist"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The offer the fill was made on",
+            "",
+            "Seeds: [\"offer\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.maker",
+                "account": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "fill",
+          "docs": [
+            "The escrowed fill, closed with rent returned to the taker",
+            "",
+            "Seeds: [\"fill\", offer, fill_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  105,
+                  108,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "fill.fill_id",
+                "account": "fill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "maker",
+          "writable": true
+        },
+        {
+          "name": "taker",
+          "writable": true
+        },
+        {
+          "name": "whitelist",
+          "docs": [
+            "The allocation a fill used up is given back for the tokens the taker doesn't receive,",
+            "and the whitelist closes with an offer that closes on completion",
+            "",
+            "Seeds: [\"whitelist\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.maker",
+                "account": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "takerFill",
+          "docs": [
+            "Cumulative fills of the taker on the offer",
+            "Required when the fill was recorded in it",
+            "",
+            "Seeds: [\"taker_fill\", offer, taker]"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  107,
+                  101,
+                  114,
+                  95,
+                  102,
+                  105,
+                  108,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "fill.taker",
+                "account": "fill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "docs": [
+            "The vault holding the reserved input tokens, closed with the offer's last fill"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "paymentEscrowTokenAccount",
+          "docs": [
+            "The offer account holding the escrowed payments"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "takerRefundTokenAccount",
+          "docs": [
+            "Taker's token account receiving the refund",
+            "Automatically created as an ATA if it doesn't exist"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "taker"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "inputTokenMint",
+          "docs": [
+            "Mint of the token being offered"
+          ]
+        },
+        {
+          "name": "outputTokenMint",
+          "docs": [
+            "Mint of the token being requested"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "Token interface program for Token-2022 support"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "docs": [
+            "Required for ATA initialization"
+          ],
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeMintsFromWhitelist",
+      "discriminator": [
+        214,
+        232,
+        203,
+        229,
+        255,
+        239,
+        201,
+        97
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Listing manager signer"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA verifying the signer holds the listing manager role"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mintWhitelist",
+          "docs": [
+            "Mint whitelist to modify"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "removeMints",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeTakers",
+      "discriminator": [
+        69,
+        178,
+        96,
+        168,
+        117,
+        95,
+        119,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "maker",
+          "docs": [
+            "Original offer maker, must sign whitelist operations",
+            "Receives the rent freed by shrinking the whitelist"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "whitelist",
+          "docs": [
+            "The whitelist PDA storing allowed takers",
+            "Shrunk to fit its remaining takers"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The offer this whitelist belongs to",
+            "Used to verify maker authority"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "takers",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "resolveDispute",
+      "discriminator": [
+        231,
+        6,
+        202,
+        6,
+        96,
+        103,
+        12,
+        230
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "The maker settling the fill or the arbitrator resolving its dispute",
+            "Pays for token account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The offer the fill was made on",
+            "",
+            "Seeds: [\"offer\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.maker",
+                "account": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "fill",
+          "docs": [
+            "The escrowed fill, closed with rent returned to the taker",
+            "",
+            "Seeds: [\"fill\", offer, fill_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  105,
+                  108,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "fill.fill_id",
+                "account": "fill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "maker",
+          "writable": true
+        },
+        {
+          "name": "taker",
+          "writable": true
+        },
+        {
+          "name": "whitelist",
+          "docs": [
+            "The allocation a fill used up is given back for the tokens the taker doesn't receive,",
+            "and the whitelist closes with an offer that closes on completion",
+            "",
+            "Seeds: [\"whitelist\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.maker",
+                "account": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "takerFill",
+          "docs": [
+            "Cumulative fills of the taker on the offer",
+            "Required when the fill was recorded in it",
+            "",
+            "Seeds: [\"taker_fill\", offer, taker]"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  107,
+                  101,
+                  114,
+                  95,
+                  102,
+                  105,
+                  108,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "fill.taker",
+                "account": "fill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "docs": [
+            "The vault holding the reserved input tokens"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "paymentEscrowTokenAccount",
+          "docs": [
+            "The offer account holding the escrowed payments"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "makerReceiveTokenAccount",
+          "docs": [
+            "Maker's token account receiving the payment on settlement",
+            "Automatically created as an ATA if it doesn't exist"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "takerReceiveTokenAccount",
+          "docs": [
+            "Taker's token account receiving the input tokens on settlement",
+            "Automatically created as an ATA if it doesn't exist"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "taker"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "takerRefundTokenAccount",
+          "docs": [
+            "Taker's token account receiving the refunded part of a disputed payment",
+            "Automatically created as an ATA if it doesn't exist"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "taker"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "feeTokenAccount",
+          "docs": [
+            "Protocol fee receiving account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "feeWallet"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "feeWallet"
+        },
+        {
+          "name": "feeConfig",
+          "docs": [
+            "Fee configuration providing the fee charged on disputed fills",
+            "Only required to resolve disputes"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "makerTokenAccount",
+          "docs": [
+            "Maker's token account receiving the input tokens the arbitrator returns",
+            "Only required to resolve disputes"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "inputTokenMint",
+          "docs": [
+            "Mint of the token being offered"
+          ]
+        },
+        {
+          "name": "outputTokenMint",
+          "docs": [
+            "Mint of the token being requested"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "Token interface program for Token-2022 support"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "docs": [
+            "Required for ATA initialization"
+          ],
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "inputToTaker",
+          "type": "u64"
+        },
+        {
+          "name": "paymentToMaker",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "revokeRole",
+      "discriminator": [
+        179,
+        232,
+        2,
+        180,
+        48,
+        227,
+        82,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Super-admin signer, pays for the roles registry on first use"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "PDA verifying super-admin authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA storing the role holders",
+            "Created on first use for configs initialized before roles existed",
+            "Space breakdown in AdminRoles::SPACE"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "adminRole"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setMintDenied",
+      "discriminator": [
+        197,
+        38,
+        224,
+        200,
+        180,
+        19,
+        156,
+        128
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Listing manager signer, pays for the mint config on first use"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA verifying the signer holds the listing manager role"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint the restriction applies to"
+          ]
+        },
+        {
+          "name": "mintConfig",
+          "docs": [
+            "PDA storing the mint's restrictions",
+            "Created on first use with init_if_needed",
+            "Space breakdown:",
+            "- 8 bytes discriminator",
+            "- 32 bytes mint pubkey",
+            "- 1 byte halted flag",
+            "- 1 byte denied flag"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "denied",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setMintHalted",
+      "discriminator": [
+        173,
+        61,
+        126,
+        231,
+        230,
+        125,
+        247,
+        41
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Listing manager signer, pays for the mint config on first use"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA verifying the signer holds the listing manager role"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "Mint the restriction applies to"
+          ]
+        },
+        {
+          "name": "mintConfig",
+          "docs": [
+            "PDA storing the mint's restrictions",
+            "Created on first use with init_if_needed",
+            "Space breakdown:",
+            "- 8 bytes discriminator",
+            "- 32 bytes mint pubkey",
+            "- 1 byte halted flag",
+            "- 1 byte denied flag"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  110,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "halted",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setOfferApprover",
+      "discriminator": [
+        104,
+        107,
+        136,
+        251,
+        250,
+        161,
+        216,
+        108
+      ],
+      "accounts": [
+        {
+          "name": "maker",
+          "docs": [
+            "Original offer maker, must sign offer updates"
+          ],
+          "signer": true
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The offer being updated",
+            "",
+            "Constraints:",
+            "- Must be signed by original maker",
+            "- Must be in Ongoing status",
+            "",
+            "Seeds: [\"offer\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "approver",
+          "type": {
+            "option": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setTakerMerkleRoot",
+      "discriminator": [
+        70,
+        187,
+        198,
+        241,
+        97,
+        74,
+        124,
+        0
+      ],
+      "accounts": [
+        {
+          "name": "maker",
+          "docs": [
+            "Original offer maker, must sign offer updates"
+          ],
+          "signer": true
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The offer being updated",
+            "",
+            "Constraints:",
+            "- Must be signed by original maker",
+            "- Must be in Ongoing status",
+            "",
+            "Seeds: [\"offer\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "root",
+          "type": {
+            "option": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "settleFill",
+      "discriminator": [
+        197,
+        81,
+        135,
+        50,
+        191,
+        3,
+        171,
+        75
+      ],
+      "accounts": [
+        {
+          "name": "authority",
+          "docs": [
+            "The maker settling the fill or the arbitrator resolving its dispute",
+            "Pays for token account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The offer the fill was made on",
+            "",
+            "Seeds: [\"offer\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.maker",
+                "account": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "fill",
+          "docs": [
+            "The escrowed fill, closed with rent returned to the taker",
+            "",
+            "Seeds: [\"fill\", offer, fill_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  105,
+                  108,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "fill.fill_id",
+                "account": "fill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "maker",
+          "writable": true
+        },
+        {
+          "name": "taker",
+          "writable": true
+        },
+        {
+          "name": "whitelist",
+          "docs": [
+            "The allocation a fill used up is given back for the tokens the taker doesn't receive,",
+            "and the whitelist closes with an offer that closes on completion",
+            "",
+            "Seeds: [\"whitelist\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer.maker",
+                "account": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        },
+        {
+          "name": "takerFill",
+          "docs": [
+            "Cumulative fills of the taker on the offer",
+            "Required when the fill was recorded in it",
+            "",
+            "Seeds: [\"taker_fill\", offer, taker]"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  107,
+                  101,
+                  114,
+                  95,
+                  102,
+                  105,
+                  108,
+                  108
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "fill.taker",
+                "account": "fill"
+              }
+            ]
+          }
+        },
+        {
+          "name": "vaultTokenAccount",
+          "docs": [
+            "The vault holding the reserved input tokens"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "paymentEscrowTokenAccount",
+          "docs": [
+            "The offer account holding the escrowed payments"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "offer"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "makerReceiveTokenAccount",
+          "docs": [
+            "Maker's token account receiving the payment on settlement",
+            "Automatically created as an ATA if it doesn't exist"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "takerReceiveTokenAccount",
+          "docs": [
+            "Taker's token account receiving the input tokens on settlement",
+            "Automatically created as an ATA if it doesn't exist"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "taker"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "inputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "takerRefundTokenAccount",
+          "docs": [
+            "Taker's token account receiving the refunded part of a disputed payment",
+            "Automatically created as an ATA if it doesn't exist"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "taker"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "feeTokenAccount",
+          "docs": [
+            "Protocol fee receiving account"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "feeWallet"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "outputTokenMint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "feeWallet"
+        },
+        {
+          "name": "feeConfig",
+          "docs": [
+            "Fee configuration providing the fee charged on disputed fills",
+            "Only required to resolve disputes"
+          ],
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "makerTokenAccount",
+          "docs": [
+            "Maker's token account receiving the input tokens the arbitrator returns",
+            "Only required to resolve disputes"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "inputTokenMint",
+          "docs": [
+            "Mint of the token being offered"
+          ]
+        },
+        {
+          "name": "outputTokenMint",
+          "docs": [
+            "Mint of the token being requested"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "Token interface program for Token-2022 support"
+          ]
+        },
+        {
+          "name": "associatedTokenProgram",
+          "docs": [
+            "Required for ATA initialization"
+          ],
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "takeOffer",
+      "discriminator": [
+        128,
+        156,
+        242,
+        207,
+        237,
+        192,
+        103,
+        240
+      ],
+      "accounts": [
+        {
+          "name": "core",
+          "accounts": [
+            {
+              "name": "taker",
+              "docs": [
+                "Transaction signer who will take the offer.",
+                "Responsible for:",
+                "- Paying for account initialization",
+                "- Sending payment tokens",
+                "- Paying protocol fees",
+                "- Receiving offered tokens"
+              ],
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "adminConfig",
+              "docs": [
+                "Protocol admin configuration.",
+                "Tracks global settings and statistics.",
+                "PDA with seeds: [\"admin_config\"]"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      97,
+                      100,
+                      109,
+                      105,
+                      110,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "whitelistConfig",
+              "docs": [
+                "Whitelist configuration deciding if mint whitelist is enforced.",
+                "PDA with seeds: [\"whitelist_config\"]"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      119,
+                      104,
+                      105,
+                      116,
+                      101,
+                      108,
+                      105,
+                      115,
+                      116,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "mintWhitelist",
+              "docs": [
+                "Mint whitelist, re-checked so de-listed mints cannot be filled.",
+                "PDA with seeds: [\"mint_whitelist\"]"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      119,
+                      104,
+                      105,
+                      116,
+                      101,
+                      108,
+                      105,
+                      115,
+                      116
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "offer",
+              "docs": [
+                "The offer being taken.",
+                "PDA with seeds: [\"offer\", maker_pubkey, offer_id]",
+                "Constraints:",
+                "- Must be in Ongoing status",
+                "- Closed on completion with rent returned to maker"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      111,
+                      102,
+                      102,
+                      101,
+                      114
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.maker",
+                    "account": "offer"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.offer_id",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "maker",
+              "writable": true
+            },
+            {
+              "name": "inputListedMint",
+              "docs": [
+                "PDA with seeds: [\"listed_mint\", input_token_mint], checked in the handler"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      108,
+                      105,
+                      115,
+                      116,
+                      101,
+                      100,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.input_token_mint",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "outputListedMint",
+              "docs": [
+                "PDA with seeds: [\"listed_mint\", output_token_mint], checked in the handler"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      108,
+                      105,
+                      115,
+                      116,
+                      101,
+                      100,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.output_token_mint",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "inputMintConfig",
+              "docs": [
+                "PDA with seeds: [\"mint_config\", input_token_mint], checked in the handler"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.input_token_mint",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "outputMintConfig",
+              "docs": [
+                "PDA with seeds: [\"mint_config\", output_token_mint], checked in the handler"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.output_token_mint",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "whitelist",
+              "docs": [
+                "PDA with seeds: [\"whitelist\", maker_pubkey, offer_id]",
+                "Only initialized if the maker added takers. On restricted offers it must",
+                "include the taker's public key and the taker's allocation is decremented by each fill"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      119,
+                      104,
+                      105,
+                      116,
+                      101,
+                      108,
+                      105,
+                      115,
+                      116
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.maker",
+                    "account": "offer"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.offer_id",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "takerFill",
+              "docs": [
+                "Cumulative fills of the taker on this offer.",
+                "PDA with seeds: [\"taker_fill\", offer, taker]",
+                "Only required when taking through a capped Merkle allowlist leaf or an allow ticket"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      116,
+                      97,
+                      107,
+                      101,
+                      114,
+                      95,
+                      102,
+                      105,
+                      108,
+                      108
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "taker"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "instructionsSysvar",
+              "docs": [
+                "Only required when taking with an allow ticket"
+              ],
+              "optional": true,
+              "address": "Sysvar1nstructions1111111111111111111111111"
+            },
+            {
+              "name": "fill",
+              "docs": [
+                "Escrow record of the fill on offers with an acceptance window.",
+                "PDA with seeds: [\"fill\", offer, fill_count]",
+                "Only required when the offer has an acceptance window"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      102,
+                      105,
+                      108,
+                      108
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.fill_count",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            }
+          ]
+        },
+        {
+          "name": "token",
+          "accounts": [
+            {
+              "name": "makerReceiveTokenAccount",
+              "docs": [
+                "Maker's token account for receiving payment.",
+                "Automatically created as an ATA if it doesn't exist."
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "maker"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "tokenProgram"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "outputTokenMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "takerPaymentTokenAccount",
+              "docs": [
+                "Taker's token account for sending payment.",
+                "Automatically created as an ATA if it doesn't exist."
+              ],
+              "writable": true
+            },
+            {
+              "name": "takerReceiveTokenAccount",
+              "docs": [
+                "Taker's token account for receiving offered tokens.",
+                "Automatically created as an ATA if it doesn't exist."
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "taker"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "tokenProgram"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "inputTokenMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "feeTokenAccount",
+              "docs": [
+                "Protocol fee receiving account.",
+                "Constraints:",
+                "- Must match configured fee wallet",
+                "- Must use correct token mint"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "feeWallet"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "tokenProgram"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "outputTokenMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "vaultTokenAccount",
+              "docs": [
+                "Vault holding the offered tokens.",
+                "For delegated offers, the maker's token account the offer PDA is delegate of.",
+                "Constraints:",
+                "- Must be owned by offer PDA, or by the maker for delegated offers",
+                "- Must match input token mint"
+              ],
+              "writable": true
+            },
+            {
+              "name": "paymentEscrowTokenAccount",
+              "docs": [
+                "Offer owned account holding the payments of escrowed fills.",
+                "Only required when the offer has an acceptance window"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "offer"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "tokenProgram"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "outputTokenMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "inputTokenMint",
+              "docs": [
+                "Mint of the token being offered"
+              ]
+            },
+            {
+              "name": "outputTokenMint",
+              "docs": [
+                "Mint of the token being requested"
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "docs": [
+                "Required program interfaces"
+              ]
+            },
+            {
+              "name": "associatedTokenProgram",
+              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            },
+            {
+              "name": "feeWallet"
+            },
+            {
+              "name": "taker",
+              "writable": true
+            },
+            {
+              "name": "maker"
+            },
+            {
+              "name": "offer"
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "inputTokenAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxPaymentAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFeeAmount",
+          "type": "u64"
+        },
+        {
+          "name": "merkleProof",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "merkleProof"
+              }
+            }
+          }
+        },
+        {
+          "name": "allowTicket",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "allowTicket"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "takeOfferExactOutput",
+      "discriminator": [
+        60,
+        178,
+        26,
+        34,
+        102,
+        126,
+        237,
+        67
+      ],
+      "accounts": [
+        {
+          "name": "core",
+          "accounts": [
+            {
+              "name": "taker",
+              "docs": [
+                "Transaction signer who will take the offer.",
+                "Responsible for:",
+                "- Paying for account initialization",
+                "- Sending payment tokens",
+                "- Paying protocol fees",
+                "- Receiving offered tokens"
+              ],
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "adminConfig",
+              "docs": [
+                "Protocol admin configuration.",
+                "Tracks global settings and statistics.",
+                "PDA with seeds: [\"admin_config\"]"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      97,
+                      100,
+                      109,
+                      105,
+                      110,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "whitelistConfig",
+              "docs": [
+                "Whitelist configuration deciding if mint whitelist is enforced.",
+                "PDA with seeds: [\"whitelist_config\"]"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      119,
+                      104,
+                      105,
+                      116,
+                      101,
+                      108,
+                      105,
+                      115,
+                      116,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "mintWhitelist",
+              "docs": [
+                "Mint whitelist, re-checked so de-listed mints cannot be filled.",
+                "PDA with seeds: [\"mint_whitelist\"]"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      119,
+                      104,
+                      105,
+                      116,
+                      101,
+                      108,
+                      105,
+                      115,
+                      116
+                    ]
+                  }
+                ]
+              }
+            },
+            {
+              "name": "offer",
+              "docs": [
+                "The offer being taken.",
+                "PDA with seeds: [\"offer\", maker_pubkey, offer_id]",
+                "Constraints:",
+                "- Must be in Ongoing status",
+                "- Closed on completion with rent returned to maker"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      111,
+                      102,
+                      102,
+                      101,
+                      114
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.maker",
+                    "account": "offer"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.offer_id",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "maker",
+              "writable": true
+            },
+            {
+              "name": "inputListedMint",
+              "docs": [
+                "PDA with seeds: [\"listed_mint\", input_token_mint], checked in the handler"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      108,
+                      105,
+                      115,
+                      116,
+                      101,
+                      100,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.input_token_mint",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "outputListedMint",
+              "docs": [
+                "PDA with seeds: [\"listed_mint\", output_token_mint], checked in the handler"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      108,
+                      105,
+                      115,
+                      116,
+                      101,
+                      100,
+                      95,
+                      109,
+                      105,
+                      110,
+                      116
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.output_token_mint",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "inputMintConfig",
+              "docs": [
+                "PDA with seeds: [\"mint_config\", input_token_mint], checked in the handler"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.input_token_mint",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "outputMintConfig",
+              "docs": [
+                "PDA with seeds: [\"mint_config\", output_token_mint], checked in the handler"
+              ],
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      109,
+                      105,
+                      110,
+                      116,
+                      95,
+                      99,
+                      111,
+                      110,
+                      102,
+                      105,
+                      103
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.output_token_mint",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "whitelist",
+              "docs": [
+                "PDA with seeds: [\"whitelist\", maker_pubkey, offer_id]",
+                "Only initialized if the maker added takers. On restricted offers it must",
+                "include the taker's public key and the taker's allocation is decremented by each fill"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      119,
+                      104,
+                      105,
+                      116,
+                      101,
+                      108,
+                      105,
+                      115,
+                      116
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.maker",
+                    "account": "offer"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.offer_id",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "takerFill",
+              "docs": [
+                "Cumulative fills of the taker on this offer.",
+                "PDA with seeds: [\"taker_fill\", offer, taker]",
+                "Only required when taking through a capped Merkle allowlist leaf or an allow ticket"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      116,
+                      97,
+                      107,
+                      101,
+                      114,
+                      95,
+                      102,
+                      105,
+                      108,
+                      108
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "taker"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "instructionsSysvar",
+              "docs": [
+                "Only required when taking with an allow ticket"
+              ],
+              "optional": true,
+              "address": "Sysvar1nstructions1111111111111111111111111"
+            },
+            {
+              "name": "fill",
+              "docs": [
+                "Escrow record of the fill on offers with an acceptance window.",
+                "PDA with seeds: [\"fill\", offer, fill_count]",
+                "Only required when the offer has an acceptance window"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "const",
+                    "value": [
+                      102,
+                      105,
+                      108,
+                      108
+                    ]
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "offer.fill_count",
+                    "account": "offer"
+                  }
+                ]
+              }
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            }
+          ]
+        },
+        {
+          "name": "token",
+          "accounts": [
+            {
+              "name": "makerReceiveTokenAccount",
+              "docs": [
+                "Maker's token account for receiving payment.",
+                "Automatically created as an ATA if it doesn't exist."
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "maker"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "tokenProgram"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "outputTokenMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "takerPaymentTokenAccount",
+              "docs": [
+                "Taker's token account for sending payment.",
+                "Automatically created as an ATA if it doesn't exist."
+              ],
+              "writable": true
+            },
+            {
+              "name": "takerReceiveTokenAccount",
+              "docs": [
+                "Taker's token account for receiving offered tokens.",
+                "Automatically created as an ATA if it doesn't exist."
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "taker"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "tokenProgram"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "inputTokenMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "feeTokenAccount",
+              "docs": [
+                "Protocol fee receiving account.",
+                "Constraints:",
+                "- Must match configured fee wallet",
+                "- Must use correct token mint"
+              ],
+              "writable": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "feeWallet"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "tokenProgram"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "outputTokenMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "vaultTokenAccount",
+              "docs": [
+                "Vault holding the offered tokens.",
+                "For delegated offers, the maker's token account the offer PDA is delegate of.",
+                "Constraints:",
+                "- Must be owned by offer PDA, or by the maker for delegated offers",
+                "- Must match input token mint"
+              ],
+              "writable": true
+            },
+            {
+              "name": "paymentEscrowTokenAccount",
+              "docs": [
+                "Offer owned account holding the payments of escrowed fills.",
+                "Only required when the offer has an acceptance window"
+              ],
+              "writable": true,
+              "optional": true,
+              "pda": {
+                "seeds": [
+                  {
+                    "kind": "account",
+                    "path": "offer"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "tokenProgram"
+                  },
+                  {
+                    "kind": "account",
+                    "path": "outputTokenMint"
+                  }
+                ],
+                "program": {
+                  "kind": "const",
+                  "value": [
+                    140,
+                    151,
+                    37,
+                    143,
+                    78,
+                    36,
+                    137,
+                    241,
+                    187,
+                    61,
+                    16,
+                    41,
+                    20,
+                    142,
+                    13,
+                    131,
+                    11,
+                    90,
+                    19,
+                    153,
+                    218,
+                    255,
+                    16,
+                    132,
+                    4,
+                    142,
+                    123,
+                    216,
+                    219,
+                    233,
+                    248,
+                    89
+                  ]
+                }
+              }
+            },
+            {
+              "name": "inputTokenMint",
+              "docs": [
+                "Mint of the token being offered"
+              ]
+            },
+            {
+              "name": "outputTokenMint",
+              "docs": [
+                "Mint of the token being requested"
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "docs": [
+                "Required program interfaces"
+              ]
+            },
+            {
+              "name": "associatedTokenProgram",
+              "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+            },
+            {
+              "name": "systemProgram",
+              "address": "11111111111111111111111111111111"
+            },
+            {
+              "name": "feeWallet"
+            },
+            {
+              "name": "taker",
+              "writable": true
+            },
+            {
+              "name": "maker"
+            },
+            {
+              "name": "offer"
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "paymentAmount",
+          "type": "u64"
+        },
+        {
+          "name": "minInputTokenAmount",
+          "type": "u64"
+        },
+        {
+          "name": "maxFeeAmount",
+          "type": "u64"
+        },
+        {
+          "name": "merkleProof",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "merkleProof"
+              }
+            }
+          }
+        },
+        {
+          "name": "allowTicket",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "allowTicket"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "toggleRequireWhitelist",
+      "discriminator": [
+        234,
+        5,
+        149,
+        172,
+        124,
+        21,
+        127,
+        98
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Listing manager signer"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA verifying the signer holds the listing manager role"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "whitelistConfig",
+          "docs": [
+            "Whitelist configuration to toggle"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unpause",
+      "discriminator": [
+        169,
+        144,
+        4,
+        38,
+        10,
+        141,
+        188,
+        255
+      ],
+      "accounts": [
+        {
+          "name": "pauser",
+          "docs": [
+            "Pauser signer"
+          ],
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "PDA storing the pause flags"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA verifying the signer holds the pauser role"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "create",
+          "type": "bool"
+        },
+        {
+          "name": "take",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "updateExpiryBounty",
+      "discriminator": [
+        177,
+        193,
+        94,
+        127,
+        109,
+        158,
+        91,
+        180
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Fee manager signer"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA verifying the signer holds the fee manager role"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "expiryConfig",
+          "docs": [
+            "PDA storing the expiry bounty",
+            "Created on first use with init_if_needed",
+            "Space breakdown:",
+            "- 8 bytes discriminator",
+            "- 8 bytes expiry bounty"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  101,
+                  120,
+                  112,
+                  105,
+                  114,
+                  121,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "newBounty",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateFeeAddress",
+      "discriminator": [
+        110,
+        156,
+        143,
+        17,
+        70,
+        205,
+        116,
+        191
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Fee manager signer"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA verifying the signer holds the fee manager role"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeConfig",
+          "docs": [
+            "Fee configuration to update"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "newAddress",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "updateFeePercentage",
+      "discriminator": [
+        102,
+        119,
+        197,
+        160,
+        139,
+        102,
+        182,
+        0
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Fee manager signer"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminRoles",
+          "docs": [
+            "PDA verifying the signer holds the fee manager role"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  114,
+                  111,
+                  108,
+                  101,
+                  115
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "feeConfig",
+          "docs": [
+            "Fee configuration to update"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  102,
+                  101,
+                  101
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "newFee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "updateMaxWhitelistTakers",
+      "discriminator": [
+        139,
+        48,
+        104,
+        101,
+        8,
+        158,
+        159,
+        241
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Admin signer, pays for the config on first use"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "adminConfig",
+          "docs": [
+            "PDA verifying admin authority"
+          ],
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  97,
+                  100,
+                  109,
+                  105,
+                  110,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "takerWhitelistConfig",
+          "docs": [
+            "PDA storing taker whitelist limits",
+            "Created on first use with init_if_needed",
+            "Space breakdown:",
+            "- 8 bytes discriminator",
+            "- 4 bytes maximum takers"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  116,
+                  97,
+                  107,
+                  101,
+                  114,
+                  95,
+                  119,
+                  104,
+                  105,
+                  116,
+                  101,
+                  108,
+                  105,
+                  115,
+                  116,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "maxTakers",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "updateOffer",
+      "discriminator": [
+        191,
+        70,
+        15,
+        66,
+        224,
+        2,
+        249,
+        223
+      ],
+      "accounts": [
+        {
+          "name": "maker",
+          "docs": [
+            "Original offer maker, must sign offer updates"
+          ],
+          "signer": true
+        },
+        {
+          "name": "offer",
+          "docs": [
+            "The offer being updated",
+            "",
+            "Constraints:",
+            "- Must be signed by original maker",
+            "- Must be in Ongoing status",
+            "",
+            "Seeds: [\"offer\", maker_pubkey, offer_id]"
+          ],
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  111,
+                  102,
+                  102,
+                  101,
+                  114
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "maker"
+              },
+              {
+                "kind": "account",
+                "path": "offer.offer_id",
+                "account": "offer"
+              }
+            ]
+          }
+        }
+      ],
+      "args": [
+        {
+          "name": "newExpectedTotalAmount",
+          "type": {
+            "option": "u64"
+          }
+        },
+        {
+          "name": "newDeadline",
+          "type": {
+            "option": "i64"
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "adminConfig",
+      "discriminator": [
+        156,
+        10,
+        79,
+        161,
+        71,
+        9,
+        62,
+        77
+      ]
+    },
+    {
+      "name": "adminProposal",
+      "discriminator": [
+        107,
+        249,
+        66,
+        11,
+        147,
+        28,
+        12,
+        239
+      ]
+    },
+    {
+      "name": "adminRoles",
+      "discriminator": [
+        173,
+        110,
+        113,
+        131,
+        150,
+        71,
+        87,
+        215
+      ]
+    },
+    {
+      "name": "expiryConfig",
+      "discriminator": [
+        194,
+        68,
+        40,
+        106,
+        50,
+        119,
+        245,
+        58
+      ]
+    },
+    {
+      "name": "feeConfig",
+      "discriminator": [
+        143,
+        52,
+        146,
+        187,
+        219,
+        123,
+        76,
+        155
+      ]
+    },
+    {
+      "name": "fill",
+      "discriminator": [
+        246,
+        116,
+        142,
+        122,
+        204,
+        199,
+        44,
+        113
+      ]
+    },
+    {
+      "name": "listedMint",
+      "discriminator": [
+        109,
+        49,
+        188,
+        210,
+        237,
+        18,
+        80,
+        249
+      ]
+    },
+    {
+      "name": "makerSequence",
+      "discriminator": [
+        75,
+        198,
+        75,
+        45,
+        62,
+        118,
+        209,
+        189
+      ]
+    },
+    {
+      "name": "mintConfig",
+      "discriminator": [
+        168,
+        252,
+        88,
+        182,
+        219,
+        205,
+        39,
+        53
+      ]
+    },
+    {
+      "name": "mintWhitelist",
+      "discriminator": [
+        21,
+        127,
+        24,
+        53,
+        117,
+        168,
+        234,
+        153
+      ]
+    },
+    {
+      "name": "offer",
+      "discriminator": [
+        215,
+        88,
+        60,
+        71,
+        170,
+        162,
+        73,
+        229
+      ]
+    },
+    {
+      "name": "orderNonces",
+      "discriminator": [
+        176,
+        219,
+        79,
+        188,
+        61,
+        203,
+        98,
+        194
+      ]
+    },
+    {
+      "name": "takerFill",
+      "discriminator": [
+        113,
+        80,
+        109,
+        52,
+        106,
+        169,
+        151,
+        83
+      ]
+    },
+    {
+      "name": "takerWhitelistConfig",
+      "discriminator": [
+        102,
+        109,
+        96,
+        151,
+        253,
+        90,
+        183,
+        81
+      ]
+    },
+    {
+      "name": "whitelist",
+      "discriminator": [
+        204,
+        176,
+        52,
+        79,
+        146,
+        121,
+        54,
+        247
+      ]
+    },
+    {
+      "name": "whitelistConfig",
+      "discriminator": [
+        58,
+        51,
+        12,
+        166,
+        38,
+        109,
+        18,
+        255
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "adminInitialized",
+      "discriminator": [
+        237,
+        223,
+        71,
+        11,
+        140,
+        218,
+        196,
+        171
+      ]
+    },
+    {
+      "name": "adminMultisigUpdated",
+      "discriminator": [
+        54,
+        27,
+        193,
+        51,
+        102,
+        130,
+        225,
+        198
+      ]
+    },
+    {
+      "name": "adminProposalApproved",
+      "discriminator": [
+        184,
+        77,
+        64,
+        67,
+        98,
+        106,
+        109,
+        124
+      ]
+    },
+    {
+      "name": "adminProposalCreated",
+      "discriminator": [
+        26,
+        201,
+        39,
+        134,
+        209,
+        40,
+        200,
+        41
+      ]
+    },
+    {
+      "name": "adminProposalExecuted",
+      "discriminator": [
+        16,
+        228,
+        88,
+        95,
+        202,
+        177,
+        91,
+        25
+      ]
+    },
+    {
+      "name": "adminTransferProposed",
+      "discriminator": [
+        203,
+        168,
+        175,
+        51,
+        239,
+        104,
+        20,
+        85
+      ]
+    },
+    {
+      "name": "adminTransferred",
+      "discriminator": [
+        255,
+        147,
+        182,
+        5,
+        199,
+        217,
+        38,
+        179
+      ]
+    },
+    {
+      "name": "disputeRaised",
+      "discriminator": [
+        246,
+        167,
+        109,
+        37,
+        142,
+        45,
+        38,
+        176
+      ]
+    },
+    {
+      "name": "disputeResolved",
+      "discriminator": [
+        121,
+        64,
+        249,
+        153,
+        139,
+        128,
+        236,
+        187
+      ]
+    },
+    {
+      "name": "expiryBountyUpdated",
+      "discriminator": [
+        5,
+        107,
+        244,
+        240,
+        199,
+        199,
+        215,
+        195
+      ]
+    },
+    {
+      "name": "feeUpdated",
+      "discriminator": [
+        228,
+        75,
+        43,
+        103,
+        9,
+        196,
+        182,
+        4
+      ]
+    },
+    {
+      "name": "feeWalletUpdated",
+      "discriminator": [
+        239,
+        21,
+        163,
+        102,
+        93,
+        63,
+        3,
+        248
+      ]
+    },
+    {
+      "name": "fillPending",
+      "discriminator": [
+        180,
+        65,
+        31,
+        44,
+        135,
+        202,
+        30,
+        40
+      ]
+    },
+    {
+      "name": "fillRejected",
+      "discriminator": [
+        111,
+        10,
+        210,
+        36,
+        38,
+        39,
+        200,
+        41
+      ]
+    },
+    {
+      "name": "fillSettled",
+      "discriminator": [
+        47,
+        107,
+        42,
+        146,
+        115,
+        71,
+        20,
+        119
+      ]
+    },
+    {
+      "name": "maxWhitelistTakersUpdated",
+      "discriminator": [
+        139,
+        236,
+        255,
+        214,
+        13,
+        19,
+        119,
+        61
+      ]
+    },
+    {
+      "name": "mintDelisted",
+      "discriminator": [
+        118,
+        184,
+        78,
+        248,
+        170,
+        199,
+        49,
+        148
+      ]
+    },
+    {
+      "name": "mintDenyUpdated",
+      "discriminator": [
+        214,
+        38,
+        118,
+        31,
+        193,
+        217,
+        80,
+        244
+      ]
+    },
+    {
+      "name": "mintHaltUpdated",
+      "discriminator": [
+        63,
+        158,
+        209,
+        174,
+        156,
+        167,
+        7,
+        19
+      ]
+    },
+    {
+      "name": "mintListed",
+      "discriminator": [
+        40,
+        212,
+        47,
+        4,
+        118,
+        131,
+        132,
+        77
+      ]
+    },
+    {
+      "name": "mintsAddedToWhitelist",
+      "discriminator": [
+        217,
+        228,
+        138,
+        116,
+        179,
+        91,
+        53,
+        53
+      ]
+    },
+    {
+      "name": "mintsRemovedFromWhitelist",
+      "discriminator": [
+        103,
+        176,
+        231,
+        135,
+        82,
+        141,
+        69,
+        19
+      ]
+    },
+    {
+      "name": "offerAmountUpdated",
+      "discriminator": [
+        212,
+        242,
+        7,
+        123,
+        171,
+        156,
+        51,
+        253
+      ]
+    },
+    {
+      "name": "offerApproverUpdated",
+      "discriminator": [
+        180,
+        253,
+        252,
+        144,
+        36,
+        16,
+        85,
+        227
+      ]
+    },
+    {
+      "name": "offerCancelled",
+      "discriminator": [
+        45,
+        42,
+        175,
+        214,
+        51,
+        192,
+        154,
+        9
+      ]
+    },
+    {
+      "name": "offerClosed",
+      "discriminator": [
+        237,
+        38,
+        102,
+        204,
+        165,
+        180,
+        177,
+        164
+      ]
+    },
+    {
+      "name": "offerCreated",
+      "discriminator": [
+        31,
+        236,
+        215,
+        144,
+        75,
+        45,
+        157,
+        87
+      ]
+    },
+    {
+      "name": "offerExpired",
+      "discriminator": [
+        50,
+        165,
+        49,
+        70,
+        32,
+        186,
+        35,
+        229
+      ]
+    },
+    {
+      "name": "offerTaken",
+      "discriminator": [
+        97,
+        101,
+        174,
+        50,
+        76,
+        209,
+        178,
+        148
+      ]
+    },
+    {
+      "name": "offerUpdated",
+      "discriminator": [
+        148,
+        115,
+        79,
+        188,
+        0,
+        21,
+        230,
+        29
+      ]
+    },
+    {
+      "name": "protocolPaused",
+      "discriminator": [
+        35,
+        111,
+        245,
+        138,
+        237,
+        199,
+        79,
+        223
+      ]
+    },
+    {
+      "name": "protocolUnpaused",
+      "discriminator": [
+        248,
+        204,
+        112,
+        239,
+        72,
+        67,
+        127,
+        216
+      ]
+    },
+    {
+      "name": "roleGranted",
+      "discriminator": [
+        220,
+        183,
+        89,
+        228,
+        143,
+        63,
+        246,
+        58
+      ]
+    },
+    {
+      "name": "roleRevoked",
+      "discriminator": [
+        167,
+        183,
+        52,
+        229,
+        126,
+        206,
+        62,
+        61
+      ]
+    },
+    {
+      "name": "signedOrderCancelled",
+      "discriminator": [
+        2,
+        109,
+        202,
+        17,
+        10,
+        145,
+        29,
+        18
+      ]
+    },
+    {
+      "name": "signedOrderFilled",
+      "discriminator": [
+        160,
+        146,
+        8,
+        139,
+        197,
+        217,
+        111,
+        12
+      ]
+    },
+    {
+      "name": "takerAdded",
+      "discriminator": [
+        10,
+        182,
+        246,
+        249,
+        242,
+        63,
+        128,
+        34
+      ]
+    },
+    {
+      "name": "takerMerkleRootUpdated",
+      "discriminator": [
+        32,
+        212,
+        249,
+        216,
+        96,
+        255,
+        114,
+        188
+      ]
+    },
+    {
+      "name": "takerRemoved",
+      "discriminator": [
+        231,
+        98,
+        8,
+        21,
+        61,
+        190,
+        107,
+        70
+      ]
+    },
+    {
+      "name": "takerUpdated",
+      "discriminator": [
+        221,
+        147,
+        234,
+        94,
+        135,
+        205,
+        91,
+        223
+      ]
+    },
+    {
+      "name": "whitelistRequirementToggled",
+      "discriminator": [
+        167,
+        221,
+        168,
+        215,
+        11,
+        108,
+        229,
+        22
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "invalidOfferStatus",
+      "msg": "Invalid Offer Status"
+    },
+    {
+      "code": 6001,
+      "name": "takerNotWhitelisted",
+      "msg": "Taker not whitelisted"
+    },
+    {
+      "code": 6002,
+      "name": "invalidMaker",
+      "msg": "Invalid Maker"
+    },
+    {
+      "code": 6003,
+      "name": "takerAlreadyWhitelisted",
+      "msg": "Taker already exists"
+    },
+    {
+      "code": 6004,
+      "name": "mintAlreadyWhitelisted",
+      "msg": "Token already whitelisted"
+    },
+    {
+      "code": 6005,
+      "name": "mintNotWhitelisted",
+      "msg": "Token not whitelisted"
+    },
+    {
+      "code": 6006,
+      "name": "invalidAdmin",
+      "msg": "Invalid Admin"
+    },
+    {
+      "code": 6007,
+      "name": "offerExpired",
+      "msg": "Offer has expired"
+    },
+    {
+      "code": 6008,
+      "name": "insufficientAmount",
+      "msg": "Insufficient amount available"
+    },
+    {
+      "code": 6009,
+      "name": "cannotCancelOffer",
+      "msg": "Cannot Cancel Offer"
+    },
+    {
+      "code": 6010,
+      "name": "unauthorizedMaker",
+      "msg": "Invalid Maker"
+    },
+    {
+      "code": 6011,
+      "name": "unauthorizedAdmin",
+      "msg": "Unauthorized admin"
+    },
+    {
+      "code": 6012,
+      "name": "offerNotExpired",
+      "msg": "Offer has not expired yet"
+    },
+    {
+      "code": 6013,
+      "name": "tooManyMints",
+      "msg": "Maximum number of whitelisted mints reached"
+    },
+    {
+      "code": 6014,
+      "name": "invalidFeePercentage",
+      "msg": "Fee percentage cannot exceed 100%"
+    },
+    {
+      "code": 6015,
+      "name": "adminNotInitialized",
+      "msg": "Admin has not been initialized"
+    },
+    {
+      "code": 6016,
+      "name": "feeConfigNotInitialized",
+      "msg": "Fee configuration has not been initialized"
+    },
+    {
+      "code": 6017,
+      "name": "invalidAddress",
+      "msg": "Zero Address not allowed"
+    },
+    {
+      "code": 6018,
+      "name": "invalidTokenAccount",
+      "msg": "Invalid token account owner"
+    },
+    {
+      "code": 6019,
+      "name": "invalidTokenMint",
+      "msg": "Token mint mismatch"
+    },
+    {
+      "code": 6020,
+      "name": "invalidDeadline",
+      "msg": "Deadline must be in the future"
+    },
+    {
+      "code": 6021,
+      "name": "whitelistFull",
+      "msg": "Whitelist is full"
+    },
+    {
+      "code": 6022,
+      "name": "emptyTakersList",
+      "msg": "Takers list cannot be empty"
+    },
+    {
+      "code": 6023,
+      "name": "invalidAmount",
+      "msg": "Token amount must be greater than 0"
+    },
+    {
+      "code": 6024,
+      "name": "sequenceOverflow",
+      "msg": "Sequence Overflow"
+    },
+    {
+      "code": 6025,
+      "name": "calculationError",
+      "msg": "Calculation error occurred."
+    },
+    {
+      "code": 6026,
+      "name": "invalidVaultOwner",
+      "msg": "Invalid vault owner"
+    },
+    {
+      "code": 6027,
+      "name": "maxPaymentExceeded",
+      "msg": "Payment amount exceeds taker's maximum"
+    },
+    {
+      "code": 6028,
+      "name": "maxFeeExceeded",
+      "msg": "Fee amount exceeds taker's maximum"
+    },
+    {
+      "code": 6029,
+      "name": "minInputNotMet",
+      "msg": "Input token amount below taker's minimum"
+    },
+    {
+      "code": 6030,
+      "name": "invalidStartTime",
+      "msg": "Start time must be before the deadline"
+    },
+    {
+      "code": 6031,
+      "name": "offerNotStarted",
+      "msg": "Offer has not started yet"
+    },
+    {
+      "code": 6032,
+      "name": "invalidMinFillAmount",
+      "msg": "Minimum fill amount cannot exceed token amount"
+    },
+    {
+      "code": 6033,
+      "name": "fillBelowMinimum",
+      "msg": "Fill amount below offer minimum"
+    },
+    {
+      "code": 6034,
+      "name": "partialFillNotAllowed",
+      "msg": "Offer must be filled in full"
+    },
+    {
+      "code": 6035,
+      "name": "allocationExceeded",
+      "msg": "Fill exceeds taker's allocation"
+    },
+    {
+      "code": 6036,
+      "name": "invalidWhitelistSize",
+      "msg": "Maximum whitelist size must be between 1 and 80"
+    },
+    {
+      "code": 6037,
+      "name": "invalidMerkleProof",
+      "msg": "Invalid Merkle proof"
+    },
+    {
+      "code": 6038,
+      "name": "takerFillRequired",
+      "msg": "Taker fill account is required for capped allocations"
+    },
+    {
+      "code": 6039,
+      "name": "invalidAllowTicket",
+      "msg": "Invalid allow ticket"
+    },
+    {
+      "code": 6040,
+      "name": "allowTicketExpired",
+      "msg": "Allow ticket has expired"
+    },
+    {
+      "code": 6041,
+      "name": "instructionsSysvarRequired",
+      "msg": "Instructions sysvar is required for allow tickets"
     },
     {
-      "name": "toggleRequireWhitelist",
-      "discriminator": [
-        234,
-        5,
-        149,
-        172,
-        124,
-        21,
-        127,
-        98
+      "code": 6042,
+      "name": "invalidOrderSignature",
+      "msg": "Invalid signed order signature"
+    },
+    {
+      "code": 6043,
+      "name": "orderExpired",
+      "msg": "Signed order has expired"
+    },
+    {
+      "code": 6044,
+      "name": "orderNonceUsed",
+      "msg": "Order nonce has already been used"
+    },
+    {
+      "code": 6045,
+      "name": "orderTakerMismatch",
+      "msg": "Signed order is reserved for another taker"
+    },
+    {
+      "code": 6046,
+      "name": "insufficientDelegation",
+      "msg": "Maker token account delegation is missing or too small"
+    },
+    {
+      "code": 6047,
+      "name": "invalidCustodyAccounts",
+      "msg": "Vault token account doesn't match the offer custody"
+    },
+    {
+      "code": 6048,
+      "name": "invalidAcceptanceWindow",
+      "msg": "Acceptance window must be greater than 0"
+    },
+    {
+      "code": 6049,
+      "name": "escrowRequiresVault",
+      "msg": "Escrowed offers must hold their tokens in a vault"
+    },
+    {
+      "code": 6050,
+      "name": "escrowAccountRequired",
+      "msg": "Fill and payment escrow accounts are required for escrowed offers"
+    },
+    {
+      "code": 6051,
+      "name": "fillAcceptanceExpired",
+      "msg": "Fill acceptance window has passed"
+    },
+    {
+      "code": 6052,
+      "name": "cannotRejectFill",
+      "msg": "Only the maker can reject a fill before its acceptance deadline"
+    },
+    {
+      "code": 6053,
+      "name": "pendingFills",
+      "msg": "Offer has pending escrowed fills"
+    },
+    {
+      "code": 6054,
+      "name": "invalidTaker",
+      "msg": "Invalid Taker"
+    },
+    {
+      "code": 6055,
+      "name": "arbitratorRequiresEscrow",
+      "msg": "Arbitrated offers must escrow their fills"
+    },
+    {
+      "code": 6056,
+      "name": "noArbitrator",
+      "msg": "Offer has no arbitrator"
+    },
+    {
+      "code": 6057,
+      "name": "cannotDisputeFill",
+      "msg": "Only the maker or the taker can dispute a fill"
+    },
+    {
+      "code": 6058,
+      "name": "disputeWindowClosed",
+      "msg": "Dispute window has passed"
+    },
+    {
+      "code": 6059,
+      "name": "fillDisputed",
+      "msg": "Fill is disputed"
+    },
+    {
+      "code": 6060,
+      "name": "fillNotDisputed",
+      "msg": "Fill is not disputed"
+    },
+    {
+      "code": 6061,
+      "name": "unauthorizedArbitrator",
+      "msg": "Only the offer arbitrator can resolve disputes"
+    },
+    {
+      "code": 6062,
+      "name": "invalidDisputeSplit",
+      "msg": "Dispute split exceeds the escrowed amounts"
+    },
+    {
+      "code": 6063,
+      "name": "disputeAccountsRequired",
+      "msg": "Fee config and maker token account are required to resolve disputes"
+    },
+    {
+      "code": 6064,
+      "name": "notPendingAdmin",
+      "msg": "Signer is not the pending admin"
+    },
+    {
+      "code": 6065,
+      "name": "multisigActive",
+      "msg": "Admin actions must go through a multisig proposal"
+    },
+    {
+      "code": 6066,
+      "name": "multisigNotActive",
+      "msg": "Admin multisig is not enabled"
+    },
+    {
+      "code": 6067,
+      "name": "invalidMultisig",
+      "msg": "Invalid multisig signers or threshold"
+    },
+    {
+      "code": 6068,
+      "name": "notMultisigSigner",
+      "msg": "Signer is not a member of the admin multisig"
+    },
+    {
+      "code": 6069,
+      "name": "proposalAlreadyApproved",
+      "msg": "Proposal has already been approved by this signer"
+    },
+    {
+      "code": 6070,
+      "name": "proposalAlreadyExecuted",
+      "msg": "Proposal has already been executed"
+    },
+    {
+      "code": 6071,
+      "name": "proposalThresholdNotMet",
+      "msg": "Proposal has not reached the approval threshold"
+    },
+    {
+      "code": 6072,
+      "name": "proposalAccountsRequired",
+      "msg": "An account required by this proposal is missing"
+    },
+    {
+      "code": 6073,
+      "name": "roleAlreadyGranted",
+      "msg": "Role is already held by this account"
+    },
+    {
+      "code": 6074,
+      "name": "createPaused",
+      "msg": "Creating offers is paused"
+    },
+    {
+      "code": 6075,
+      "name": "takePaused",
+      "msg": "Taking offers is paused"
+    },
+    {
+      "code": 6076,
+      "name": "mintHalted",
+      "msg": "Trading of this mint is halted"
+    },
+    {
+      "code": 6077,
+      "name": "mintDenied",
+      "msg": "Mint is denied"
+    },
+    {
+      "code": 6078,
+      "name": "tradeBelowMinimum",
+      "msg": "Trade amount is below the mint's minimum trade size"
+    },
+    {
+      "code": 6079,
+      "name": "tradeAboveMaximum",
+      "msg": "Trade amount is above the mint's maximum trade size"
+    },
+    {
+      "code": 6080,
+      "name": "invalidTradeLimits",
+      "msg": "Minimum trade size exceeds the maximum trade size"
+    },
+    {
+      "code": 6081,
+      "name": "offerAccountMismatch",
+      "msg": "Account does not belong to the offer being taken"
+    },
+    {
+      "code": 6082,
+      "name": "invalidExpiryBounty",
+      "msg": "Expiry bounty exceeds the maximum"
+    },
+    {
+      "code": 6083,
+      "name": "offerIdAlreadyUsed",
+      "msg": "Offer id must be greater than the maker's previous offer ids"
+    }
+  ],
+  "types": [
+    {
+      "name": "adminAction",
+      "docs": [
+        "Admin actions that can be proposed to the multisig",
+        "Each mirrors the single admin instruction of the same name"
       ],
-      "accounts": [
-        {
-          "name": "admin",
-          "docs": [
-            "Admin signer"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "adminConfig",
-          "docs": [
-            "PDA verifying admin authority"
-          ],
-          "pda": {
-            "seeds": [
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "updateFee",
+            "fields": [
               {
-                "kind": "const",
-                "value": [
-                  97,
-                  100,
-                  109,
-                  105,
-                  110,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
+                "name": "newFee",
+                "type": "u64"
               }
             ]
-          }
-        },
-        {
-          "name": "whitelistConfig",
-          "docs": [
-            "Whitelist configuration to toggle"
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
+          },
+          {
+            "name": "updateFeeAddress",
+            "fields": [
               {
-                "kind": "const",
-                "value": [
-                  119,
-                  104,
-                  105,
-                  116,
-                  101,
-                  108,
-                  105,
-                  115,
-                  116,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
+                "name": "newAddress",
+                "type": "pubkey"
               }
             ]
-          }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "updateFeeAddress",
-      "discriminator": [
-        110,
-        156,
-        143,
-        17,
-        70,
-        205,
-        116,
-        191
-      ],
-      "accounts": [
-        {
-          "name": "admin",
-          "docs": [
-            "Admin signer"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "adminConfig",
-          "docs": [
-            "PDA verifying admin authority"
-          ],
-          "pda": {
-            "seeds": [
+          },
+          {
+            "name": "updateExpiryBounty",
+            "fields": [
               {
-                "kind": "const",
-                "value": [
-                  97,
-                  100,
-                  109,
-                  105,
-                  110,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
+                "name": "newBounty",
+                "type": "u64"
               }
             ]
-          }
-        },
-        {
-          "name": "feeConfig",
-          "docs": [
-            "Fee configuration to update"
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
+          },
+          {
+            "name": "toggleRequireWhitelist"
+          },
+          {
+            "name": "updateMaxWhitelistTakers",
+            "fields": [
               {
-                "kind": "const",
-                "value": [
-                  102,
-                  101,
-                  101
-                ]
+                "name": "maxTakers",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "addMints",
+            "fields": [
+              {
+                "name": "newMints",
+                "type": {
+                  "vec": "pubkey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "removeMints",
+            "fields": [
+              {
+                "name": "removeMints",
+                "type": {
+                  "vec": "pubkey"
+                }
+              }
+            ]
+          },
+          {
+            "name": "setMultisig",
+            "fields": [
+              {
+                "name": "signers",
+                "type": {
+                  "vec": "pubkey"
+                }
+              },
+              {
+                "name": "threshold",
+                "type": "u8"
               }
             ]
-          }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "newAddress",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "updateFeePercentage",
-      "discriminator": [
-        102,
-        119,
-        197,
-        160,
-        139,
-        102,
-        182,
-        0
-      ],
-      "accounts": [
-        {
-          "name": "admin",
-          "docs": [
-            "Admin signer"
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "adminConfig",
-          "docs": [
-            "PDA verifying admin authority"
-          ],
-          "pda": {
-            "seeds": [
+          },
+          {
+            "name": "grantRole",
+            "fields": [
               {
-                "kind": "const",
-                "value": [
-                  97,
-                  100,
-                  109,
-                  105,
-                  110,
-                  95,
-                  99,
-                  111,
-                  110,
-                  102,
-                  105,
-                  103
-                ]
+                "name": "role",
+                "type": {
+                  "defined": {
+                    "name": "adminRole"
+                  }
+                }
+              },
+              {
+                "name": "holder",
+                "type": "pubkey"
               }
             ]
-          }
-        },
-        {
-          "name": "feeConfig",
-          "docs": [
-            "Fee configuration to update"
-          ],
-          "writable": true,
-          "pda": {
-            "seeds": [
+          },
+          {
+            "name": "revokeRole",
+            "fields": [
               {
-                "kind": "const",
-                "value": [
-                  102,
-                  101,
-                  101
-                ]
+                "name": "role",
+                "type": {
+                  "defined": {
+                    "name": "adminRole"
+                  }
+                }
               }
             ]
           }
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "newFee",
-          "type": "u64"
-        }
-      ]
-    }
-  ],
-  "accounts": [
+        ]
+      }
+    },
     {
       "name": "adminConfig",
-      "discriminator": [
-        156,
-        10,
-        79,
-        161,
-        71,
-        9,
-        62,
-        77
-      ]
+      "docs": [
+        "Configuration account for admin operations and offer tracking"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "Admin's public key for authorization"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "Admin proposed by the current admin, becomes admin once it accepts"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "signers",
+            "docs": [
+              "Members of the admin multisig, empty in single admin mode"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Approvals a proposal needs before it can be executed",
+              "0 keeps the config in single admin mode"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "proposalCount",
+            "docs": [
+              "Number of admin proposals created, used as the next proposal id"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "createPaused",
+            "docs": [
+              "Whether creating new offers is paused"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "takePaused",
+            "docs": [
+              "Whether taking offers and filling signed orders is paused",
+              "Cancelling offers stays available so makers can always withdraw"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "name": "feeConfig",
-      "discriminator": [
-        143,
-        52,
-        146,
-        187,
-        219,
-        123,
-        76,
-        155
-      ]
+      "name": "adminInitialized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "feePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "feeWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "requireWhitelist",
+            "type": "bool"
+          },
+          {
+            "name": "initialMints",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "mintWhitelist",
-      "discriminator": [
-        21,
-        127,
-        24,
-        53,
-        117,
-        168,
-        234,
-        153
-      ]
+      "name": "adminMultisigUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "signers",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminProposal",
+      "docs": [
+        "Account structure recording an admin action awaiting multisig approval"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "docs": [
+              "Sequential id of the proposal"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "docs": [
+              "Multisig member who created the proposal"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "docs": [
+              "Admin action applied once the proposal is executed"
+            ],
+            "type": {
+              "defined": {
+                "name": "adminAction"
+              }
+            }
+          },
+          {
+            "name": "approvals",
+            "docs": [
+              "Multisig members who approved the proposal",
+              "Only approvals from current members count towards the threshold"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "executed",
+            "docs": [
+              "Whether the action has been applied"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Unix timestamp when the proposal was created"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "offer",
-      "discriminator": [
-        215,
-        88,
-        60,
-        71,
-        170,
-        162,
-        73,
-        229
-      ]
+      "name": "adminProposalApproved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "signer",
+            "type": "pubkey"
+          },
+          {
+            "name": "approvals",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "whitelist",
-      "discriminator": [
-        204,
-        176,
-        52,
-        79,
-        146,
-        121,
-        54,
-        247
-      ]
+      "name": "adminProposalCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "adminAction"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "whitelistConfig",
-      "discriminator": [
-        58,
-        51,
-        12,
-        166,
-        38,
-        109,
-        18,
-        255
-      ]
-    }
-  ],
-  "events": [
-    {
-      "name": "adminInitialized",
-      "discriminator": [
-        237,
-        223,
-        71,
-        11,
-        140,
-        218,
-        196,
-        171
-      ]
+      "name": "adminProposalExecuted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "executor",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "adminAction"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "feeUpdated",
-      "discriminator": [
-        228,
-        75,
-        43,
-        103,
-        9,
-        196,
-        182,
-        4
-      ]
+      "name": "adminRole",
+      "docs": [
+        "Enum of the admin roles delegated by the super-admin"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "feeManager"
+          },
+          {
+            "name": "listingManager"
+          },
+          {
+            "name": "pauser"
+          }
+        ]
+      }
     },
     {
-      "name": "feeWalletUpdated",
-      "discriminator": [
-        239,
-        21,
-        163,
-        102,
-        93,
-        63,
-        3,
-        248
-      ]
+      "name": "adminRoles",
+      "docs": [
+        "Registry of the accounts holding each delegated admin role",
+        "Roles are granted and revoked by the super-admin, the `AdminConfig` authority"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feeManager",
+            "docs": [
+              "Account allowed to update the fee percentage, fee address and expiry bounty"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "listingManager",
+            "docs": [
+              "Account allowed to manage the mint whitelist and its requirement"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "pauser",
+            "docs": [
+              "Account allowed to pause the protocol in an emergency"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "mintsAddedToWhitelist",
-      "discriminator": [
-        217,
-        228,
-        138,
-        116,
-        179,
-        91,
-        53,
-        53
-      ]
+      "name": "adminTransferProposed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "mintsRemovedFromWhitelist",
-      "discriminator": [
-        103,
-        176,
-        231,
-        135,
-        82,
-        141,
-        69,
-        19
-      ]
+      "name": "adminTransferred",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "oldAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "offerCancelled",
-      "discriminator": [
-        45,
-        42,
-        175,
-        214,
-        51,
-        192,
-        154,
-        9
-      ]
+      "name": "allowTicket",
+      "docs": [
+        "Off-chain allow ticket signed by the offer's approver",
+        "",
+        "The Borsh serialization of the ticket is the message signed with Ed25519.",
+        "The taker submits the ticket with `take_offer`, preceded by an Ed25519",
+        "program instruction verifying the approver's signature over it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offer",
+            "docs": [
+              "Offer the ticket is valid for"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "taker",
+            "docs": [
+              "Taker allowed to take the offer"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "maxAmount",
+            "docs": [
+              "Input token amount the taker is allowed to take in total"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiry",
+            "docs": [
+              "Unix timestamp after which the ticket can no longer be used"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "offerCreated",
-      "discriminator": [
-        31,
-        236,
-        215,
-        144,
-        75,
-        45,
-        157,
-        87
-      ]
+      "name": "cancellationReason",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "expired"
+          },
+          {
+            "name": "makerCancelled"
+          }
+        ]
+      }
     },
     {
-      "name": "offerTaken",
-      "discriminator": [
-        97,
-        101,
-        174,
-        50,
-        76,
-        209,
-        178,
-        148
-      ]
+      "name": "disputeRaised",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "fillId",
+            "type": "u64"
+          },
+          {
+            "name": "raisedBy",
+            "type": "pubkey"
+          },
+          {
+            "name": "arbitrator",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "takerUpdated",
-      "discriminator": [
-        221,
-        147,
-        234,
-        94,
-        135,
-        205,
-        91,
-        223
-      ]
+      "name": "disputeResolved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "fillId",
+            "type": "u64"
+          },
+          {
+            "name": "arbitrator",
+            "type": "pubkey"
+          },
+          {
+            "name": "inputToTaker",
+            "type": "u64"
+          },
+          {
+            "name": "inputToMaker",
+            "type": "u64"
+          },
+          {
+            "name": "paymentToMaker",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "refundToTaker",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "name": "whitelistRequirementToggled",
-      "discriminator": [
-        167,
-        221,
-        168,
-        215,
-        11,
-        108,
-        229,
-        22
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "invalidOfferStatus",
-      "msg": "Invalid Offer Status"
+      "name": "expiryBountyUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldBounty",
+            "type": "u64"
+          },
+          {
+            "name": "newBounty",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6001,
-      "name": "takerNotWhitelisted",
-      "msg": "Taker not whitelisted"
+      "name": "expiryConfig",
+      "docs": [
+        "Account structure storing the bounty paid for expiring offers",
+        "Kept apart from `FeeConfig` so the fee PDA layout is unchanged"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "expiryBounty",
+            "docs": [
+              "Bounty paid to whoever expires an offer, in basis points of the returned tokens",
+              "Taken from the maker's remaining tokens, 0 disables the bounty"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "invalidMaker",
-      "msg": "Invalid Maker"
+      "name": "feeConfig",
+      "docs": [
+        "Account structure storing fee configuration for the protocol",
+        "Controls both the fee amount and where fees are sent"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "feePercentage",
+            "docs": [
+              "The fee amount in basis points (1/100th of a percent)",
+              "e.g., 100 = 1%, 50 = 0.5%, 25 = 0.25%"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feeAddress",
+            "docs": [
+              "The public key of the account that receives protocol fees",
+              "All fees collected from trades will be sent to this address"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "takerAlreadyWhitelisted",
-      "msg": "Taker already exists"
+      "name": "feeUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldFee",
+            "type": "u64"
+          },
+          {
+            "name": "newFee",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6004,
-      "name": "mintAlreadyWhitelisted",
-      "msg": "Token already whitelisted"
+      "name": "feeWalletUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "newWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6005,
-      "name": "mintNotWhitelisted",
-      "msg": "Token not whitelisted"
+      "name": "fill",
+      "docs": [
+        "Account structure recording a fill escrowed until the maker accepts it",
+        "The input tokens stay reserved in the offer vault and the taker's",
+        "payment and fee are held in the offer's payment escrow account"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offer",
+            "docs": [
+              "The offer the fill was made on"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "fillId",
+            "docs": [
+              "Sequential id of the fill on the offer"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "taker",
+            "docs": [
+              "The taker who made the fill, receives the rent back"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "inputTokenAmount",
+            "docs": [
+              "Input tokens reserved for the taker"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paymentAmount",
+            "docs": [
+              "Output tokens owed to the maker on settlement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "docs": [
+              "Protocol fee paid to the fee wallet on settlement"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "acceptDeadline",
+            "docs": [
+              "Unix timestamp until which the maker can settle the fill",
+              "Anyone can reject it after this time"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "disputed",
+            "docs": [
+              "Whether a dispute froze the fill until the arbitrator resolves it"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "consumedAllocation",
+            "docs": [
+              "Whether the fill was deducted from the taker's capped whitelist allocation"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "recordedTakerFill",
+            "docs": [
+              "Whether the fill was added to the taker's recorded fills"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6006,
-      "name": "invalidAdmin",
-      "msg": "Invalid Admin"
+      "name": "fillPending",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "fillId",
+            "type": "u64"
+          },
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "taker",
+            "type": "pubkey"
+          },
+          {
+            "name": "inputTokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "paymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "acceptDeadline",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6007,
-      "name": "offerExpired",
-      "msg": "Offer has expired"
+      "name": "fillRejected",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "fillId",
+            "type": "u64"
+          },
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "taker",
+            "type": "pubkey"
+          },
+          {
+            "name": "inputTokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "refundedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "remainingAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6008,
-      "name": "insufficientAmount",
-      "msg": "Insufficient amount available"
+      "name": "fillSettled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "fillId",
+            "type": "u64"
+          },
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "taker",
+            "type": "pubkey"
+          },
+          {
+            "name": "inputTokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "paymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "remainingAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6009,
-      "name": "cannotCancelOffer",
-      "msg": "Cannot Cancel Offer"
+      "name": "listedMint",
+      "docs": [
+        "Account structure listing a single mint for trading",
+        "One [\"listed_mint\", mint] PDA exists per listed mint, so listings are",
+        "not limited by the size of the `MintWhitelist` vector"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The listed mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "listedAt",
+            "docs": [
+              "Unix timestamp when the mint was first listed"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "listedBy",
+            "docs": [
+              "Listing manager who listed the mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "minTradeAmount",
+            "docs": [
+              "Smallest amount of the mint a single trade can move"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maxTradeAmount",
+            "docs": [
+              "Largest amount of the mint a single trade can move, 0 disables the limit"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6010,
-      "name": "unauthorizedMaker",
-      "msg": "Invalid Maker"
+      "name": "makerSequence",
+      "docs": [
+        "Account structure tracking the offer ids a maker has used",
+        "Offer ids must increase, so a closed offer's PDA can't be recreated and",
+        "inherit the tickets, taker fills and fills bound to its address"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maker",
+            "docs": [
+              "The maker whose offer ids are tracked"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "nextOfferId",
+            "docs": [
+              "Smallest offer id the maker can create next"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6011,
-      "name": "unauthorizedAdmin",
-      "msg": "Unauthorized admin"
+      "name": "maxWhitelistTakersUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldMax",
+            "type": "u32"
+          },
+          {
+            "name": "newMax",
+            "type": "u32"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6012,
-      "name": "offerNotExpired",
-      "msg": "Offer has not expired yet"
+      "name": "merkleProof",
+      "docs": [
+        "Proof that a taker is part of an offer's Merkle allowlist",
+        "Passed by the taker when the offer stores a taker Merkle root"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "allocation",
+            "docs": [
+              "Input token amount the taker is allowed to take in total",
+              "Must match the leaf, None for uncapped leaves"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "proof",
+            "docs": [
+              "Sibling hashes from the leaf up to the root"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6013,
-      "name": "tooManyMints",
-      "msg": "Maximum number of whitelisted mints reached"
+      "name": "mintConfig",
+      "docs": [
+        "Account structure storing per-mint trading restrictions",
+        "Mints without a config PDA are unrestricted"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The mint the config applies to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "halted",
+            "docs": [
+              "Whether trading the mint is temporarily halted"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "denied",
+            "docs": [
+              "Whether the mint is on the compliance denylist"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "invalidFeePercentage",
-      "msg": "Fee percentage cannot exceed 100%"
+      "name": "mintDelisted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6015,
-      "name": "adminNotInitialized",
-      "msg": "Admin has not been initialized"
+      "name": "mintDenyUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "denied",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6016,
-      "name": "feeConfigNotInitialized",
-      "msg": "Fee configuration has not been initialized"
+      "name": "mintHaltUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "halted",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6017,
-      "name": "invalidAddress",
-      "msg": "Zero Address not allowed"
+      "name": "mintListed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "minTradeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxTradeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6018,
-      "name": "invalidTokenAccount",
-      "msg": "Invalid token account owner"
+      "name": "mintWhitelist",
+      "docs": [
+        "Account structure storing the whitelist of permitted token mints",
+        "This controls which tokens can be used in the protocol",
+        "Capped at 50 mints, further mints are listed through `ListedMint` PDAs"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mints",
+            "docs": [
+              "Vector of public keys representing allowed token mints",
+              "Only tokens from these mints can be used in the protocol when whitelist is enabled"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6019,
-      "name": "invalidTokenMint",
-      "msg": "Token mint mismatch"
+      "name": "mintsAddedToWhitelist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "newMints",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6020,
-      "name": "invalidDeadline",
-      "msg": "Deadline must be in the future"
+      "name": "mintsRemovedFromWhitelist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "removedMints",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "offer",
+      "docs": [
+        "Account structure representing a token swap offer",
+        "Stores all details about an offer including its current state and parameters",
+        "",
+        "The layout differs from the original program's offers and no migration exists,",
+        "upgrading an existing deployment requires its open offers to be cancelled first"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "docs": [
+              "Unique identifier for the offer"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "maker",
+            "docs": [
+              "The public key of the account that created this offer",
+              "Controls permissions for offer modification and cancellation"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "inputTokenMint",
+            "docs": [
+              "The mint address of the token being offered",
+              "Must be a whitelisted token mint if whitelist is enabled"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenAmount",
+            "docs": [
+              "The amount of tokens being offered",
+              "Decreases as partial fills occur, reaches 0 when fully filled"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "outputTokenMint",
+            "docs": [
+              "The mint address of the token being offered",
+              "Must be a whitelisted token mint if whitelist is enabled"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "expectedTotalAmount",
+            "docs": [
+              "The total amount of payment tokens expected in return",
+              "Used to calculate the exchange rate for partial fills"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "tokenAmountRemaining",
+            "docs": [
+              "token amount_a remaining after each trade"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expectedFulfilledAmount",
+            "docs": [
+              "token_b fullfilled"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "Unix timestamp when this offer expires",
+              "Offer cannot be taken after this time"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "startTime",
+            "docs": [
+              "Unix timestamp from when this offer can be taken",
+              "Offer cannot be taken before this time"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Current status of the offer",
+              "Controls what operations are permitted"
+            ],
+            "type": {
+              "defined": {
+                "name": "offerStatus"
+              }
+            }
+          },
+          {
+            "name": "feePercentage",
+            "type": "u64"
+          },
+          {
+            "name": "feeWallet",
+            "type": "pubkey"
+          },
+          {
+            "name": "visibility",
+            "docs": [
+              "Who is allowed to take this offer",
+              "Restricted offers require the taker to be in the offer's whitelist PDA"
+            ],
+            "type": {
+              "defined": {
+                "name": "offerVisibility"
+              }
+            }
+          },
+          {
+            "name": "closeOnCompletion",
+            "docs": [
+              "Whether the offer and whitelist PDAs are closed to the maker on the final fill"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "minFillAmount",
+            "docs": [
+              "Smallest input token amount a single fill can take",
+              "The final remainder can always be taken even if it is smaller"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "allOrNone",
+            "docs": [
+              "Whether the whole remaining amount must be taken in a single fill"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "takerMerkleRoot",
+            "docs": [
+              "Merkle root of an off-chain taker allowlist for restricted offers",
+              "Takers proving inclusion can take the offer without being in the whitelist PDA"
+            ],
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          },
+          {
+            "name": "approver",
+            "docs": [
+              "Key signing off-chain allow tickets for restricted offers",
+              "None lets the maker sign the tickets"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "custody",
+            "docs": [
+              "Where the offered tokens are held until they are taken"
+            ],
+            "type": {
+              "defined": {
+                "name": "offerCustody"
+              }
+            }
+          },
+          {
+            "name": "acceptanceWindow",
+            "docs": [
+              "Seconds the maker has to settle an escrowed fill",
+              "None pays the maker immediately on take"
+            ],
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "fillCount",
+            "docs": [
+              "Number of escrowed fills made on the offer, used as the next fill id"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pendingFills",
+            "docs": [
+              "Number of escrowed fills waiting to be settled or rejected"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "arbitrator",
+            "docs": [
+              "Key allowed to split the escrow of disputed fills",
+              "None disables disputes on the offer"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "expiryBounty",
+            "docs": [
+              "Expiry bounty copied from the expiry config at creation, in basis points",
+              "Paid to whoever expires the offer"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "whitelistFull",
-      "msg": "Whitelist is full"
+      "name": "offerAmountUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldTokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "newTokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "remainingAmount",
+            "type": "u64"
+          },
+          {
+            "name": "expectedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6022,
-      "name": "emptyTakersList",
-      "msg": "Takers list cannot be empty"
+      "name": "offerApproverUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldApprover",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "newApprover",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "invalidAmount",
-      "msg": "Token amount must be greater than 0"
+      "name": "offerCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "tokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "reason",
+            "type": {
+              "defined": {
+                "name": "cancellationReason"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6024,
-      "name": "sequenceOverflow",
-      "msg": "Sequence Overflow"
+      "name": "offerClosed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "offerStatus"
+              }
+            }
+          },
+          {
+            "name": "tokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "tokenAmountRemaining",
+            "type": "u64"
+          },
+          {
+            "name": "expectedFulfilledAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 6025,
-      "name": "calculationError",
-      "msg": "Calculation error occurred."
+      "name": "offerCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "inputTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "outputTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "expectedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "deadline",
+            "type": "i64"
+          },
+          {
+            "name": "startTime",
+            "type": "i64"
+          },
+          {
+            "name": "visibility",
+            "type": {
+              "defined": {
+                "name": "offerVisibility"
+              }
+            }
+          },
+          {
+            "name": "closeOnCompletion",
+            "type": "bool"
+          },
+          {
+            "name": "minFillAmount",
+            "type": "u64"
+          },
+          {
+            "name": "allOrNone",
+            "type": "bool"
+          },
+          {
+            "name": "custody",
+            "type": {
+              "defined": {
+                "name": "offerCustody"
+              }
+            }
+          },
+          {
+            "name": "acceptanceWindow",
+            "type": {
+              "option": "i64"
+            }
+          },
+          {
+            "name": "arbitrator",
+            "type": {
+              "option": "pubkey"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6026,
-      "name": "invalidVaultOwner",
-      "msg": "Invalid vault owner"
-    }
-  ],
-  "types": [
-    {
-      "name": "adminConfig",
+      "name": "offerCustody",
       "docs": [
-        "Configuration account for admin operations and offer tracking"
+        "Enum controlling where the offered tokens are held"
       ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "admin",
-            "docs": [
-              "Admin's public key for authorization"
-            ],
-            "type": "pubkey"
+            "name": "vault"
+          },
+          {
+            "name": "delegate"
           }
         ]
       }
     },
     {
-      "name": "adminInitialized",
+      "name": "offerExpired",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
-            "type": "pubkey"
+            "name": "offerId",
+            "type": "u64"
           },
           {
-            "name": "feePercentage",
-            "type": "u64"
+            "name": "maker",
+            "type": "pubkey"
           },
           {
-            "name": "feeWallet",
+            "name": "cranker",
             "type": "pubkey"
           },
           {
-            "name": "requireWhitelist",
-            "type": "bool"
+            "name": "returnedAmount",
+            "type": "u64"
           },
           {
-            "name": "initialMints",
-            "type": {
-              "vec": "pubkey"
-            }
+            "name": "bountyAmount",
+            "type": "u64"
+          },
+          {
+            "name": "tokenMint",
+            "type": "pubkey"
           },
           {
             "name": "timestamp",
@@ -1966,62 +10992,116 @@ export type Swap = {
       }
     },
     {
-      "name": "cancellationReason",
+      "name": "offerStatus",
+      "docs": [
+        "Enum representing the possible states of an offer",
+        "Used to track the offer's lifecycle and control permitted operations"
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "expired"
+            "name": "initialized"
           },
           {
-            "name": "makerCancelled"
+            "name": "vaultInitialized"
+          },
+          {
+            "name": "ongoing"
+          },
+          {
+            "name": "completed"
+          },
+          {
+            "name": "cancelled"
+          },
+          {
+            "name": "expired"
           }
         ]
       }
     },
     {
-      "name": "feeConfig",
-      "docs": [
-        "Account structure storing fee configuration for the protocol",
-        "Controls both the fee amount and where fees are sent"
-      ],
+      "name": "offerTaken",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "feePercentage",
-            "docs": [
-              "The fee amount in basis points (1/100th of a percent)",
-              "e.g., 100 = 1%, 50 = 0.5%, 25 = 0.25%"
-            ],
+            "name": "offerId",
             "type": "u64"
           },
           {
-            "name": "feeAddress",
-            "docs": [
-              "The public key of the account that receives protocol fees",
-              "All fees collected from trades will be sent to this address"
-            ],
+            "name": "maker",
+            "type": "pubkey"
+          },
+          {
+            "name": "taker",
+            "type": "pubkey"
+          },
+          {
+            "name": "inputTokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "paymentAmount",
+            "type": "u64"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "remainingAmount",
+            "type": "u64"
+          },
+          {
+            "name": "inputTokenMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "outputTokenMint",
             "type": "pubkey"
+          },
+          {
+            "name": "takerRemainingAllocation",
+            "type": {
+              "option": "u64"
+            }
           }
         ]
       }
     },
     {
-      "name": "feeUpdated",
+      "name": "offerUpdated",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
+            "name": "offerId",
+            "type": "u64"
+          },
+          {
+            "name": "maker",
             "type": "pubkey"
           },
           {
-            "name": "oldFee",
+            "name": "oldExpectedAmount",
             "type": "u64"
           },
           {
-            "name": "newFee",
+            "name": "newExpectedAmount",
+            "type": "u64"
+          },
+          {
+            "name": "oldDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "newDeadline",
+            "type": "i64"
+          },
+          {
+            "name": "remainingAmount",
             "type": "u64"
           },
           {
@@ -2032,22 +11112,77 @@ export type Swap = {
       }
     },
     {
-      "name": "feeWalletUpdated",
+      "name": "offerVisibility",
+      "docs": [
+        "Enum controlling which takers may fill an offer"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "public"
+          },
+          {
+            "name": "restricted"
+          }
+        ]
+      }
+    },
+    {
+      "name": "orderNonces",
+      "docs": [
+        "Account structure tracking the used nonces of a maker's signed orders",
+        "Each PDA covers `ORDER_NONCES_PER_PAGE` consecutive nonces, one bit each"
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "admin",
+            "name": "maker",
+            "docs": [
+              "The maker whose nonces are tracked"
+            ],
             "type": "pubkey"
           },
           {
-            "name": "oldWallet",
-            "type": "pubkey"
+            "name": "page",
+            "docs": [
+              "Page index, covers nonces from `page * ORDER_NONCES_PER_PAGE`"
+            ],
+            "type": "u64"
           },
           {
-            "name": "newWallet",
+            "name": "bitmap",
+            "docs": [
+              "One bit per nonce of the page, set once the nonce is filled or cancelled"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                128
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "protocolPaused",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pauser",
             "type": "pubkey"
           },
+          {
+            "name": "createPaused",
+            "type": "bool"
+          },
+          {
+            "name": "takePaused",
+            "type": "bool"
+          },
           {
             "name": "timestamp",
             "type": "i64"
@@ -2056,29 +11191,31 @@ export type Swap = {
       }
     },
     {
-      "name": "mintWhitelist",
-      "docs": [
-        "Account structure storing the whitelist of permitted token mints",
-        "This controls which tokens can be used in the protocol"
-      ],
+      "name": "protocolUnpaused",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "mints",
-            "docs": [
-              "Vector of public keys representing allowed token mints",
-              "Only tokens from these mints can be used in the protocol when whitelist is enabled"
-            ],
-            "type": {
-              "vec": "pubkey"
-            }
+            "name": "pauser",
+            "type": "pubkey"
+          },
+          {
+            "name": "createPaused",
+            "type": "bool"
+          },
+          {
+            "name": "takePaused",
+            "type": "bool"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "mintsAddedToWhitelist",
+      "name": "roleGranted",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2087,11 +11224,17 @@ export type Swap = {
             "type": "pubkey"
           },
           {
-            "name": "newMints",
+            "name": "role",
             "type": {
-              "vec": "pubkey"
+              "defined": {
+                "name": "adminRole"
+              }
             }
           },
+          {
+            "name": "holder",
+            "type": "pubkey"
+          },
           {
             "name": "timestamp",
             "type": "i64"
@@ -2100,7 +11243,7 @@ export type Swap = {
       }
     },
     {
-      "name": "mintsRemovedFromWhitelist",
+      "name": "roleRevoked",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2109,9 +11252,17 @@ export type Swap = {
             "type": "pubkey"
           },
           {
-            "name": "removedMints",
+            "name": "role",
             "type": {
-              "vec": "pubkey"
+              "defined": {
+                "name": "adminRole"
+              }
+            }
+          },
+          {
+            "name": "holder",
+            "type": {
+              "option": "pubkey"
             }
           },
           {
@@ -2122,135 +11273,91 @@ export type Swap = {
       }
     },
     {
-      "name": "offer",
+      "name": "signedOrder",
       "docs": [
-        "Account structure representing a token swap offer",
-        "Stores all details about an offer including its current state and parameters"
+        "Order signed off-chain by a maker and settled by the taker in one transaction",
+        "",
+        "The maker signs with Ed25519 the program id followed by the Borsh",
+        "serialization of the order, and approves the [\"order_delegate\", maker]",
+        "PDA as delegate of their input token account. No offer or vault is created."
       ],
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "offerId",
-            "docs": [
-              "Unique identifier for the offer"
-            ],
-            "type": "u64"
-          },
           {
             "name": "maker",
             "docs": [
-              "The public key of the account that created this offer",
-              "Controls permissions for offer modification and cancellation"
+              "Maker selling the input tokens"
             ],
             "type": "pubkey"
           },
           {
             "name": "inputTokenMint",
             "docs": [
-              "The mint address of the token being offered",
-              "Must be a whitelisted token mint if whitelist is enabled"
+              "Mint of the token being sold"
             ],
             "type": "pubkey"
           },
-          {
-            "name": "tokenAmount",
-            "docs": [
-              "The amount of tokens being offered",
-              "Decreases as partial fills occur, reaches 0 when fully filled"
-            ],
-            "type": "u64"
-          },
           {
             "name": "outputTokenMint",
             "docs": [
-              "The mint address of the token being offered",
-              "Must be a whitelisted token mint if whitelist is enabled"
+              "Mint of the token requested in return"
             ],
             "type": "pubkey"
           },
           {
-            "name": "expectedTotalAmount",
+            "name": "inputTokenAmount",
             "docs": [
-              "The total amount of payment tokens expected in return",
-              "Used to calculate the exchange rate for partial fills"
+              "Amount of input tokens sold, always filled in full"
             ],
             "type": "u64"
           },
           {
-            "name": "tokenAmountRemaining",
+            "name": "expectedTotalAmount",
             "docs": [
-              "token amount_a remaining after each trade"
+              "Amount of output tokens the maker receives"
             ],
             "type": "u64"
           },
           {
-            "name": "expectedFulfilledAmount",
+            "name": "deadline",
             "docs": [
-              "token_b fullfilled"
+              "Unix timestamp after which the order can no longer be filled"
             ],
-            "type": "u64"
+            "type": "i64"
           },
           {
-            "name": "deadline",
+            "name": "nonce",
             "docs": [
-              "Unix timestamp when this offer expires",
-              "Offer cannot be taken after this time"
+              "Maker chosen nonce, each nonce can be filled or cancelled once"
             ],
-            "type": "i64"
+            "type": "u64"
           },
           {
-            "name": "status",
+            "name": "taker",
             "docs": [
-              "Current status of the offer",
-              "Controls what operations are permitted"
+              "Only taker allowed to fill the order, None for anyone"
             ],
             "type": {
-              "defined": {
-                "name": "offerStatus"
-              }
+              "option": "pubkey"
             }
-          },
-          {
-            "name": "feePercentage",
-            "type": "u64"
-          },
-          {
-            "name": "feeWallet",
-            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "offerCancelled",
+      "name": "signedOrderCancelled",
       "type": {
         "kind": "struct",
         "fields": [
-          {
-            "name": "offerId",
-            "type": "u64"
-          },
           {
             "name": "maker",
             "type": "pubkey"
           },
           {
-            "name": "tokenAmount",
+            "name": "nonce",
             "type": "u64"
           },
-          {
-            "name": "tokenMint",
-            "type": "pubkey"
-          },
-          {
-            "name": "reason",
-            "type": {
-              "defined": {
-                "name": "cancellationReason"
-              }
-            }
-          },
           {
             "name": "timestamp",
             "type": "i64"
@@ -2259,18 +11366,22 @@ export type Swap = {
       }
     },
     {
-      "name": "offerCreated",
+      "name": "signedOrderFilled",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "offerId",
-            "type": "u64"
+            "name": "maker",
+            "type": "pubkey"
           },
           {
-            "name": "maker",
+            "name": "taker",
             "type": "pubkey"
           },
+          {
+            "name": "nonce",
+            "type": "u64"
+          },
           {
             "name": "inputTokenMint",
             "type": "pubkey"
@@ -2280,52 +11391,85 @@ export type Swap = {
             "type": "pubkey"
           },
           {
-            "name": "tokenAmount",
+            "name": "inputTokenAmount",
             "type": "u64"
           },
           {
-            "name": "expectedAmount",
+            "name": "paymentAmount",
             "type": "u64"
           },
           {
-            "name": "deadline",
+            "name": "feeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
             "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "offerStatus",
-      "docs": [
-        "Enum representing the possible states of an offer",
-        "Used to track the offer's lifecycle and control permitted operations"
-      ],
+      "name": "takerAdded",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "kind": "struct",
+        "fields": [
           {
-            "name": "initialized"
+            "name": "offerId",
+            "type": "u64"
           },
           {
-            "name": "vaultInitialized"
+            "name": "maker",
+            "type": "pubkey"
           },
           {
-            "name": "ongoing"
+            "name": "taker",
+            "type": "pubkey"
           },
           {
-            "name": "completed"
+            "name": "allocation",
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "takerFill",
+      "docs": [
+        "Account structure tracking how much a taker has filled on an offer",
+        "Used to enforce allocations that are not stored in the offer whitelist"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "offer",
+            "docs": [
+              "The offer the fills were made on"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "cancelled"
+            "name": "taker",
+            "docs": [
+              "The taker who made the fills"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "expired"
+            "name": "filledAmount",
+            "docs": [
+              "Total input tokens taken by this taker on the offer"
+            ],
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "offerTaken",
+      "name": "takerMerkleRootUpdated",
       "type": {
         "kind": "struct",
         "fields": [
@@ -2338,31 +11482,49 @@ export type Swap = {
             "type": "pubkey"
           },
           {
-            "name": "taker",
-            "type": "pubkey"
-          },
-          {
-            "name": "inputTokenAmount",
-            "type": "u64"
+            "name": "oldRoot",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
           },
           {
-            "name": "paymentAmount",
-            "type": "u64"
+            "name": "newRoot",
+            "type": {
+              "option": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
           },
           {
-            "name": "feeAmount",
-            "type": "u64"
-          },
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "takerRemoved",
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "remainingAmount",
+            "name": "offerId",
             "type": "u64"
           },
           {
-            "name": "inputTokenMint",
+            "name": "maker",
             "type": "pubkey"
           },
           {
-            "name": "outputTokenMint",
+            "name": "taker",
             "type": "pubkey"
           }
         ]
@@ -2384,17 +11546,42 @@ export type Swap = {
           {
             "name": "takers",
             "type": {
-              "vec": "pubkey"
+              "vec": {
+                "defined": {
+                  "name": "whitelistEntry"
+                }
+              }
             }
           }
         ]
       }
     },
+    {
+      "name": "takerWhitelistConfig",
+      "docs": [
+        "Account structure storing protocol-wide limits for offer taker whitelists"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxTakers",
+            "docs": [
+              "Maximum number of takers a single offer whitelist can hold"
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
     {
       "name": "whitelist",
       "docs": [
         "Account structure representing a whitelist of allowed takers for a specific offer",
-        "Controls which addresses can take (accept) a particular offer"
+        "Controls which addresses can take (accept) a particular offer",
+        "",
+        "Entries now carry allocations, whitelists created by the original program can't be",
+        "decoded and their offers must be cancelled before an existing deployment is upgraded"
       ],
       "type": {
         "kind": "struct",
@@ -2417,12 +11604,16 @@ export type Swap = {
           {
             "name": "takers",
             "docs": [
-              "Vector of public keys representing addresses allowed to take the offer",
+              "Vector of takers allowed to take the offer, with their allocations",
               "Only these addresses can execute trades against the offer when whitelist is enabled",
               "Empty vector means no takers are currently whitelisted"
             ],
             "type": {
-              "vec": "pubkey"
+              "vec": {
+                "defined": {
+                  "name": "whitelistEntry"
+                }
+              }
             }
           }
         ]
@@ -2449,6 +11640,34 @@ export type Swap = {
         ]
       }
     },
+    {
+      "name": "whitelistEntry",
+      "docs": [
+        "A single taker allowed to take an offer"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "taker",
+            "docs": [
+              "Public key of the allowed taker"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "allocation",
+            "docs": [
+              "Input token amount this taker can still take",
+              "None means the taker is not capped"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          }
+        ]
+      }
+    },
     {
       "name": "whitelistRequirementToggled",
       "type": {
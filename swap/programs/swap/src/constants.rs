@@ -1 +1,22 @@
+/// Default maximum number of takers a single offer whitelist can hold
+/// Used until the admin configures a protocol-wide maximum
+pub const MAX_WHITELIST_TAKERS: usize = 50;
 
+/// Largest maximum whitelist size the admin can configure
+/// Takes deserialize the whole whitelist into the 32 KiB bump heap, which never
+/// frees. At 48 bytes per entry, 80 entries stay within the single 4 KiB
+/// allocation borsh reserves up front, so loading it never grows the vector.
+pub const WHITELIST_TAKERS_LIMIT: u32 = 80;
+
+/// Number of signed order nonces tracked by a single nonce bitmap PDA
+pub const ORDER_NONCES_PER_PAGE: u64 = 1024;
+
+/// Maximum number of signers in the admin multisig
+pub const MAX_ADMIN_SIGNERS: usize = 10;
+
+/// Maximum expiry bounty, in basis points of the returned tokens
+pub const MAX_EXPIRY_BOUNTY: u64 = 500;
+
+/// Seconds the arbitrator has to resolve a dispute after the fill's acceptance deadline
+/// Once passed anyone can reject the disputed fill
+pub const DISPUTE_RESOLUTION_PERIOD: i64 = 7 * 24 * 60 * 60;
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked,
+    load_instruction_at_checked,
+};
+
+/// Size of the Ed25519 instruction header (signature count + padding)
+const ED25519_HEADER_LEN: usize = 2;
+
+/// Size of one Ed25519 signature offsets entry (7 x u16)
+const ED25519_OFFSETS_LEN: usize = 14;
+
+/// Size of an Ed25519 public key
+const ED25519_PUBKEY_LEN: usize = 32;
+
+/// Instruction index telling the Ed25519 program that the data lives in its own instruction
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Checks whether the instruction right before the current one is an
+/// Ed25519 program instruction verifying `signer`'s signature over `message`
+///
+/// The Ed25519 program itself rejects the transaction if the signature is
+/// invalid, so only the signed public key and message are checked here.
+pub fn has_preceding_signature(instructions_sysvar: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<bool> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    if current_index == 0 {
+        return Ok(false);
+    }
+
+    let ed25519_ix = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    if ed25519_ix.program_id != ed25519_program::ID {
+        return Ok(false);
+    }
+
+    Ok(parse_ed25519_instruction(&ed25519_ix.data)
+        .is_some_and(|(public_key, signed_message)| public_key == signer.as_ref() && signed_message == message))
+}
+
+/// Extracts the public key and message of a single signature Ed25519
+/// instruction whose data is stored in the instruction itself
+fn parse_ed25519_instruction(data: &[u8]) -> Option<(&[u8], &[u8])> {
+    if data.len() < ED25519_HEADER_LEN + ED25519_OFFSETS_LEN || data[0] != 1 {
+        return None;
+    }
+
+    let offsets = &data[ED25519_HEADER_LEN..ED25519_HEADER_LEN + ED25519_OFFSETS_LEN];
+    let read_u16 = |index: usize| u16::from_le_bytes([offsets[index * 2], offsets[index * 2 + 1]]);
+
+    let signature_instruction_index = read_u16(1);
+    let public_key_offset = read_u16(2) as usize;
+    let public_key_instruction_index = read_u16(3);
+    let message_offset = read_u16(4) as usize;
+    let message_size = read_u16(5) as usize;
+    let message_instruction_index = read_u16(6);
+
+    // Data stored in other instructions could be swapped without the signature covering it
+    if signature_instruction_index != ED25519_CURRENT_INSTRUCTION
+        || public_key_instruction_index != ED25519_CURRENT_INSTRUCTION
+        || message_instruction_index != ED25519_CURRENT_INSTRUCTION
+    {
+        return None;
+    }
+
+    let public_key = data.get(public_key_offset..public_key_offset.checked_add(ED25519_PUBKEY_LEN)?)?;
+    let message = data.get(message_offset..message_offset.checked_add(message_size)?)?;
+    Some((public_key, message))
+}
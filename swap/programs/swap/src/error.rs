@@ -56,4 +56,118 @@ pub enum SwapError {
     CalculationError,
     #[msg("Invalid vault owner")]
     InvalidVaultOwner,
+    #[msg("Payment amount exceeds taker's maximum")]
+    MaxPaymentExceeded,
+    #[msg("Fee amount exceeds taker's maximum")]
+    MaxFeeExceeded,
+    #[msg("Input token amount below taker's minimum")]
+    MinInputNotMet,
+    #[msg("Start time must be before the deadline")]
+    InvalidStartTime,
+    #[msg("Offer has not started yet")]
+    OfferNotStarted,
+    #[msg("Minimum fill amount cannot exceed token amount")]
+    InvalidMinFillAmount,
+    #[msg("Fill amount below offer minimum")]
+    FillBelowMinimum,
+    #[msg("Offer must be filled in full")]
+    PartialFillNotAllowed,
+    #[msg("Fill exceeds taker's allocation")]
+    AllocationExceeded,
+    #[msg("Maximum whitelist size must be between 1 and 80")]
+    InvalidWhitelistSize,
+    #[msg("Invalid Merkle proof")]
+    InvalidMerkleProof,
+    #[msg("Taker fill account is required for capped allocations")]
+    TakerFillRequired,
+    #[msg("Invalid allow ticket")]
+    InvalidAllowTicket,
+    #[msg("Allow ticket has expired")]
+    AllowTicketExpired,
+    #[msg("Instructions sysvar is required for allow tickets")]
+    InstructionsSysvarRequired,
+    #[msg("Invalid signed order signature")]
+    InvalidOrderSignature,
+    #[msg("Signed order has expired")]
+    OrderExpired,
+    #[msg("Order nonce has already been used")]
+    OrderNonceUsed,
+    #[msg("Signed order is reserved for another taker")]
+    OrderTakerMismatch,
+    #[msg("Maker token account delegation is missing or too small")]
+    InsufficientDelegation,
+    #[msg("Vault token account doesn't match the offer custody")]
+    InvalidCustodyAccounts,
+    #[msg("Acceptance window must be greater than 0")]
+    InvalidAcceptanceWindow,
+    #[msg("Escrowed offers must hold their tokens in a vault")]
+    EscrowRequiresVault,
+    #[msg("Fill and payment escrow accounts are required for escrowed offers")]
+    EscrowAccountRequired,
+    #[msg("Fill acceptance window has passed")]
+    FillAcceptanceExpired,
+    #[msg("Only the maker can reject a fill before its acceptance deadline")]
+    CannotRejectFill,
+    #[msg("Offer has pending escrowed fills")]
+    PendingFills,
+    #[msg("Invalid Taker")]
+    InvalidTaker,
+    #[msg("Arbitrated offers must escrow their fills")]
+    ArbitratorRequiresEscrow,
+    #[msg("Offer has no arbitrator")]
+    NoArbitrator,
+    #[msg("Only the maker or the taker can dispute a fill")]
+    CannotDisputeFill,
+    #[msg("Dispute window has passed")]
+    DisputeWindowClosed,
+    #[msg("Fill is disputed")]
+    FillDisputed,
+    #[msg("Fill is not disputed")]
+    FillNotDisputed,
+    #[msg("Only the offer arbitrator can resolve disputes")]
+    UnauthorizedArbitrator,
+    #[msg("Dispute split exceeds the escrowed amounts")]
+    InvalidDisputeSplit,
+    #[msg("Fee config and maker token account are required to resolve disputes")]
+    DisputeAccountsRequired,
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+    #[msg("Admin actions must go through a multisig proposal")]
+    MultisigActive,
+    #[msg("Admin multisig is not enabled")]
+    MultisigNotActive,
+    #[msg("Invalid multisig signers or threshold")]
+    InvalidMultisig,
+    #[msg("Signer is not a member of the admin multisig")]
+    NotMultisigSigner,
+    #[msg("Proposal has already been approved by this signer")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal has not reached the approval threshold")]
+    ProposalThresholdNotMet,
+    #[msg("An account required by this proposal is missing")]
+    ProposalAccountsRequired,
+    #[msg("Role is already held by this account")]
+    RoleAlreadyGranted,
+    #[msg("Creating offers is paused")]
+    CreatePaused,
+    #[msg("Taking offers is paused")]
+    TakePaused,
+    #[msg("Trading of this mint is halted")]
+    MintHalted,
+    #[msg("Mint is denied")]
+    MintDenied,
+    #[msg("Trade amount is below the mint's minimum trade size")]
+    TradeBelowMinimum,
+    #[msg("Trade amount is above the mint's maximum trade size")]
+    TradeAboveMaximum,
+    #[msg("Minimum trade size exceeds the maximum trade size")]
+    InvalidTradeLimits,
+    #[msg("Account does not belong to the offer being taken")]
+    OfferAccountMismatch,
+    #[msg("Expiry bounty exceeds the maximum")]
+    InvalidExpiryBounty,
+    #[msg("Offer id must be greater than the maker's previous offer ids")]
+    OfferIdAlreadyUsed,
 }
\ No newline at end of file
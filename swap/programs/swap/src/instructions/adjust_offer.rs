@@ -0,0 +1,256 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface}
+};
+use crate::state::*;
+use crate::error::*;
+use crate::instructions::create_offer::approve_offer_delegate;
+
+#[event]
+pub struct OfferAmountUpdated {
+    pub offer_id: u64,
+    pub maker: Pubkey,
+    pub old_token_amount: u64,
+    pub new_token_amount: u64,
+    pub remaining_amount: u64,
+    pub expected_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Account validation structure for topping up or withdrawing from an offer's vault
+/// Shared by the increase and decrease offer instructions
+/// Delegated offers re-approve the offer PDA for the new remaining amount instead
+#[derive(Accounts)]
+pub struct AdjustOffer<'info> {
+    /// Original offer maker who sends or receives the tokens
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    /// The offer being adjusted
+    ///
+    /// Constraints:
+    /// - Must be signed by original maker
+    /// - Must be in Ongoing status
+    ///
+    /// Seeds: ["offer", maker_pubkey, offer_id]
+    #[account(
+        mut,
+        constraint = offer.maker == maker.key() @ SwapError::UnauthorizedMaker,
+        constraint = offer.status == OfferStatus::Ongoing @ SwapError::InvalidOfferStatus,
+        seeds = [b"offer", maker.key().as_ref(), &offer.offer_id.to_le_bytes()],
+        bump,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    /// Maker's token account for the input token
+    /// Source of top-ups and destination of withdrawals
+    #[account(
+        mut,
+        constraint = maker_token_account.owner == maker.key() @ SwapError::InvalidTokenAccount,
+        constraint = maker_token_account.mint == offer.input_token_mint @ SwapError::InvalidTokenMint
+    )]
+    pub maker_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// The vault token account holding the offered tokens
+    /// Authority is the offer PDA
+    /// Only passed for vault offers
+    #[account(
+        mut,
+        associated_token::mint = input_token_mint,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Input token mint (token being offered)
+    #[account(
+        constraint = input_token_mint.key() == offer.input_token_mint @ SwapError::InvalidTokenMint
+    )]
+    pub input_token_mint: InterfaceAccount<'info, Mint>,
+
+    /// Token interface program for Token-2022 support
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Required for ATA validation
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Adds more input tokens to an ongoing offer
+///
+/// The expected amount is scaled with the token amount, rounded up,
+/// so the price of the offer never drops.
+///
+/// # Arguments
+/// * `ctx` - AdjustOffer context
+/// * `amount` - Amount of input tokens to move from the maker into the vault
+///
+/// # Errors
+/// * `SwapError::InvalidAmount` - If amount is zero
+/// * `SwapError::OfferExpired` - If offer deadline has passed
+/// * `SwapError::CalculationError` - If the new amounts overflow
+/// * `SwapError::InvalidCustodyAccounts` - If the vault is missing for a vault offer
+pub fn increase_offer_amount(ctx: Context<AdjustOffer>, amount: u64) -> Result<()> {
+    require!(amount > 0, SwapError::InvalidAmount);
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time <= ctx.accounts.offer.deadline, SwapError::OfferExpired);
+
+    let offer = &ctx.accounts.offer;
+    let old_token_amount = offer.token_amount;
+    let new_token_amount = old_token_amount
+        .checked_add(amount)
+        .ok_or(SwapError::CalculationError)?;
+    let new_remaining = offer.token_amount_remaining
+        .checked_add(amount)
+        .ok_or(SwapError::CalculationError)?;
+    let new_expected = scale_expected_amount(offer, new_token_amount)?;
+
+    match offer.custody {
+        OfferCustody::Vault => {
+            let vault_token_account = ctx.accounts.vault_token_account
+                .as_ref()
+                .ok_or(SwapError::InvalidCustodyAccounts)?;
+
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_interface::TransferChecked {
+                        from: ctx.accounts.maker_token_account.to_account_info(),
+                        mint: ctx.accounts.input_token_mint.to_account_info(),
+                        to: vault_token_account.to_account_info(),
+                        authority: ctx.accounts.maker.to_account_info(),
+                    },
+                ),
+                amount,
+                ctx.accounts.input_token_mint.decimals,
+            )?;
+        }
+        OfferCustody::Delegate => {
+            approve_offer_delegate(
+                &ctx.accounts.token_program,
+                &ctx.accounts.maker_token_account,
+                offer,
+                &ctx.accounts.maker,
+                new_remaining,
+            )?;
+        }
+    }
+
+    let offer = &mut ctx.accounts.offer;
+    offer.token_amount = new_token_amount;
+    offer.token_amount_remaining = new_remaining;
+    offer.expected_total_amount = new_expected;
+
+    emit!(OfferAmountUpdated {
+        offer_id: offer.offer_id,
+        maker: offer.maker,
+        old_token_amount,
+        new_token_amount,
+        remaining_amount: new_remaining,
+        expected_amount: new_expected,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// Withdraws part of the remaining input tokens from an ongoing offer
+///
+/// The expected amount is scaled with the token amount, rounded up,
+/// so the price of the offer never drops. Withdrawing everything is
+/// done through `cancel_offer`.
+///
+/// # Arguments
+/// * `ctx` - AdjustOffer context
+/// * `amount` - Amount of input tokens to move from the vault back to the maker
+///
+/// # Errors
+/// * `SwapError::InvalidAmount` - If amount is zero
+/// * `SwapError::InsufficientAmount` - If amount is not below the remaining amount
+/// * `SwapError::InvalidCustodyAccounts` - If the vault is missing for a vault offer
+pub fn decrease_offer_amount(ctx: Context<AdjustOffer>, amount: u64) -> Result<()> {
+    require!(amount > 0, SwapError::InvalidAmount);
+    require!(amount < ctx.accounts.offer.token_amount_remaining, SwapError::InsufficientAmount);
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let offer = &ctx.accounts.offer;
+    let old_token_amount = offer.token_amount;
+    let new_token_amount = old_token_amount
+        .checked_sub(amount)
+        .ok_or(SwapError::CalculationError)?;
+    let new_remaining = offer.token_amount_remaining
+        .checked_sub(amount)
+        .ok_or(SwapError::CalculationError)?;
+    let new_expected = scale_expected_amount(offer, new_token_amount)?;
+
+    let seeds = &[
+        b"offer",
+        offer.maker.as_ref(),
+        &offer.offer_id.to_le_bytes(),
+        &[ctx.bumps.offer],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    match offer.custody {
+        OfferCustody::Vault => {
+            let vault_token_account = ctx.accounts.vault_token_account
+                .as_ref()
+                .ok_or(SwapError::InvalidCustodyAccounts)?;
+
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_interface::TransferChecked {
+                        from: vault_token_account.to_account_info(),
+                        mint: ctx.accounts.input_token_mint.to_account_info(),
+                        to: ctx.accounts.maker_token_account.to_account_info(),
+                        authority: ctx.accounts.offer.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+                ctx.accounts.input_token_mint.decimals,
+            )?;
+        }
+        OfferCustody::Delegate => {
+            approve_offer_delegate(
+                &ctx.accounts.token_program,
+                &ctx.accounts.maker_token_account,
+                offer,
+                &ctx.accounts.maker,
+                new_remaining,
+            )?;
+        }
+    }
+
+    let offer = &mut ctx.accounts.offer;
+    offer.token_amount = new_token_amount;
+    offer.token_amount_remaining = new_remaining;
+    offer.expected_total_amount = new_expected;
+
+    emit!(OfferAmountUpdated {
+        offer_id: offer.offer_id,
+        maker: offer.maker,
+        old_token_amount,
+        new_token_amount,
+        remaining_amount: new_remaining,
+        expected_amount: new_expected,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// Scales the expected amount to a new token amount at the offer's current price
+/// Rounds up so adjusting an offer never lowers the maker's price
+fn scale_expected_amount(offer: &Offer, new_token_amount: u64) -> Result<u64> {
+    let token_amount = offer.token_amount as u128;
+    let expected = (offer.expected_total_amount as u128)
+        .checked_mul(new_token_amount as u128)
+        .and_then(|scaled| scaled.checked_add(token_amount.checked_sub(1)?))
+        .ok_or(SwapError::CalculationError)?
+        .checked_div(token_amount)
+        .ok_or(SwapError::CalculationError)?;
+
+    u64::try_from(expected).map_err(|_| SwapError::CalculationError.into())
+}
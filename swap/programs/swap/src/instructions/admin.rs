@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::Mint;
 use crate::state::*;
 use crate::error::*;
+use crate::constants::*;
 
 #[event]
 pub struct AdminInitialized {
@@ -20,6 +24,14 @@ pub struct FeeUpdated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ExpiryBountyUpdated {
+    pub admin: Pubkey,
+    pub old_bounty: u64,
+    pub new_bounty: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct FeeWalletUpdated {
     pub admin: Pubkey,
@@ -35,6 +47,92 @@ pub struct WhitelistRequirementToggled {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct MaxWhitelistTakersUpdated {
+    pub admin: Pubkey,
+    pub old_max: u32,
+    pub new_max: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub admin: Pubkey,
+    pub role: AdminRole,
+    pub holder: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub admin: Pubkey,
+    pub role: AdminRole,
+    pub holder: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtocolPaused {
+    pub pauser: Pubkey,
+    pub create_paused: bool,
+    pub take_paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProtocolUnpaused {
+    pub pauser: Pubkey,
+    pub create_paused: bool,
+    pub take_paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintHaltUpdated {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub halted: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintDenyUpdated {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub denied: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintListed {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub min_trade_amount: u64,
+    pub max_trade_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MintDelisted {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct MintsAddedToWhitelist {
     pub admin: Pubkey,
@@ -59,13 +157,11 @@ pub struct Initialize<'info> {
     pub admin: Signer<'info>,
     
     /// PDA storing admin details and protocol statistics
-    /// Space breakdown:
-    /// - 8 bytes discriminator
-    /// - 32 bytes admin pubkey
+    /// Space breakdown in AdminConfig::SPACE
     #[account(
         init,
         payer = admin,
-        space = 8 + 32,
+        space = AdminConfig::SPACE,
         seeds = [b"admin_config"],
         bump
     )]
@@ -112,6 +208,17 @@ pub struct Initialize<'info> {
     )]
     pub mint_whitelist: Account<'info, MintWhitelist>,
 
+    /// PDA storing the holders of the delegated admin roles
+    /// Space breakdown in AdminRoles::SPACE
+    #[account(
+        init,
+        payer = admin,
+        space = AdminRoles::SPACE,
+        seeds = [b"admin_roles"],
+        bump
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
     /// Global PDA for tracking maker sequences
     // #[account(
     //     init,
@@ -126,19 +233,22 @@ pub struct Initialize<'info> {
 }
 
 /// Account validation struct for updating fee recipient address
+/// Instructions gated by a role in AdminRoles stay available in multisig mode,
+/// where roles are cleared on enabling and only granted through executed proposals.
+/// The super-admin ones below are disabled once the admin multisig is enabled
 #[derive(Accounts)]
 pub struct UpdateFeeAddress<'info> {
-    /// Admin signer
+    /// Fee manager signer
     #[account(mut)]
     pub admin: Signer<'info>,
 
-    /// PDA verifying admin authority
+    /// PDA verifying the signer holds the fee manager role
     #[account(
-        seeds = [b"admin_config"],
+        seeds = [b"admin_roles"],
         bump,
-        constraint = admin_config.admin == admin.key() @ SwapError::UnauthorizedAdmin
+        constraint = admin_roles.has_role(AdminRole::FeeManager, &admin.key()) @ SwapError::UnauthorizedAdmin
     )]
-    pub admin_config: Account<'info, AdminConfig>,
+    pub admin_roles: Account<'info, AdminRoles>,
 
     /// Fee configuration to update
     #[account(
@@ -154,17 +264,17 @@ pub struct UpdateFeeAddress<'info> {
 /// Account validation struct for updating fee percentage
 #[derive(Accounts)]
 pub struct UpdateFee<'info> {
-    /// Admin signer
+    /// Fee manager signer
     #[account(mut)]
     pub admin: Signer<'info>,
 
-    /// PDA verifying admin authority
+    /// PDA verifying the signer holds the fee manager role
     #[account(
-        seeds = [b"admin_config"],
+        seeds = [b"admin_roles"],
         bump,
-        constraint = admin_config.admin == admin.key() @ SwapError::UnauthorizedAdmin
+        constraint = admin_roles.has_role(AdminRole::FeeManager, &admin.key()) @ SwapError::UnauthorizedAdmin
     )]
-    pub admin_config: Account<'info, AdminConfig>,
+    pub admin_roles: Account<'info, AdminRoles>,
 
     /// Fee configuration to update
     #[account(
@@ -177,20 +287,52 @@ pub struct UpdateFee<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Account validation struct for updating the expiry bounty
+#[derive(Accounts)]
+pub struct UpdateExpiryBounty<'info> {
+    /// Fee manager signer
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// PDA verifying the signer holds the fee manager role
+    #[account(
+        seeds = [b"admin_roles"],
+        bump,
+        constraint = admin_roles.has_role(AdminRole::FeeManager, &admin.key()) @ SwapError::UnauthorizedAdmin
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    /// PDA storing the expiry bounty
+    /// Created on first use with init_if_needed
+    /// Space breakdown:
+    /// - 8 bytes discriminator
+    /// - 8 bytes expiry bounty
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + 8,
+        seeds = [b"expiry_config"],
+        bump
+    )]
+    pub expiry_config: Account<'info, ExpiryConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
 /// Account validation struct for toggling whitelist requirement
 #[derive(Accounts)]
 pub struct ToggleRequireWhitelist<'info> {
-    /// Admin signer
+    /// Listing manager signer
     #[account(mut)]
     pub admin: Signer<'info>,
 
-    /// PDA verifying admin authority
+    /// PDA verifying the signer holds the listing manager role
     #[account(
-        seeds = [b"admin_config"],
+        seeds = [b"admin_roles"],
         bump,
-        constraint = admin_config.admin == admin.key() @ SwapError::UnauthorizedAdmin
+        constraint = admin_roles.has_role(AdminRole::ListingManager, &admin.key()) @ SwapError::UnauthorizedAdmin
     )]
-    pub admin_config: Account<'info, AdminConfig>,
+    pub admin_roles: Account<'info, AdminRoles>,
 
     /// Whitelist configuration to toggle
     #[account(
@@ -203,10 +345,10 @@ pub struct ToggleRequireWhitelist<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// Account validation struct for modifying mint whitelist
+/// Account validation struct for setting the maximum taker whitelist size
 #[derive(Accounts)]
-pub struct ModifyMintWhitelist<'info> {
-    /// Admin signer
+pub struct UpdateMaxWhitelistTakers<'info> {
+    /// Admin signer, pays for the config on first use
     #[account(mut)]
     pub admin: Signer<'info>,
 
@@ -214,9 +356,262 @@ pub struct ModifyMintWhitelist<'info> {
     #[account(
         seeds = [b"admin_config"],
         bump,
+        constraint = !admin_config.is_multisig() @ SwapError::MultisigActive,
+        constraint = admin_config.admin == admin.key() @ SwapError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// PDA storing taker whitelist limits
+    /// Created on first use with init_if_needed
+    /// Space breakdown:
+    /// - 8 bytes discriminator
+    /// - 4 bytes maximum takers
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + 4,
+        seeds = [b"taker_whitelist_config"],
+        bump
+    )]
+    pub taker_whitelist_config: Account<'info, TakerWhitelistConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation struct for proposing a new admin
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    /// Current admin signer
+    pub admin: Signer<'info>,
+
+    /// PDA storing the pending admin
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump,
+        constraint = !admin_config.is_multisig() @ SwapError::MultisigActive,
         constraint = admin_config.admin == admin.key() @ SwapError::UnauthorizedAdmin
     )]
     pub admin_config: Account<'info, AdminConfig>,
+}
+
+/// Account validation struct for accepting the admin role
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// Proposed admin signer, pays for the roles registry on first use
+    #[account(mut)]
+    pub new_admin: Signer<'info>,
+
+    /// PDA storing the admin
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump,
+        constraint = admin_config.pending_admin == Some(new_admin.key()) @ SwapError::NotPendingAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// PDA storing the role holders, roles held by the outgoing admin are revoked
+    /// Space breakdown in AdminRoles::SPACE
+    #[account(
+        init_if_needed,
+        payer = new_admin,
+        space = AdminRoles::SPACE,
+        seeds = [b"admin_roles"],
+        bump
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation struct for growing a legacy admin config
+#[derive(Accounts)]
+pub struct MigrateAdminConfig<'info> {
+    /// Admin signer, pays for the extra rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Legacy config that can't be deserialized as AdminConfig yet,
+    /// its discriminator and admin are checked in the handler
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump,
+    )]
+    pub admin_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation struct for granting and revoking admin roles
+#[derive(Accounts)]
+pub struct ManageRoles<'info> {
+    /// Super-admin signer, pays for the roles registry on first use
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// PDA verifying super-admin authority
+    #[account(
+        seeds = [b"admin_config"],
+        bump,
+        constraint = !admin_config.is_multisig() @ SwapError::MultisigActive,
+        constraint = admin_config.admin == admin.key() @ SwapError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// PDA storing the role holders
+    /// Created on first use for configs initialized before roles existed
+    /// Space breakdown in AdminRoles::SPACE
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminRoles::SPACE,
+        seeds = [b"admin_roles"],
+        bump
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation struct for pausing and unpausing the protocol
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    /// Pauser signer
+    pub pauser: Signer<'info>,
+
+    /// PDA storing the pause flags
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump,
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// PDA verifying the signer holds the pauser role
+    #[account(
+        seeds = [b"admin_roles"],
+        bump,
+        constraint = admin_roles.has_role(AdminRole::Pauser, &pauser.key()) @ SwapError::UnauthorizedAdmin
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+}
+
+/// Account validation struct for halting or denying a single mint
+#[derive(Accounts)]
+pub struct SetMintConfig<'info> {
+    /// Listing manager signer, pays for the mint config on first use
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// PDA verifying the signer holds the listing manager role
+    #[account(
+        seeds = [b"admin_roles"],
+        bump,
+        constraint = admin_roles.has_role(AdminRole::ListingManager, &admin.key()) @ SwapError::UnauthorizedAdmin
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    /// Mint the restriction applies to
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// PDA storing the mint's restrictions
+    /// Created on first use with init_if_needed
+    /// Space breakdown:
+    /// - 8 bytes discriminator
+    /// - 32 bytes mint pubkey
+    /// - 1 byte halted flag
+    /// - 1 byte denied flag
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + 32 + 1 + 1,
+        seeds = [b"mint_config", mint.key().as_ref()],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation struct for listing a mint or updating its listing
+#[derive(Accounts)]
+pub struct ListMint<'info> {
+    /// Listing manager signer, pays for the listing
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// PDA verifying the signer holds the listing manager role
+    #[account(
+        seeds = [b"admin_roles"],
+        bump,
+        constraint = admin_roles.has_role(AdminRole::ListingManager, &admin.key()) @ SwapError::UnauthorizedAdmin
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    /// Mint being listed
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// PDA storing the listing
+    /// Space breakdown:
+    /// - 8 bytes discriminator
+    /// - 32 bytes mint pubkey
+    /// - 8 bytes listing time
+    /// - 32 bytes listing manager pubkey
+    /// - 8 bytes minimum trade amount
+    /// - 8 bytes maximum trade amount
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + 32 + 8 + 32 + 8 + 8,
+        seeds = [b"listed_mint", mint.key().as_ref()],
+        bump
+    )]
+    pub listed_mint: Account<'info, ListedMint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation struct for delisting a mint
+#[derive(Accounts)]
+pub struct DelistMint<'info> {
+    /// Listing manager signer, receives the listing rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// PDA verifying the signer holds the listing manager role
+    #[account(
+        seeds = [b"admin_roles"],
+        bump,
+        constraint = admin_roles.has_role(AdminRole::ListingManager, &admin.key()) @ SwapError::UnauthorizedAdmin
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    /// Listing being closed
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"listed_mint", listed_mint.mint.as_ref()],
+        bump
+    )]
+    pub listed_mint: Account<'info, ListedMint>,
+}
+
+/// Account validation struct for modifying mint whitelist
+#[derive(Accounts)]
+pub struct ModifyMintWhitelist<'info> {
+    /// Listing manager signer
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// PDA verifying the signer holds the listing manager role
+    #[account(
+        seeds = [b"admin_roles"],
+        bump,
+        constraint = admin_roles.has_role(AdminRole::ListingManager, &admin.key()) @ SwapError::UnauthorizedAdmin
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
 
     /// Mint whitelist to modify
     #[account(
@@ -255,6 +650,7 @@ pub fn initialize(
     // Initialize admin configuration
     let admin_config = &mut ctx.accounts.admin_config;
     admin_config.admin = ctx.accounts.admin.key();
+    admin_config.pending_admin = None;
     // admin_config.total_offers = 0;
     // admin_config.active_offers = 0;
     // admin_config.completed_offers = 0;
@@ -263,6 +659,12 @@ pub fn initialize(
     // admin_config.last_expiry_check = Clock::get()?.unix_timestamp;
     // admin_config.maker_sequence_pda = ctx.accounts.maker_sequence_pda.key();
     
+    // The initializing admin starts with every role
+    let admin_roles = &mut ctx.accounts.admin_roles;
+    admin_roles.fee_manager = Some(ctx.accounts.admin.key());
+    admin_roles.listing_manager = Some(ctx.accounts.admin.key());
+    admin_roles.pauser = Some(ctx.accounts.admin.key());
+
     // Initialize fee configuration
     let fee_config = &mut ctx.accounts.fee_config;
     fee_config.fee_percentage = fee_percentage;
@@ -286,7 +688,7 @@ pub fn initialize(
         fee_percentage,
         fee_wallet,
         require_whitelist,
-        initial_mints: initial_mints,
+        initial_mints,
         timestamp: Clock::get()?.unix_timestamp,
     });
     
@@ -302,18 +704,7 @@ pub fn fee_address_update(
     ctx: Context<UpdateFeeAddress>, 
     new_address: Pubkey
 ) -> Result<()> {
-    require!(new_address != Pubkey::default(), SwapError::InvalidAddress);
-    let old_wallet = ctx.accounts.fee_config.fee_address;
-    ctx.accounts.fee_config.fee_address = new_address;
-
-    emit!(FeeWalletUpdated {
-        admin: ctx.accounts.admin.key(),
-        old_wallet,
-        new_wallet: new_address,
-        timestamp: Clock::get()?.unix_timestamp,
-    });
-
-    Ok(())
+    apply_fee_address(&mut ctx.accounts.fee_config, ctx.accounts.admin.key(), new_address)
 }
 
 /// Updates the protocol fee percentage
@@ -328,19 +719,23 @@ pub fn fee_update(
     ctx: Context<UpdateFee>, 
     new_fee: u64
 ) -> Result<()> {
-    let old_fee = ctx.accounts.fee_config.fee_percentage;
-    
-    require!(new_fee <= 10000, SwapError::InvalidFeePercentage);
-    ctx.accounts.fee_config.fee_percentage = new_fee;
+    apply_fee(&mut ctx.accounts.fee_config, ctx.accounts.admin.key(), new_fee)
+}
 
-    emit!(FeeUpdated {
-        admin: ctx.accounts.admin.key(),
-        old_fee,
-        new_fee,
-        timestamp: Clock::get()?.unix_timestamp,
-    });
-    
-    Ok(())
+/// Updates the bounty paid for expiring offers
+/// Only offers created afterwards pay the new bounty
+///
+/// # Arguments
+/// * `ctx` - UpdateExpiryBounty context
+/// * `new_bounty` - New bounty in basis points of the returned tokens
+///
+/// # Errors
+/// * `SwapError::InvalidExpiryBounty` - If bounty exceeds MAX_EXPIRY_BOUNTY
+pub fn expiry_bounty_update(
+    ctx: Context<UpdateExpiryBounty>,
+    new_bounty: u64
+) -> Result<()> {
+    apply_expiry_bounty(&mut ctx.accounts.expiry_config, ctx.accounts.admin.key(), new_bounty)
 }
 
 /// Toggles the token mint whitelist requirement
@@ -350,16 +745,22 @@ pub fn fee_update(
 pub fn update_toggle_whitelist(
     ctx: Context<ToggleRequireWhitelist>
 ) -> Result<()> {
-    let whitelist_config = &mut ctx.accounts.whitelist_config;
-    whitelist_config.require_whitelist = !whitelist_config.require_whitelist;
-
-    emit!(WhitelistRequirementToggled {
-        admin: ctx.accounts.admin.key(),
-        new_status: whitelist_config.require_whitelist,
-        timestamp: Clock::get()?.unix_timestamp,
-    });
+    apply_toggle_whitelist(&mut ctx.accounts.whitelist_config, ctx.accounts.admin.key())
+}
 
-    Ok(())
+/// Sets the maximum number of takers an offer whitelist can hold
+///
+/// # Arguments
+/// * `ctx` - UpdateMaxWhitelistTakers context
+/// * `max_takers` - New maximum number of takers per whitelist
+///
+/// # Errors
+/// * `SwapError::InvalidWhitelistSize` - If the maximum is zero or above `WHITELIST_TAKERS_LIMIT`
+pub fn max_whitelist_takers_update(
+    ctx: Context<UpdateMaxWhitelistTakers>,
+    max_takers: u32,
+) -> Result<()> {
+    apply_max_whitelist_takers(&mut ctx.accounts.taker_whitelist_config, ctx.accounts.admin.key(), max_takers)
 }
 
 /// Adds multiple token mints to the whitelist
@@ -373,20 +774,300 @@ pub fn update_toggle_whitelist(
 pub fn add_mints(
     ctx: Context<ModifyMintWhitelist>,
     new_mints: Vec<Pubkey>,
+) -> Result<()> {
+    apply_add_mints(&mut ctx.accounts.mint_whitelist, ctx.accounts.admin.key(), new_mints)
+}
+
+/// Removes multiple token mints from the whitelist
+///
+/// # Arguments
+/// * `ctx` - ModifyMintWhitelist context
+/// * `remove_mints` - Vector of mint addresses to remove
+pub fn remove_mints(
+    ctx: Context<ModifyMintWhitelist>,
+    remove_mints: Vec<Pubkey>,
+) -> Result<()> {
+    apply_remove_mints(&mut ctx.accounts.mint_whitelist, ctx.accounts.admin.key(), remove_mints)
+}
+
+/// Grants an admin role, replacing its current holder
+///
+/// # Arguments
+/// * `ctx` - ManageRoles context
+/// * `role` - Role to grant
+/// * `holder` - Account receiving the role
+///
+/// # Errors
+/// * `SwapError::MultisigActive` - If the admin multisig is enabled
+/// * `SwapError::UnauthorizedAdmin` - If caller isn't the super-admin
+/// * `SwapError::InvalidAddress` - If the holder is the default pubkey
+/// * `SwapError::RoleAlreadyGranted` - If the holder already has the role
+pub fn role_grant(ctx: Context<ManageRoles>, role: AdminRole, holder: Pubkey) -> Result<()> {
+    apply_grant_role(&mut ctx.accounts.admin_roles, ctx.accounts.admin.key(), role, holder)
+}
+
+/// Revokes an admin role from its holder
+///
+/// # Arguments
+/// * `ctx` - ManageRoles context
+/// * `role` - Role to revoke
+///
+/// # Errors
+/// * `SwapError::MultisigActive` - If the admin multisig is enabled
+/// * `SwapError::UnauthorizedAdmin` - If caller isn't the super-admin
+pub fn role_revoke(ctx: Context<ManageRoles>, role: AdminRole) -> Result<()> {
+    apply_revoke_role(&mut ctx.accounts.admin_roles, ctx.accounts.admin.key(), role)
+}
+
+/// Pauses creating and/or taking offers
+/// Cancelling offers and resolving escrowed fills stay available while paused
+///
+/// # Arguments
+/// * `ctx` - SetPause context
+/// * `create` - Whether to pause creating offers
+/// * `take` - Whether to pause taking offers and filling signed orders
+///
+/// # Errors
+/// * `SwapError::UnauthorizedAdmin` - If caller doesn't hold the pauser role
+pub fn protocol_pause(ctx: Context<SetPause>, create: bool, take: bool) -> Result<()> {
+    let admin_config = &mut ctx.accounts.admin_config;
+    admin_config.create_paused |= create;
+    admin_config.take_paused |= take;
+
+    emit!(ProtocolPaused {
+        pauser: ctx.accounts.pauser.key(),
+        create_paused: admin_config.create_paused,
+        take_paused: admin_config.take_paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Unpauses creating and/or taking offers
+///
+/// # Arguments
+/// * `ctx` - SetPause context
+/// * `create` - Whether to unpause creating offers
+/// * `take` - Whether to unpause taking offers and filling signed orders
+///
+/// # Errors
+/// * `SwapError::UnauthorizedAdmin` - If caller doesn't hold the pauser role
+pub fn protocol_unpause(ctx: Context<SetPause>, create: bool, take: bool) -> Result<()> {
+    let admin_config = &mut ctx.accounts.admin_config;
+    admin_config.create_paused &= !create;
+    admin_config.take_paused &= !take;
+
+    emit!(ProtocolUnpaused {
+        pauser: ctx.accounts.pauser.key(),
+        create_paused: admin_config.create_paused,
+        take_paused: admin_config.take_paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Halts or resumes creating and taking offers trading a mint
+///
+/// # Arguments
+/// * `ctx` - SetMintConfig context
+/// * `halted` - Whether trading the mint is halted
+///
+/// # Errors
+/// * `SwapError::UnauthorizedAdmin` - If caller doesn't hold the listing manager role
+pub fn mint_halt_update(ctx: Context<SetMintConfig>, halted: bool) -> Result<()> {
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.mint = ctx.accounts.mint.key();
+    mint_config.halted = halted;
+
+    emit!(MintHaltUpdated {
+        admin: ctx.accounts.admin.key(),
+        mint: mint_config.mint,
+        halted,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Adds or removes a mint from the denylist
+/// Denied mints can't be used in new or existing offers, even when whitelisted
+///
+/// # Arguments
+/// * `ctx` - SetMintConfig context
+/// * `denied` - Whether the mint is denied
+///
+/// # Errors
+/// * `SwapError::UnauthorizedAdmin` - If caller doesn't hold the listing manager role
+pub fn mint_deny_update(ctx: Context<SetMintConfig>, denied: bool) -> Result<()> {
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.mint = ctx.accounts.mint.key();
+    mint_config.denied = denied;
+
+    emit!(MintDenyUpdated {
+        admin: ctx.accounts.admin.key(),
+        mint: mint_config.mint,
+        denied,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Lists a mint through its own PDA, or updates the trade size limits of a listed mint
+/// Listed mints count as whitelisted in addition to the `MintWhitelist` vector
+///
+/// # Arguments
+/// * `ctx` - ListMint context
+/// * `min_trade_amount` - Smallest amount of the mint a single trade can move
+/// * `max_trade_amount` - Largest amount of the mint a single trade can move, 0 for no limit
+///
+/// # Errors
+/// * `SwapError::UnauthorizedAdmin` - If caller doesn't hold the listing manager role
+/// * `SwapError::InvalidTradeLimits` - If the minimum exceeds a non-zero maximum
+pub fn mint_list(
+    ctx: Context<ListMint>,
+    min_trade_amount: u64,
+    max_trade_amount: u64,
 ) -> Result<()> {
     require!(
-        ctx.accounts.mint_whitelist.mints.len() + new_mints.len() <= 50,
+        max_trade_amount == 0 || min_trade_amount <= max_trade_amount,
+        SwapError::InvalidTradeLimits
+    );
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let listed_mint = &mut ctx.accounts.listed_mint;
+    if listed_mint.mint == Pubkey::default() {
+        listed_mint.mint = ctx.accounts.mint.key();
+        listed_mint.listed_at = current_time;
+        listed_mint.listed_by = ctx.accounts.admin.key();
+    }
+    listed_mint.min_trade_amount = min_trade_amount;
+    listed_mint.max_trade_amount = max_trade_amount;
+
+    emit!(MintListed {
+        admin: ctx.accounts.admin.key(),
+        mint: listed_mint.mint,
+        min_trade_amount,
+        max_trade_amount,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// Delists a mint by closing its listing PDA
+/// A mint also present in the `MintWhitelist` vector stays whitelisted
+///
+/// # Arguments
+/// * `ctx` - DelistMint context
+///
+/// # Errors
+/// * `SwapError::UnauthorizedAdmin` - If caller doesn't hold the listing manager role
+pub fn mint_delist(ctx: Context<DelistMint>) -> Result<()> {
+    emit!(MintDelisted {
+        admin: ctx.accounts.admin.key(),
+        mint: ctx.accounts.listed_mint.mint,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// The apply_* helpers below hold the logic of each admin action so it can be
+// run by the single admin instructions and by executed multisig proposals.
+// `admin` is the authority reported in the emitted event.
+
+pub(crate) fn apply_fee_address(fee_config: &mut FeeConfig, admin: Pubkey, new_address: Pubkey) -> Result<()> {
+    require!(new_address != Pubkey::default(), SwapError::InvalidAddress);
+    let old_wallet = fee_config.fee_address;
+    fee_config.fee_address = new_address;
+
+    emit!(FeeWalletUpdated {
+        admin,
+        old_wallet,
+        new_wallet: new_address,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn apply_fee(fee_config: &mut FeeConfig, admin: Pubkey, new_fee: u64) -> Result<()> {
+    let old_fee = fee_config.fee_percentage;
+    
+    require!(new_fee <= 10000, SwapError::InvalidFeePercentage);
+    fee_config.fee_percentage = new_fee;
+
+    emit!(FeeUpdated {
+        admin,
+        old_fee,
+        new_fee,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+pub(crate) fn apply_expiry_bounty(expiry_config: &mut ExpiryConfig, admin: Pubkey, new_bounty: u64) -> Result<()> {
+    let old_bounty = expiry_config.expiry_bounty;
+
+    require!(new_bounty <= MAX_EXPIRY_BOUNTY, SwapError::InvalidExpiryBounty);
+    expiry_config.expiry_bounty = new_bounty;
+
+    emit!(ExpiryBountyUpdated {
+        admin,
+        old_bounty,
+        new_bounty,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn apply_toggle_whitelist(whitelist_config: &mut WhitelistConfig, admin: Pubkey) -> Result<()> {
+    whitelist_config.require_whitelist = !whitelist_config.require_whitelist;
+
+    emit!(WhitelistRequirementToggled {
+        admin,
+        new_status: whitelist_config.require_whitelist,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn apply_max_whitelist_takers(config: &mut TakerWhitelistConfig, admin: Pubkey, max_takers: u32) -> Result<()> {
+    require!(max_takers > 0 && max_takers <= WHITELIST_TAKERS_LIMIT, SwapError::InvalidWhitelistSize);
+
+    let old_max = config.max_takers;
+    config.max_takers = max_takers;
+
+    emit!(MaxWhitelistTakersUpdated {
+        admin,
+        old_max,
+        new_max: max_takers,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn apply_add_mints(mint_whitelist: &mut MintWhitelist, admin: Pubkey, new_mints: Vec<Pubkey>) -> Result<()> {
+    require!(
+        mint_whitelist.mints.len() + new_mints.len() <= 50,
         SwapError::TooManyMints
     );
 
     for mint in &new_mints {
-        if !ctx.accounts.mint_whitelist.mints.contains(&mint) {
-            ctx.accounts.mint_whitelist.mints.push(*mint);
+        if !mint_whitelist.mints.contains(mint) {
+            mint_whitelist.mints.push(*mint);
         }
     }
 
     emit!(MintsAddedToWhitelist {
-        admin: ctx.accounts.admin.key(),
+        admin,
         new_mints,
         timestamp: Clock::get()?.unix_timestamp,
     });
@@ -394,23 +1075,160 @@ pub fn add_mints(
     Ok(())
 }
 
-/// Removes multiple token mints from the whitelist
-///
-/// # Arguments
-/// * `ctx` - ModifyMintWhitelist context
-/// * `remove_mints` - Vector of mint addresses to remove
-pub fn remove_mints(
-    ctx: Context<ModifyMintWhitelist>,
-    remove_mints: Vec<Pubkey>,
-) -> Result<()> {
+pub(crate) fn apply_remove_mints(mint_whitelist: &mut MintWhitelist, admin: Pubkey, remove_mints: Vec<Pubkey>) -> Result<()> {
     let removed = remove_mints.clone();
-    ctx.accounts.mint_whitelist.mints.retain(|mint| !remove_mints.contains(mint));
+    mint_whitelist.mints.retain(|mint| !remove_mints.contains(mint));
 
     emit!(MintsRemovedFromWhitelist {
-        admin: ctx.accounts.admin.key(),
+        admin,
         removed_mints: removed,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
     Ok(())
-}
\ No newline at end of file
+}
+
+pub(crate) fn apply_grant_role(admin_roles: &mut AdminRoles, admin: Pubkey, role: AdminRole, holder: Pubkey) -> Result<()> {
+    require!(holder != Pubkey::default(), SwapError::InvalidAddress);
+    require!(!admin_roles.has_role(role, &holder), SwapError::RoleAlreadyGranted);
+    admin_roles.set_holder(role, Some(holder));
+
+    emit!(RoleGranted {
+        admin,
+        role,
+        holder,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub(crate) fn apply_revoke_role(admin_roles: &mut AdminRoles, admin: Pubkey, role: AdminRole) -> Result<()> {
+    let holder = admin_roles.holder(role);
+    admin_roles.set_holder(role, None);
+
+    emit!(RoleRevoked {
+        admin,
+        role,
+        holder,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Revokes every role `holder` holds, used when an admin hands over control
+pub(crate) fn revoke_all_roles(admin_roles: &mut AdminRoles, holder: Pubkey) -> Result<()> {
+    for role in [AdminRole::FeeManager, AdminRole::ListingManager, AdminRole::Pauser] {
+        if admin_roles.has_role(role, &holder) {
+            apply_revoke_role(admin_roles, holder, role)?;
+        }
+    }
+    Ok(())
+}
+
+/// Revokes every granted role, used when the multisig takes over so that
+/// roles are only held by accounts the multisig grants them to
+pub(crate) fn revoke_every_role(admin_roles: &mut AdminRoles, admin: Pubkey) -> Result<()> {
+    for role in [AdminRole::FeeManager, AdminRole::ListingManager, AdminRole::Pauser] {
+        if admin_roles.holder(role).is_some() {
+            apply_revoke_role(admin_roles, admin, role)?;
+        }
+    }
+    Ok(())
+}
+
+/// Proposes a new admin, who must accept before the transfer takes effect
+/// Proposing again replaces the pending admin
+///
+/// # Arguments
+/// * `ctx` - ProposeAdmin context
+/// * `new_admin` - Proposed admin
+///
+/// # Errors
+/// * `SwapError::UnauthorizedAdmin` - If caller isn't the admin
+/// * `SwapError::InvalidAddress` - If the proposed admin is the default pubkey
+pub fn admin_propose(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    require!(new_admin != Pubkey::default(), SwapError::InvalidAddress);
+    ctx.accounts.admin_config.pending_admin = Some(new_admin);
+
+    emit!(AdminTransferProposed {
+        admin: ctx.accounts.admin.key(),
+        pending_admin: new_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Accepts the admin role proposed by the current admin
+/// Roles still held by the outgoing admin are revoked, the new admin grants them again
+///
+/// # Arguments
+/// * `ctx` - AcceptAdmin context
+///
+/// # Errors
+/// * `SwapError::NotPendingAdmin` - If caller isn't the pending admin
+pub fn admin_accept(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let admin_config = &mut ctx.accounts.admin_config;
+    let old_admin = admin_config.admin;
+    admin_config.admin = ctx.accounts.new_admin.key();
+    admin_config.pending_admin = None;
+
+    revoke_all_roles(&mut ctx.accounts.admin_roles, old_admin)?;
+
+    emit!(AdminTransferred {
+        old_admin,
+        new_admin: admin_config.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Grows an admin config created before new fields were added to `AdminConfig::SPACE`
+/// The added bytes are zeroed, which decodes as empty values for the new fields
+///
+/// # Arguments
+/// * `ctx` - MigrateAdminConfig context
+///
+/// # Errors
+/// * `SwapError::AdminNotInitialized` - If the account isn't an initialized admin config
+/// * `SwapError::UnauthorizedAdmin` - If caller isn't the admin
+pub fn admin_config_migrate(ctx: Context<MigrateAdminConfig>) -> Result<()> {
+    let admin_config = ctx.accounts.admin_config.to_account_info();
+    require!(admin_config.owner == &crate::ID, SwapError::AdminNotInitialized);
+    {
+        let data = admin_config.try_borrow_data()?;
+        require!(
+            data.len() >= AdminConfig::LEGACY_SPACE && data[..8] == AdminConfig::DISCRIMINATOR,
+            SwapError::AdminNotInitialized
+        );
+        require!(
+            data[8..AdminConfig::LEGACY_SPACE] == ctx.accounts.admin.key().to_bytes(),
+            SwapError::UnauthorizedAdmin
+        );
+    }
+
+    if admin_config.data_len() >= AdminConfig::SPACE {
+        return Ok(());
+    }
+
+    let required_lamports = Rent::get()?.minimum_balance(AdminConfig::SPACE);
+    let missing_lamports = required_lamports.saturating_sub(admin_config.lamports());
+    if missing_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.admin.to_account_info(),
+                    to: admin_config.clone(),
+                },
+            ),
+            missing_lamports,
+        )?;
+    }
+    admin_config.realloc(AdminConfig::SPACE, true)?;
+
+    Ok(())
+}
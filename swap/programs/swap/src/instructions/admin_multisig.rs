@@ -0,0 +1,417 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_ADMIN_SIGNERS;
+use crate::instructions::admin::*;
+use crate::state::*;
+use crate::error::*;
+
+#[event]
+pub struct AdminMultisigUpdated {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalCreated {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalApproved {
+    pub proposal_id: u64,
+    pub signer: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalExecuted {
+    pub proposal_id: u64,
+    pub executor: Pubkey,
+    pub action: AdminAction,
+    pub timestamp: i64,
+}
+
+/// Account validation struct for switching the single admin to a multisig
+#[derive(Accounts)]
+pub struct EnableAdminMultisig<'info> {
+    /// Current admin signer, pays for the roles registry on first use
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// PDA storing the multisig signers and threshold
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump,
+        constraint = !admin_config.is_multisig() @ SwapError::MultisigActive,
+        constraint = admin_config.admin == admin.key() @ SwapError::UnauthorizedAdmin
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// PDA storing the role holders, every role is revoked
+    /// Space breakdown in AdminRoles::SPACE
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminRoles::SPACE,
+        seeds = [b"admin_roles"],
+        bump
+    )]
+    pub admin_roles: Account<'info, AdminRoles>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation struct for creating an admin proposal
+#[derive(Accounts)]
+#[instruction(action: AdminAction)]
+pub struct CreateAdminProposal<'info> {
+    /// Multisig member creating the proposal, pays for the proposal PDA
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// PDA verifying multisig membership and numbering proposals
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump,
+        constraint = admin_config.is_multisig() @ SwapError::MultisigNotActive,
+        constraint = admin_config.is_signer(&proposer.key()) @ SwapError::NotMultisigSigner
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// PDA recording the proposed action and its approvals
+    /// Space breakdown in AdminProposal::space
+    ///
+    /// Seeds: ["admin_proposal", proposal_id]
+    #[account(
+        init,
+        payer = proposer,
+        space = AdminProposal::space(&action),
+        seeds = [b"admin_proposal".as_ref(), &admin_config.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation struct for approving an admin proposal
+#[derive(Accounts)]
+pub struct ApproveAdminProposal<'info> {
+    /// Multisig member approving the proposal
+    pub signer: Signer<'info>,
+
+    /// PDA verifying multisig membership
+    #[account(
+        seeds = [b"admin_config"],
+        bump,
+        constraint = admin_config.is_multisig() @ SwapError::MultisigNotActive,
+        constraint = admin_config.is_signer(&signer.key()) @ SwapError::NotMultisigSigner
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// Proposal being approved
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump,
+        constraint = !proposal.executed @ SwapError::ProposalAlreadyExecuted
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+}
+
+/// Account validation struct for executing an approved admin proposal
+/// Holds every config an admin action can change
+#[derive(Accounts)]
+pub struct ExecuteAdminProposal<'info> {
+    /// Multisig member executing the proposal
+    /// Pays for the taker whitelist config on first use
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// PDA verifying multisig membership and threshold
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump,
+        constraint = admin_config.is_multisig() @ SwapError::MultisigNotActive,
+        constraint = admin_config.is_signer(&executor.key()) @ SwapError::NotMultisigSigner
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+
+    /// Proposal being executed
+    #[account(
+        mut,
+        seeds = [b"admin_proposal".as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump,
+        constraint = !proposal.executed @ SwapError::ProposalAlreadyExecuted
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    #[account(
+        mut,
+        seeds = [b"fee"],
+        bump,
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"whitelist_config"],
+        bump,
+    )]
+    pub whitelist_config: Account<'info, WhitelistConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_whitelist"],
+        bump,
+    )]
+    pub mint_whitelist: Account<'info, MintWhitelist>,
+
+    /// PDA storing taker whitelist limits
+    /// Only required for UpdateMaxWhitelistTakers proposals
+    /// Space breakdown:
+    /// - 8 bytes discriminator
+    /// - 4 bytes maximum takers
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + 4,
+        seeds = [b"taker_whitelist_config"],
+        bump
+    )]
+    pub taker_whitelist_config: Option<Account<'info, TakerWhitelistConfig>>,
+
+    /// PDA storing the expiry bounty
+    /// Only required for UpdateExpiryBounty proposals
+    /// Space breakdown:
+    /// - 8 bytes discriminator
+    /// - 8 bytes expiry bounty
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = 8 + 8,
+        seeds = [b"expiry_config"],
+        bump
+    )]
+    pub expiry_config: Option<Account<'info, ExpiryConfig>>,
+
+    /// PDA storing the role holders
+    /// Only required for GrantRole and RevokeRole proposals
+    /// Space breakdown in AdminRoles::SPACE
+    #[account(
+        init_if_needed,
+        payer = executor,
+        space = AdminRoles::SPACE,
+        seeds = [b"admin_roles"],
+        bump
+    )]
+    pub admin_roles: Option<Account<'info, AdminRoles>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Switches admin authority from the single admin to an M-of-N multisig
+/// Afterwards super-admin actions are only applied through executed proposals
+/// and every role is revoked until a proposal grants it again
+///
+/// # Arguments
+/// * `ctx` - EnableAdminMultisig context
+/// * `signers` - Multisig members
+/// * `threshold` - Approvals a proposal needs before it can be executed
+///
+/// # Errors
+/// * `SwapError::MultisigActive` - If the multisig is already enabled
+/// * `SwapError::UnauthorizedAdmin` - If caller isn't the admin
+/// * `SwapError::InvalidMultisig` - If the signers or threshold are invalid
+pub fn multisig_enable(
+    ctx: Context<EnableAdminMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    revoke_every_role(&mut ctx.accounts.admin_roles, ctx.accounts.admin.key())?;
+
+    let admin_config = &mut ctx.accounts.admin_config;
+    admin_config.pending_admin = None;
+    set_multisig(admin_config, signers, threshold)
+}
+
+/// Creates a proposal for an admin action, approved by its proposer
+///
+/// # Arguments
+/// * `ctx` - CreateAdminProposal context
+/// * `action` - Admin action to apply once the proposal is executed
+///
+/// # Errors
+/// * `SwapError::MultisigNotActive` - If the multisig isn't enabled
+/// * `SwapError::NotMultisigSigner` - If caller isn't a multisig member
+pub fn proposal_create(ctx: Context<CreateAdminProposal>, action: AdminAction) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let admin_config = &mut ctx.accounts.admin_config;
+    let proposal_id = admin_config.proposal_count;
+    admin_config.proposal_count = proposal_id
+        .checked_add(1)
+        .ok_or(SwapError::CalculationError)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposal_id = proposal_id;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action.clone();
+    proposal.approvals = vec![ctx.accounts.proposer.key()];
+    proposal.executed = false;
+    proposal.created_at = current_time;
+
+    emit!(AdminProposalCreated {
+        proposal_id,
+        proposer: proposal.proposer,
+        action,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// Approves an admin proposal
+///
+/// # Arguments
+/// * `ctx` - ApproveAdminProposal context
+///
+/// # Errors
+/// * `SwapError::NotMultisigSigner` - If caller isn't a multisig member
+/// * `SwapError::ProposalAlreadyExecuted` - If the proposal was executed
+/// * `SwapError::ProposalAlreadyApproved` - If caller already approved the proposal
+pub fn proposal_approve(ctx: Context<ApproveAdminProposal>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let signers = &ctx.accounts.admin_config.signers;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.approvals.contains(&signer), SwapError::ProposalAlreadyApproved);
+    // Approvals of removed members no longer count, dropping them keeps the
+    // approvals within MAX_ADMIN_SIGNERS after the signer set changes
+    proposal.approvals.retain(|key| signers.contains(key));
+    proposal.approvals.push(signer);
+
+    emit!(AdminProposalApproved {
+        proposal_id: proposal.proposal_id,
+        signer,
+        approvals: proposal.approvals.len() as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Applies the action of a proposal approved by the threshold of current members
+/// Events of the applied action report the proposal PDA as the admin
+///
+/// # Arguments
+/// * `ctx` - ExecuteAdminProposal context
+///
+/// # Errors
+/// * `SwapError::NotMultisigSigner` - If caller isn't a multisig member
+/// * `SwapError::ProposalAlreadyExecuted` - If the proposal was executed
+/// * `SwapError::ProposalThresholdNotMet` - If too few current members approved
+/// * `SwapError::ProposalAccountsRequired` - If an optional account the action changes is missing
+/// * Any error of the single admin instruction the action mirrors
+pub fn proposal_execute(ctx: Context<ExecuteAdminProposal>) -> Result<()> {
+    let accounts = ctx.accounts;
+    let proposal_key = accounts.proposal.key();
+    require!(
+        accounts.proposal.approval_count(&accounts.admin_config.signers)
+            >= accounts.admin_config.threshold as usize,
+        SwapError::ProposalThresholdNotMet
+    );
+
+    match accounts.proposal.action.clone() {
+        AdminAction::UpdateFee { new_fee } => {
+            apply_fee(&mut accounts.fee_config, proposal_key, new_fee)?;
+        }
+        AdminAction::UpdateFeeAddress { new_address } => {
+            apply_fee_address(&mut accounts.fee_config, proposal_key, new_address)?;
+        }
+        AdminAction::UpdateExpiryBounty { new_bounty } => {
+            let config = accounts.expiry_config
+                .as_mut()
+                .ok_or(SwapError::ProposalAccountsRequired)?;
+            apply_expiry_bounty(config, proposal_key, new_bounty)?;
+        }
+        AdminAction::ToggleRequireWhitelist => {
+            apply_toggle_whitelist(&mut accounts.whitelist_config, proposal_key)?;
+        }
+        AdminAction::UpdateMaxWhitelistTakers { max_takers } => {
+            let config = accounts.taker_whitelist_config
+                .as_mut()
+                .ok_or(SwapError::ProposalAccountsRequired)?;
+            apply_max_whitelist_takers(config, proposal_key, max_takers)?;
+        }
+        AdminAction::AddMints { new_mints } => {
+            apply_add_mints(&mut accounts.mint_whitelist, proposal_key, new_mints)?;
+        }
+        AdminAction::RemoveMints { remove_mints } => {
+            apply_remove_mints(&mut accounts.mint_whitelist, proposal_key, remove_mints)?;
+        }
+        AdminAction::SetMultisig { signers, threshold } => {
+            set_multisig(&mut accounts.admin_config, signers, threshold)?;
+        }
+        AdminAction::GrantRole { role, holder } => {
+            let admin_roles = accounts.admin_roles
+                .as_mut()
+                .ok_or(SwapError::ProposalAccountsRequired)?;
+            apply_grant_role(admin_roles, proposal_key, role, holder)?;
+        }
+        AdminAction::RevokeRole { role } => {
+            let admin_roles = accounts.admin_roles
+                .as_mut()
+                .ok_or(SwapError::ProposalAccountsRequired)?;
+            apply_revoke_role(admin_roles, proposal_key, role)?;
+        }
+    }
+
+    let proposal = &mut accounts.proposal;
+    proposal.executed = true;
+
+    emit!(AdminProposalExecuted {
+        proposal_id: proposal.proposal_id,
+        executor: accounts.executor.key(),
+        action: proposal.action.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Validates and stores the multisig signers and threshold
+///
+/// # Errors
+/// * `SwapError::InvalidMultisig` - If there are no or too many signers, a duplicate
+///   or default signer, or the threshold is zero or above the number of signers
+fn set_multisig(admin_config: &mut AdminConfig, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_ADMIN_SIGNERS,
+        SwapError::InvalidMultisig
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        SwapError::InvalidMultisig
+    );
+    for (index, signer) in signers.iter().enumerate() {
+        require!(*signer != Pubkey::default(), SwapError::InvalidMultisig);
+        require!(!signers[..index].contains(signer), SwapError::InvalidMultisig);
+    }
+
+    admin_config.signers = signers.clone();
+    admin_config.threshold = threshold;
+
+    emit!(AdminMultisigUpdated {
+        signers,
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
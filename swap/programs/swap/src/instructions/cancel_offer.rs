@@ -5,6 +5,7 @@ use anchor_spl::{
 };
 use crate::state::*;
 use crate::error::*;
+use crate::instructions::close_offer::close_offer_accounts;
 
 #[event]
 pub struct OfferCancelled {
@@ -28,8 +29,8 @@ pub enum CancellationReason {
 /// 2. When the maker wants to voluntarily cancel their active offer
 ///
 /// The instruction will:
-/// - Return tokens from vault to maker
-/// - Close all related PDAs
+/// - Return tokens from vault to maker, or revoke the delegation of delegated offers
+/// - Optionally close the offer and whitelist PDAs
 /// - Update protocol statistics
 /// - Return rent to maker
 #[derive(Accounts)]
@@ -38,6 +39,7 @@ pub struct CancelOffer<'info> {
     /// - Sign the cancellation transaction
     /// - Receive returned tokens
     /// - Receive rent from closed accounts
+    ///
     /// Must match the maker stored in the offer
     #[account(mut)]
     pub maker: Signer<'info>,
@@ -61,8 +63,8 @@ pub struct CancelOffer<'info> {
     )]
     pub offer: Account<'info, Offer>,
 
-    /// The whitelist PDA storing allowed takers
-    /// Will be closed and rent returned to maker
+    /// CHECK: The whitelist PDA storing allowed takers, closed with the offer if one was created
+    /// Public offers and offers without takers never create it
     /// 
     /// Seeds: ["whitelist", maker_pubkey, offer_id]
     #[account(
@@ -70,7 +72,7 @@ pub struct CancelOffer<'info> {
         seeds = [b"whitelist", maker.key().as_ref(), &offer.offer_id.to_le_bytes()],
         bump,
     )]
-    pub whitelist: Account<'info, Whitelist>,
+    pub whitelist: UncheckedAccount<'info>,
 
     /// Token account owned by maker that will receive
     /// returned tokens from the vault
@@ -88,6 +90,7 @@ pub struct CancelOffer<'info> {
     /// The vault token account holding the offered tokens
     /// Created as an Associated Token Account owned by offer PDA
     /// Will be closed after returning tokens
+    /// Only passed for vault offers
     /// 
     /// Constraints:
     /// - Must be an ATA
@@ -100,7 +103,7 @@ pub struct CancelOffer<'info> {
         associated_token::token_program = token_program,
         constraint = vault_token_account.mint == offer.input_token_mint,
     )]
-    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     /// Admin configuration PDA for updating protocol statistics
     /// Tracks active/cancelled offer counts
@@ -130,12 +133,13 @@ pub struct CancelOffer<'info> {
 /// 
 /// This function handles the complete cancellation flow including:
 /// 1. Validation of cancellation conditions
-/// 2. Return of tokens to maker
+/// 2. Return of tokens to maker, or revocation of the offer's delegation
 /// 3. Closure of vault and PDAs
 /// 4. Update of protocol statistics
 /// 
 /// # Arguments
 /// * `ctx` - The CancelOffer context containing all required accounts
+/// * `close_accounts` - Whether to also close the offer and whitelist PDAs
 ///
 /// # Security Checks
 /// - Verifies maker authority
@@ -152,20 +156,22 @@ pub struct CancelOffer<'info> {
 /// # State Updates
 /// - Marks offer as Cancelled
 /// - Updates protocol statistics
-/// - Closes related PDAs
+/// - Closes related PDAs if requested
 ///
 /// # Errors
 /// * `SwapError::CannotCancelOffer` - If neither expiry nor maker cancellation conditions are met
 /// * `SwapError::InvalidOfferStatus` - If offer is not in Ongoing status
+/// * `SwapError::InvalidCustodyAccounts` - If the vault is missing for a vault offer
+/// * `SwapError::PendingFills` - If escrowed fills are still waiting for settlement
 /// * Various token program errors for transfer failures
-
-pub fn update_cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
+pub fn update_cancel_offer(ctx: Context<CancelOffer>, close_accounts: bool) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
 
     // Validate cancellation conditions
     let is_expired = current_time > ctx.accounts.offer.deadline;
     let is_maker = ctx.accounts.offer.maker == ctx.accounts.maker.key();
     require!(is_expired || is_maker, SwapError::CannotCancelOffer);
+    require!(ctx.accounts.offer.pending_fills == 0, SwapError::PendingFills);
 
     let cancellation_reason = if is_expired {
         CancellationReason::Expired
@@ -187,40 +193,61 @@ pub fn update_cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
     ];
     let signer_seeds = &[&seeds[..]];
 
-    msg!("Preparing to close vault account");
-
-    msg!("Transferring {} tokens back to maker", token_amount);
-    token_interface::transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            token_interface::TransferChecked {
-                from: ctx.accounts.vault_token_account.to_account_info(),
-                mint: ctx.accounts.input_token_mint.to_account_info(),
-                to: ctx.accounts.maker_token_account.to_account_info(),
-                authority: ctx.accounts.offer.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        token_amount,
-        ctx.accounts.input_token_mint.decimals,
-    )?;
-
-    // Mark offer as cancelled
-
-    // Close the vault account
-    msg!("Closing vault account...");
-    token_interface::close_account(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            token_interface::CloseAccount {
-                account: ctx.accounts.vault_token_account.to_account_info(),
-                destination: ctx.accounts.maker.to_account_info(),
-                authority: ctx.accounts.offer.to_account_info(),
-            },
-            signer_seeds,
-        )
-    )?;
-    msg!("Vault closed successfully");
+    match ctx.accounts.offer.custody {
+        OfferCustody::Vault => {
+            let vault_token_account = ctx.accounts.vault_token_account
+                .as_ref()
+                .ok_or(SwapError::InvalidCustodyAccounts)?;
+
+            msg!("Preparing to close vault account");
+
+            msg!("Transferring {} tokens back to maker", token_amount);
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_interface::TransferChecked {
+                        from: vault_token_account.to_account_info(),
+                        mint: ctx.accounts.input_token_mint.to_account_info(),
+                        to: ctx.accounts.maker_token_account.to_account_info(),
+                        authority: ctx.accounts.offer.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                token_amount,
+                ctx.accounts.input_token_mint.decimals,
+            )?;
+
+            // Close the vault account
+            msg!("Closing vault account...");
+            token_interface::close_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_interface::CloseAccount {
+                        account: vault_token_account.to_account_info(),
+                        destination: ctx.accounts.maker.to_account_info(),
+                        authority: ctx.accounts.offer.to_account_info(),
+                    },
+                    signer_seeds,
+                )
+            )?;
+            msg!("Vault closed successfully");
+        }
+        OfferCustody::Delegate => {
+            // Tokens never left the maker, only drop the delegation if it is still the offer's
+            if ctx.accounts.maker_token_account.delegate == Some(ctx.accounts.offer.key()).into() {
+                msg!("Revoking offer delegation");
+                token_interface::revoke(
+                    CpiContext::new(
+                        ctx.accounts.token_program.to_account_info(),
+                        token_interface::Revoke {
+                            source: ctx.accounts.maker_token_account.to_account_info(),
+                            authority: ctx.accounts.maker.to_account_info(),
+                        },
+                    )
+                )?;
+            }
+        }
+    }
 
     ctx.accounts.offer.status = OfferStatus::Cancelled;
 
@@ -234,5 +261,13 @@ pub fn update_cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
         timestamp: current_time,
     });
 
+    if close_accounts {
+        close_offer_accounts(
+            &ctx.accounts.offer,
+            ctx.accounts.whitelist.to_account_info(),
+            ctx.accounts.maker.to_account_info(),
+        )?;
+    }
+
     Ok(())
 }
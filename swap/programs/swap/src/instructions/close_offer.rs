@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+
+#[event]
+pub struct OfferClosed {
+    pub offer_id: u64,
+    pub maker: Pubkey,
+    pub status: OfferStatus,
+    pub token_amount: u64,
+    pub token_amount_remaining: u64,
+    pub expected_fulfilled_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Account validation structure for closing a finished offer
+/// Reclaims the rent of the offer and whitelist PDAs once
+/// the offer can no longer be traded
+#[derive(Accounts)]
+pub struct CloseOffer<'info> {
+    /// Original offer maker who receives the reclaimed rent
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    /// The offer being closed
+    ///
+    /// Constraints:
+    /// - Must be signed by original maker
+    /// - Must be Completed, Cancelled or Expired
+    ///
+    /// Seeds: ["offer", maker_pubkey, offer_id]
+    #[account(
+        mut,
+        constraint = offer.maker == maker.key() @ SwapError::UnauthorizedMaker,
+        constraint = matches!(
+            offer.status,
+            OfferStatus::Completed | OfferStatus::Cancelled | OfferStatus::Expired
+        ) @ SwapError::InvalidOfferStatus,
+        seeds = [b"offer", maker.key().as_ref(), &offer.offer_id.to_le_bytes()],
+        bump,
+        close = maker,
+    )]
+    pub offer: Account<'info, Offer>,
+
+    /// CHECK: The whitelist PDA of the offer, closed in the handler if one was created
+    ///
+    /// Seeds: ["whitelist", maker_pubkey, offer_id]
+    #[account(
+        mut,
+        seeds = [b"whitelist", maker.key().as_ref(), &offer.offer_id.to_le_bytes()],
+        bump,
+    )]
+    pub whitelist: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Closes a completed, cancelled or expired offer and its whitelist
+///
+/// # Arguments
+/// * `ctx` - CloseOffer context
+///
+/// # Errors
+/// * `SwapError::UnauthorizedMaker` - If caller isn't offer maker
+/// * `SwapError::InvalidOfferStatus` - If offer can still be traded
+pub fn close_finished_offer(ctx: Context<CloseOffer>) -> Result<()> {
+    emit_offer_closed(&ctx.accounts.offer)?;
+    close_whitelist(
+        ctx.accounts.whitelist.to_account_info(),
+        ctx.accounts.maker.to_account_info(),
+    )
+}
+
+/// Closes the offer and whitelist PDAs to the maker from within another instruction
+/// Used when the maker opts into closing on the final fill or on cancellation
+pub(crate) fn close_offer_accounts<'info>(
+    offer: &Account<'info, Offer>,
+    whitelist: AccountInfo<'info>,
+    maker: AccountInfo<'info>,
+) -> Result<()> {
+    emit_offer_closed(offer)?;
+
+    close_whitelist(whitelist, maker.clone())?;
+    offer.close(maker)
+}
+
+/// Closes the ["whitelist", maker, offer_id] PDA to the maker if the offer has one
+/// Callers always pass the PDA, so an existing whitelist can't outlive its offer
+fn close_whitelist<'info>(whitelist: AccountInfo<'info>, maker: AccountInfo<'info>) -> Result<()> {
+    if whitelist.owner != &crate::ID || whitelist.data_is_empty() {
+        return Ok(());
+    }
+    let lamports = whitelist.lamports();
+    **maker.try_borrow_mut_lamports()? = maker
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(SwapError::CalculationError)?;
+    **whitelist.try_borrow_mut_lamports()? = 0;
+    whitelist.assign(&System::id());
+    whitelist.realloc(0, false)?;
+    Ok(())
+}
+
+/// Emits the final event for an offer so indexers keep its history after closing
+fn emit_offer_closed(offer: &Offer) -> Result<()> {
+    emit!(OfferClosed {
+        offer_id: offer.offer_id,
+        maker: offer.maker,
+        status: offer.status,
+        token_amount: offer.token_amount,
+        token_amount_remaining: offer.token_amount_remaining,
+        expected_fulfilled_amount: offer.expected_fulfilled_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
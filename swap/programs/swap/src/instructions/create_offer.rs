@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{self, Mint, TokenAccount, TokenInterface}
@@ -15,13 +16,36 @@ pub struct OfferCreated {
     pub token_amount: u64,
     pub expected_amount: u64,
     pub deadline: i64,
+    pub start_time: i64,
+    pub visibility: OfferVisibility,
+    pub close_on_completion: bool,
+    pub min_fill_amount: u64,
+    pub all_or_none: bool,
+    pub custody: OfferCustody,
+    pub acceptance_window: Option<i64>,
+    pub arbitrator: Option<Pubkey>,
 }
 
 #[event]
 pub struct TakerUpdated {
     pub offer_id: u64,
     pub maker: Pubkey,
-    pub takers: Vec<Pubkey>,
+    pub takers: Vec<WhitelistEntry>,
+}
+
+#[event]
+pub struct TakerAdded {
+    pub offer_id: u64,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub allocation: Option<u64>,
+}
+
+#[event]
+pub struct TakerRemoved {
+    pub offer_id: u64,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
 }
 
 /// Account structure for creating offer and setting up vault in one instruction
@@ -36,14 +60,15 @@ pub struct CreateOffer<'info> {
     #[account(mut)]
     pub maker: Signer<'info>,
 
-    // #[account(
-    //     init_if_needed,
-    //     payer = maker,
-    //     space = 8 + 32 + 8, // discriminator + pubkey + u64
-    //     seeds = [b"maker_sequence", maker.key().as_ref()],
-    //     bump
-    // )]
-    // pub maker_sequence: Account<'info, MakerSequence>,
+    /// Offer ids used by the maker, rejects ids that were already used
+    #[account(
+        init_if_needed,
+        payer = maker,
+        space = 8 + 32 + 8, // discriminator + pubkey + u64
+        seeds = [b"maker_sequence", maker.key().as_ref()],
+        bump
+    )]
+    pub maker_sequence: Account<'info, MakerSequence>,
 
     /// The offer PDA storing all trade details
     /// Space breakdown:
@@ -57,13 +82,26 @@ pub struct CreateOffer<'info> {
     /// - 8 bytes for token amount remaining
     /// - 8 bytes for expected fulfilled amount
     /// - 8 bytes deadline
+    /// - 8 bytes start time
     /// - 1 byte offer status
     /// - 8 bytes fee percentage
     /// - 32 bytes fee wallet
+    /// - 1 byte offer visibility
+    /// - 1 byte close on completion flag
+    /// - 8 bytes minimum fill amount
+    /// - 1 byte all or none flag
+    /// - 33 bytes optional taker Merkle root
+    /// - 33 bytes optional ticket approver
+    /// - 1 byte offer custody
+    /// - 9 bytes optional acceptance window
+    /// - 8 bytes fill count
+    /// - 8 bytes pending fills
+    /// - 33 bytes optional arbitrator
+    /// - 8 bytes expiry bounty
     #[account(
         init,
         payer = maker,
-        space = 8 + 8 + 32 + 32 + 8 + 32 + 8 + 8 + 8 + 8 + 1 + 8 + 32,
+        space = 8 + 8 + 32 + 32 + 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 8 + 32 + 1 + 1 + 8 + 1 + 33 + 33 + 1 + 9 + 8 + 8 + 33 + 8,
         seeds = [b"offer", maker.key().as_ref(),&offer_id.to_le_bytes()],
         bump
     )]
@@ -88,6 +126,27 @@ pub struct CreateOffer<'info> {
     )]
     pub fee_config: Account<'info, FeeConfig>,
 
+    /// CHECK: Optional ["expiry_config"] PDA providing the expiry bounty, checked in the handler
+    #[account(
+        seeds = [b"expiry_config"],
+        bump,
+    )]
+    pub expiry_config: UncheckedAccount<'info>,
+
+    /// Whitelist configuration deciding if mint whitelist is enforced
+    #[account(
+        seeds = [b"whitelist_config"],
+        bump,
+    )]
+    pub whitelist_config: Account<'info, WhitelistConfig>,
+
+    /// Mint whitelist both offer mints are checked against
+    #[account(
+        seeds = [b"mint_whitelist"],
+        bump,
+    )]
+    pub mint_whitelist: Account<'info, MintWhitelist>,
+
     /// Maker's token account containing tokens to be offered
     /// Must match the input token mint
     #[account(
@@ -100,6 +159,7 @@ pub struct CreateOffer<'info> {
     /// Vault token account created as an Associated Token Account
     /// Will hold the offered tokens until trade completion
     /// Authority is the offer PDA
+    /// Only passed for vault offers, delegated offers keep the tokens in maker_token_account
     #[account(
         init,
         payer = maker,
@@ -107,7 +167,7 @@ pub struct CreateOffer<'info> {
         associated_token::authority = offer,
         associated_token::token_program = token_program
     )]
-    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     /// Input token mint (token being offered)
     pub input_token_mint: InterfaceAccount<'info, Mint>,
@@ -115,6 +175,34 @@ pub struct CreateOffer<'info> {
     /// Output token mint (token being requested)
     pub output_token_mint: InterfaceAccount<'info, Mint>,
 
+    /// CHECK: Optional ["mint_config", input_token_mint] PDA, checked in the handler
+    #[account(
+        seeds = [b"mint_config", input_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub input_mint_config: UncheckedAccount<'info>,
+
+    /// CHECK: Optional ["mint_config", output_token_mint] PDA, checked in the handler
+    #[account(
+        seeds = [b"mint_config", output_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub output_mint_config: UncheckedAccount<'info>,
+
+    /// CHECK: Optional ["listed_mint", input_token_mint] PDA, checked in the handler
+    #[account(
+        seeds = [b"listed_mint", input_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub input_listed_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Optional ["listed_mint", output_token_mint] PDA, checked in the handler
+    #[account(
+        seeds = [b"listed_mint", output_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub output_listed_mint: UncheckedAccount<'info>,
+
     /// Token interface program for Token-2022 support
     pub token_program: Interface<'info, TokenInterface>,
 
@@ -133,12 +221,45 @@ pub struct ManageWhitelist<'info> {
     pub maker: Signer<'info>,
 
     /// The whitelist PDA storing allowed takers
-    /// Created on first use with init_if_needed
-    /// Space for up to 50 taker addresses
+    /// Created empty on first use with init_if_needed,
+    /// then resized to fit its takers with the maker paying the rent
     #[account(
         init_if_needed,
         payer = maker,
-        space = 8 + 32 + 32 + 4 + (32 * 50),
+        space = Whitelist::space(0),
+        seeds = [b"whitelist", maker.key().as_ref(), &offer.offer_id.to_le_bytes()],
+        bump,
+        constraint = offer.maker == maker.key() @ SwapError::UnauthorizedMaker
+    )]
+    pub whitelist: Account<'info, Whitelist>,
+
+    /// The offer this whitelist belongs to
+    /// Used to verify maker authority
+    pub offer: Account<'info, Offer>,
+
+    /// CHECK: ["taker_whitelist_config"] PDA with the protocol-wide whitelist limits, checked in the handler
+    /// MAX_WHITELIST_TAKERS applies until the admin configures it
+    #[account(
+        seeds = [b"taker_whitelist_config"],
+        bump,
+    )]
+    pub taker_whitelist_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account structure for removing takers from an existing offer whitelist
+#[derive(Accounts)]
+pub struct RemoveTakers<'info> {
+    /// Original offer maker, must sign whitelist operations
+    /// Receives the rent freed by shrinking the whitelist
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    /// The whitelist PDA storing allowed takers
+    /// Shrunk to fit its remaining takers
+    #[account(
+        mut,
         seeds = [b"whitelist", maker.key().as_ref(), &offer.offer_id.to_le_bytes()],
         bump,
         constraint = offer.maker == maker.key() @ SwapError::UnauthorizedMaker
@@ -160,39 +281,89 @@ pub struct ManageWhitelist<'info> {
 /// * `token_amount` - Amount of input tokens to offer
 /// * `expected_amount` - Amount of output tokens expected in return
 /// * `deadline` - Unix timestamp when offer expires
+/// * `visibility` - Whether any taker can fill the offer or only whitelisted ones
+/// * `close_on_completion` - Whether the offer and whitelist PDAs are closed on the final fill
+/// * `start_time` - Unix timestamp from when the offer can be taken
+/// * `min_fill_amount` - Smallest input token amount a single fill can take
+/// * `all_or_none` - Whether the offer must be taken in a single fill
+/// * `custody` - Whether the tokens move into a vault or stay delegated in the maker's account
+/// * `acceptance_window` - Seconds the maker has to settle escrowed fills, None to settle on take
+/// * `arbitrator` - Key resolving disputes on escrowed fills, None to disable disputes
 ///
 /// # Steps
 /// 1. Validate all input parameters
 /// 2. Initialize offer PDA with trade details
-/// 3. Create vault and transfer tokens
+/// 3. Create vault and transfer tokens, or approve the offer PDA as delegate
 /// 4. Apply protocol configuration
 /// 5. Update admin statistics
 ///
 /// # Errors
+/// * `SwapError::CreatePaused` - If creating offers is paused
 /// * `SwapError::InvalidDeadline` - If deadline is in the past
+/// * `SwapError::InvalidStartTime` - If start time is not before the deadline
 /// * `SwapError::InvalidAmount` - If token amount is zero
+/// * `SwapError::InvalidMinFillAmount` - If minimum fill exceeds token amount
+/// * `SwapError::OfferIdAlreadyUsed` - If the maker already used an offer id at or above `offer_id`
+/// * `SwapError::MintDenied` - If either mint is denied
+/// * `SwapError::MintHalted` - If trading either mint is halted
+/// * `SwapError::TradeAboveMaximum` - If the smallest fill the offer allows is above a mint's maximum trade size
 /// * `SwapError::AdminNotInitialized` - If admin config not set
 /// * `SwapError::FeeConfigNotInitialized` - If fee config not set
 /// * `SwapError::InvalidTokenAccount` - If token accounts don't match
+/// * `SwapError::MintNotWhitelisted` - If whitelist is required and either mint is not listed
+/// * `SwapError::InvalidCustodyAccounts` - If the vault is missing for a vault offer or passed for a delegated one
+/// * `SwapError::InvalidAcceptanceWindow` - If the acceptance window is not positive
+/// * `SwapError::EscrowRequiresVault` - If an escrowed offer is delegated
+/// * `SwapError::ArbitratorRequiresEscrow` - If an arbitrator is set without an acceptance window
+#[allow(clippy::too_many_arguments)]
 pub fn initialize_offer(
     ctx: Context<CreateOffer>,
     offer_id:u64,
     token_amount: u64,
     expected_amount: u64,
     deadline: i64,
+    visibility: OfferVisibility,
+    close_on_completion: bool,
+    start_time: i64,
+    min_fill_amount: u64,
+    all_or_none: bool,
+    custody: OfferCustody,
+    acceptance_window: Option<i64>,
+    arbitrator: Option<Pubkey>,
 ) -> Result<()> {
     // Validate all inputs
+    require!(!ctx.accounts.admin_config.create_paused, SwapError::CreatePaused);
     let current_time = Clock::get()?.unix_timestamp;
     require!(deadline > current_time, SwapError::InvalidDeadline);
+    require!(start_time < deadline, SwapError::InvalidStartTime);
     require!(token_amount > 0, SwapError::InvalidAmount);
+    require!(min_fill_amount <= token_amount, SwapError::InvalidMinFillAmount);
+    if let Some(window) = acceptance_window {
+        require!(window > 0, SwapError::InvalidAcceptanceWindow);
+        require!(custody == OfferCustody::Vault, SwapError::EscrowRequiresVault);
+    }
+    require!(arbitrator.is_none() || acceptance_window.is_some(), SwapError::ArbitratorRequiresEscrow);
+    ctx.accounts.mint_whitelist.validate_mints(
+        &ctx.accounts.whitelist_config,
+        &[ctx.accounts.input_token_mint.key(), ctx.accounts.output_token_mint.key()],
+        &[
+            ctx.accounts.input_listed_mint.to_account_info(),
+            ctx.accounts.output_listed_mint.to_account_info(),
+        ],
+    )?;
+    MintConfig::validate(&[
+        ctx.accounts.input_mint_config.to_account_info(),
+        ctx.accounts.output_mint_config.to_account_info(),
+    ])?;
+    // The smallest fill the offer allows must fit under the listed maximum trade sizes,
+    // an all-or-none offer is taken in a single final fill
+    let smallest_fill = if all_or_none { token_amount } else { min_fill_amount };
+    ListedMint::validate_trade(&ctx.accounts.input_listed_mint, smallest_fill, true)?;
+    if all_or_none {
+        ListedMint::validate_trade(&ctx.accounts.output_listed_mint, expected_amount, true)?;
+    }
 
-    // let maker_sequence = &mut ctx.accounts.maker_sequence;
-    // maker_sequence.offer_count = maker_sequence.offer_count.checked_add(1)
-    //     .ok_or(SwapError::SequenceOverflow)?;
-
-    // if maker_sequence.maker == Pubkey::default() {
-    //     maker_sequence.maker = ctx.accounts.maker.key();
-    // }
+    ctx.accounts.maker_sequence.consume(ctx.accounts.maker.key(), offer_id)?;
 
     // Initialize offer parameters
     let offer = &mut ctx.accounts.offer;
@@ -207,25 +378,52 @@ pub fn initialize_offer(
     offer.token_amount_remaining = token_amount;
     offer.expected_fulfilled_amount = 0;
     offer.deadline = deadline;
+    offer.start_time = start_time;
+    offer.visibility = visibility;
+    offer.close_on_completion = close_on_completion;
+    offer.min_fill_amount = min_fill_amount;
+    offer.all_or_none = all_or_none;
+    offer.taker_merkle_root = None;
+    offer.approver = None;
+    offer.custody = custody;
+    offer.acceptance_window = acceptance_window;
+    offer.fill_count = 0;
+    offer.pending_fills = 0;
+    offer.arbitrator = arbitrator;
     
     // Copy protocol configuration
     offer.fee_percentage = ctx.accounts.fee_config.fee_percentage;
     offer.fee_wallet = ctx.accounts.fee_config.fee_address;
+    offer.expiry_bounty = ExpiryConfig::load_bounty(&ctx.accounts.expiry_config)?;
 
-    // Transfer tokens to vault with amount validation
-    token_interface::transfer_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token_interface::TransferChecked {
-                from: ctx.accounts.maker_token_account.to_account_info(),
-                mint: ctx.accounts.input_token_mint.to_account_info(),
-                to: ctx.accounts.vault_token_account.to_account_info(),
-                authority: ctx.accounts.maker.to_account_info(),
-            },
-        ),
-        token_amount,
-        ctx.accounts.input_token_mint.decimals,
-    )?;
+    match (custody, ctx.accounts.vault_token_account.as_ref()) {
+        (OfferCustody::Vault, Some(vault_token_account)) => {
+            // Transfer tokens to vault with amount validation
+            token_interface::transfer_checked(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_interface::TransferChecked {
+                        from: ctx.accounts.maker_token_account.to_account_info(),
+                        mint: ctx.accounts.input_token_mint.to_account_info(),
+                        to: vault_token_account.to_account_info(),
+                        authority: ctx.accounts.maker.to_account_info(),
+                    },
+                ),
+                token_amount,
+                ctx.accounts.input_token_mint.decimals,
+            )?;
+        }
+        (OfferCustody::Delegate, None) => {
+            approve_offer_delegate(
+                &ctx.accounts.token_program,
+                &ctx.accounts.maker_token_account,
+                offer,
+                &ctx.accounts.maker,
+                token_amount,
+            )?;
+        }
+        _ => return err!(SwapError::InvalidCustodyAccounts),
+    }
 
 
     // Activate the offer
@@ -239,17 +437,47 @@ pub fn initialize_offer(
         token_amount,
         expected_amount,
         deadline,
+        start_time,
+        visibility,
+        close_on_completion,
+        min_fill_amount,
+        all_or_none,
+        custody,
+        acceptance_window,
+        arbitrator,
     });
 
     Ok(())
 }
 
+/// Approves the offer PDA as delegate of the maker's token account
+/// Replaces any previous delegation on that account
+pub(crate) fn approve_offer_delegate<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    maker_token_account: &InterfaceAccount<'info, TokenAccount>,
+    offer: &Account<'info, Offer>,
+    maker: &Signer<'info>,
+    amount: u64,
+) -> Result<()> {
+    token_interface::approve(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token_interface::Approve {
+                to: maker_token_account.to_account_info(),
+                delegate: offer.to_account_info(),
+                authority: maker.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
 /// Updates multiple takers to an offer's whitelist
 /// Creates whitelist PDA if it doesn't exist
 /// 
 /// # Arguments
 /// * `ctx` - ManageWhitelist context
-/// * `takers` - Vector of taker public keys to add
+/// * `takers` - Vector of takers to add, each with an optional input token allocation
 ///
 /// # Security
 /// - Only callable by offer maker
@@ -262,20 +490,31 @@ pub fn initialize_offer(
 /// * `SwapError::UnauthorizedMaker` - If caller isn't offer maker
 pub fn manage_takers(
     ctx: Context<ManageWhitelist>,
-    takers: Vec<Pubkey>,
+    takers: Vec<WhitelistEntry>,
 ) -> Result<()> {
     require!(!takers.is_empty(), SwapError::EmptyTakersList);
-    require!(takers.len() < 50, SwapError::WhitelistFull);
+    let max_takers = ctx.accounts.max_takers()?;
+    require!(takers.len() <= max_takers, SwapError::WhitelistFull);
     
-    let whitelist = &mut ctx.accounts.whitelist;
     let maker = ctx.accounts.maker.key();
     let offer_id = ctx.accounts.offer.offer_id;
 
+    resize_whitelist(
+        &ctx.accounts.whitelist,
+        &ctx.accounts.maker,
+        &ctx.accounts.system_program,
+        takers.len(),
+    )?;
+
+    let whitelist = &mut ctx.accounts.whitelist;
+    whitelist.maker = maker;
+    whitelist.offer = ctx.accounts.offer.key();
+
     // Clear the existing takers
     whitelist.takers.clear();
 
     for taker in takers {
-        whitelist.takers.push(taker);
+        whitelist.add_taker(taker, max_takers)?;
     }
 
     emit!(TakerUpdated {
@@ -285,4 +524,142 @@ pub fn manage_takers(
     });
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Adds takers to an offer's whitelist, keeping the existing ones
+/// Creates whitelist PDA if it doesn't exist and grows it to fit the new takers
+/// 
+/// # Arguments
+/// * `ctx` - ManageWhitelist context
+/// * `takers` - Vector of takers to add, each with an optional input token allocation
+///
+/// # Errors
+/// * `SwapError::EmptyTakersList` - If takers list is empty
+/// * `SwapError::TakerAlreadyWhitelisted` - If a taker is already whitelisted
+/// * `SwapError::WhitelistFull` - If adding would exceed capacity
+/// * `SwapError::UnauthorizedMaker` - If caller isn't offer maker
+pub fn add_whitelist_takers(
+    ctx: Context<ManageWhitelist>,
+    takers: Vec<WhitelistEntry>,
+) -> Result<()> {
+    require!(!takers.is_empty(), SwapError::EmptyTakersList);
+    let max_takers = ctx.accounts.max_takers()?;
+    let new_len = ctx.accounts.whitelist.takers.len()
+        .checked_add(takers.len())
+        .ok_or(SwapError::CalculationError)?;
+    require!(new_len <= max_takers, SwapError::WhitelistFull);
+
+    let maker = ctx.accounts.maker.key();
+    let offer_id = ctx.accounts.offer.offer_id;
+
+    resize_whitelist(
+        &ctx.accounts.whitelist,
+        &ctx.accounts.maker,
+        &ctx.accounts.system_program,
+        new_len,
+    )?;
+
+    let whitelist = &mut ctx.accounts.whitelist;
+    whitelist.maker = maker;
+    whitelist.offer = ctx.accounts.offer.key();
+
+    for entry in takers {
+        whitelist.add_taker(entry, max_takers)?;
+
+        emit!(TakerAdded {
+            offer_id,
+            maker,
+            taker: entry.taker,
+            allocation: entry.allocation,
+        });
+    }
+
+    Ok(())
+}
+
+/// Removes takers from an offer's whitelist
+/// Shrinks the whitelist PDA and returns the freed rent to the maker
+/// 
+/// # Arguments
+/// * `ctx` - RemoveTakers context
+/// * `takers` - Vector of taker public keys to remove
+///
+/// # Errors
+/// * `SwapError::EmptyTakersList` - If takers list is empty
+/// * `SwapError::TakerNotWhitelisted` - If a taker is not whitelisted
+/// * `SwapError::UnauthorizedMaker` - If caller isn't offer maker
+pub fn remove_whitelist_takers(
+    ctx: Context<RemoveTakers>,
+    takers: Vec<Pubkey>,
+) -> Result<()> {
+    require!(!takers.is_empty(), SwapError::EmptyTakersList);
+
+    let maker = ctx.accounts.maker.key();
+    let offer_id = ctx.accounts.offer.offer_id;
+    let whitelist = &mut ctx.accounts.whitelist;
+
+    for taker in takers {
+        whitelist.remove_taker(&taker)?;
+
+        emit!(TakerRemoved {
+            offer_id,
+            maker,
+            taker,
+        });
+    }
+
+    let new_len = whitelist.takers.len();
+    resize_whitelist(
+        &ctx.accounts.whitelist,
+        &ctx.accounts.maker,
+        &ctx.accounts.system_program,
+        new_len,
+    )?;
+
+    Ok(())
+}
+
+impl<'info> ManageWhitelist<'info> {
+    /// Maximum number of takers allowed in the whitelist
+    fn max_takers(&self) -> Result<usize> {
+        TakerWhitelistConfig::load_max_takers(&self.taker_whitelist_config)
+    }
+}
+
+/// Resizes the whitelist PDA to fit `taker_count` takers
+/// The maker pays the rent for growing and receives the rent freed by shrinking
+fn resize_whitelist<'info>(
+    whitelist: &Account<'info, Whitelist>,
+    maker: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    taker_count: usize,
+) -> Result<()> {
+    let whitelist_info = whitelist.to_account_info();
+    let new_space = Whitelist::space(taker_count);
+    if whitelist_info.data_len() == new_space {
+        return Ok(());
+    }
+
+    let required_lamports = Rent::get()?.minimum_balance(new_space);
+    let current_lamports = whitelist_info.lamports();
+
+    if required_lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: maker.to_account_info(),
+                    to: whitelist_info.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    } else if current_lamports > required_lamports {
+        let refund = current_lamports - required_lamports;
+        **whitelist_info.try_borrow_mut_lamports()? -= refund;
+        **maker.to_account_info().try_borrow_mut_lamports()? += refund;
+    }
+
+    whitelist_info.realloc(new_space, false)?;
+    Ok(())
+}
@@ -0,0 +1,830 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface}
+};
+use crate::state::*;
+use crate::error::*;
+use crate::instructions::close_offer::close_offer_accounts;
+use crate::instructions::taker_offer::fee_on;
+
+#[event]
+pub struct FillSettled {
+    pub offer_id: u64,
+    pub fill_id: u64,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub input_token_amount: u64,
+    pub payment_amount: u64,
+    pub fee_amount: u64,
+    pub remaining_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FillRejected {
+    pub offer_id: u64,
+    pub fill_id: u64,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub input_token_amount: u64,
+    pub refunded_amount: u64,
+    pub remaining_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeRaised {
+    pub offer_id: u64,
+    pub fill_id: u64,
+    pub raised_by: Pubkey,
+    pub arbitrator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub offer_id: u64,
+    pub fill_id: u64,
+    pub arbitrator: Pubkey,
+    pub input_to_taker: u64,
+    pub input_to_maker: u64,
+    pub payment_to_maker: u64,
+    pub fee_amount: u64,
+    pub refund_to_taker: u64,
+    pub timestamp: i64,
+}
+
+/// Account validation structure for disputing an escrowed fill
+/// Freezes the fill until the offer's arbitrator resolves it
+#[derive(Accounts)]
+pub struct RaiseDispute<'info> {
+    /// The maker or the taker of the fill
+    pub party: Signer<'info>,
+
+    /// The offer the fill was made on
+    ///
+    /// Seeds: ["offer", maker_pubkey, offer_id]
+    #[account(
+        seeds = [b"offer", offer.maker.as_ref(), &offer.offer_id.to_le_bytes()],
+        bump,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    /// The escrowed fill being disputed
+    ///
+    /// Seeds: ["fill", offer, fill_id]
+    #[account(
+        mut,
+        seeds = [b"fill", offer.key().as_ref(), &fill.fill_id.to_le_bytes()],
+        bump,
+    )]
+    pub fill: Box<Account<'info, Fill>>,
+}
+
+/// Account validation structure for releasing an escrowed fill to the taker
+/// Shared by the settle and resolve dispute instructions
+#[derive(Accounts)]
+pub struct ResolveFill<'info> {
+    /// The maker settling the fill or the arbitrator resolving its dispute
+    /// Pays for token account creation
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The offer the fill was made on
+    ///
+    /// Seeds: ["offer", maker_pubkey, offer_id]
+    #[account(
+        mut,
+        seeds = [b"offer", offer.maker.as_ref(), &offer.offer_id.to_le_bytes()],
+        bump,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    /// The escrowed fill, closed with rent returned to the taker
+    ///
+    /// Seeds: ["fill", offer, fill_id]
+    #[account(
+        mut,
+        close = taker,
+        seeds = [b"fill", offer.key().as_ref(), &fill.fill_id.to_le_bytes()],
+        bump,
+    )]
+    pub fill: Box<Account<'info, Fill>>,
+
+    /// CHECK: Verified through the constraint with offer.maker
+    #[account(
+        mut,
+        constraint = maker.key() == offer.maker @ SwapError::InvalidMaker
+    )]
+    pub maker: AccountInfo<'info>,
+
+    /// CHECK: Verified through the constraint with fill.taker
+    #[account(
+        mut,
+        constraint = taker.key() == fill.taker @ SwapError::InvalidTaker
+    )]
+    pub taker: AccountInfo<'info>,
+
+    /// CHECK: Whitelist PDA of the offer, loaded in the handler if one was created
+    /// The allocation a fill used up is given back for the tokens the taker doesn't receive,
+    /// and the whitelist closes with an offer that closes on completion
+    ///
+    /// Seeds: ["whitelist", maker_pubkey, offer_id]
+    #[account(
+        mut,
+        seeds = [b"whitelist", offer.maker.as_ref(), &offer.offer_id.to_le_bytes()],
+        bump,
+    )]
+    pub whitelist: UncheckedAccount<'info>,
+
+    /// Cumulative fills of the taker on the offer
+    /// Required when the fill was recorded in it
+    ///
+    /// Seeds: ["taker_fill", offer, taker]
+    #[account(
+        mut,
+        seeds = [b"taker_fill", offer.key().as_ref(), fill.taker.as_ref()],
+        bump,
+    )]
+    pub taker_fill: Option<Box<Account<'info, TakerFill>>>,
+
+    /// The vault holding the reserved input tokens
+    #[account(
+        mut,
+        associated_token::mint = input_token_mint,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The offer account holding the escrowed payments
+    #[account(
+        mut,
+        associated_token::mint = output_token_mint,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program,
+    )]
+    pub payment_escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Maker's token account receiving the payment on settlement
+    /// Automatically created as an ATA if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = output_token_mint,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_receive_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Taker's token account receiving the input tokens on settlement
+    /// Automatically created as an ATA if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = input_token_mint,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program,
+    )]
+    pub taker_receive_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Taker's token account receiving the refunded part of a disputed payment
+    /// Automatically created as an ATA if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = output_token_mint,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program,
+    )]
+    pub taker_refund_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Protocol fee receiving account
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = output_token_mint,
+        associated_token::authority = fee_wallet,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Must match the fee wallet copied on the offer
+    #[account(address = offer.fee_wallet)]
+    pub fee_wallet: AccountInfo<'info>,
+
+    /// Fee configuration providing the fee charged on disputed fills
+    /// Only required to resolve disputes
+    #[account(
+        seeds = [b"fee"],
+        bump,
+    )]
+    pub fee_config: Option<Box<Account<'info, FeeConfig>>>,
+
+    /// Maker's token account receiving the input tokens the arbitrator returns
+    /// Only required to resolve disputes
+    #[account(
+        mut,
+        constraint = maker_token_account.owner == maker.key() @ SwapError::InvalidTokenAccount,
+        constraint = maker_token_account.mint == input_token_mint.key() @ SwapError::InvalidTokenMint
+    )]
+    pub maker_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Mint of the token being offered
+    #[account(
+        constraint = input_token_mint.key() == offer.input_token_mint @ SwapError::InvalidTokenMint
+    )]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Mint of the token being requested
+    #[account(
+        constraint = output_token_mint.key() == offer.output_token_mint @ SwapError::InvalidTokenMint
+    )]
+    pub output_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token interface program for Token-2022 support
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Required for ATA initialization
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation structure for rejecting an escrowed fill
+/// Only holds the accounts a refund touches, so a cranker rejecting a
+/// timed out fill doesn't pay for token accounts it never uses
+#[derive(Accounts)]
+pub struct RejectFill<'info> {
+    /// The maker rejecting the fill, or anyone once its acceptance deadline has passed
+    /// Pays for the taker's refund account if it doesn't exist
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The offer the fill was made on
+    ///
+    /// Seeds: ["offer", maker_pubkey, offer_id]
+    #[account(
+        mut,
+        seeds = [b"offer", offer.maker.as_ref(), &offer.offer_id.to_le_bytes()],
+        bump,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    /// The escrowed fill, closed with rent returned to the taker
+    ///
+    /// Seeds: ["fill", offer, fill_id]
+    #[account(
+        mut,
+        close = taker,
+        seeds = [b"fill", offer.key().as_ref(), &fill.fill_id.to_le_bytes()],
+        bump,
+    )]
+    pub fill: Box<Account<'info, Fill>>,
+
+    /// CHECK: Verified through the constraint with offer.maker
+    #[account(
+        mut,
+        constraint = maker.key() == offer.maker @ SwapError::InvalidMaker
+    )]
+    pub maker: AccountInfo<'info>,
+
+    /// CHECK: Verified through the constraint with fill.taker
+    #[account(
+        mut,
+        constraint = taker.key() == fill.taker @ SwapError::InvalidTaker
+    )]
+    pub taker: AccountInfo<'info>,
+
+    /// CHECK: Whitelist PDA of the offer, loaded in the handler if one was created
+    /// The allocation a fill used up is given back for the tokens the taker doesn't receive,
+    /// and the whitelist closes with an offer that closes on completion
+    ///
+    /// Seeds: ["whitelist", maker_pubkey, offer_id]
+    #[account(
+        mut,
+        seeds = [b"whitelist", offer.maker.as_ref(), &offer.offer_id.to_le_bytes()],
+        bump,
+    )]
+    pub whitelist: UncheckedAccount<'info>,
+
+    /// Cumulative fills of the taker on the offer
+    /// Required when the fill was recorded in it
+    ///
+    /// Seeds: ["taker_fill", offer, taker]
+    #[account(
+        mut,
+        seeds = [b"taker_fill", offer.key().as_ref(), fill.taker.as_ref()],
+        bump,
+    )]
+    pub taker_fill: Option<Box<Account<'info, TakerFill>>>,
+
+    /// The vault holding the reserved input tokens, closed with the offer's last fill
+    #[account(
+        mut,
+        associated_token::mint = input_token_mint,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The offer account holding the escrowed payments
+    #[account(
+        mut,
+        associated_token::mint = output_token_mint,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program,
+    )]
+    pub payment_escrow_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Taker's token account receiving the refund
+    /// Automatically created as an ATA if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = output_token_mint,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program,
+    )]
+    pub taker_refund_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Mint of the token being offered
+    #[account(
+        constraint = input_token_mint.key() == offer.input_token_mint @ SwapError::InvalidTokenMint
+    )]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Mint of the token being requested
+    #[account(
+        constraint = output_token_mint.key() == offer.output_token_mint @ SwapError::InvalidTokenMint
+    )]
+    pub output_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token interface program for Token-2022 support
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Required for ATA initialization
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Transfers tokens out of an offer owned account, signed by the offer PDA
+fn transfer_from_offer<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    offer: &Account<'info, Offer>,
+    offer_bump: u8,
+    from: &InterfaceAccount<'info, TokenAccount>,
+    to: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let offer_id = offer.offer_id.to_le_bytes();
+    let seeds = &[
+        b"offer".as_ref(),
+        offer.maker.as_ref(),
+        offer_id.as_ref(),
+        &[offer_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: offer.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// Closes an offer owned account, rent returned to the maker
+fn close_offer_account<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    offer: &Account<'info, Offer>,
+    offer_bump: u8,
+    account: &InterfaceAccount<'info, TokenAccount>,
+    maker: &AccountInfo<'info>,
+) -> Result<()> {
+    let offer_id = offer.offer_id.to_le_bytes();
+    let seeds = &[
+        b"offer".as_ref(),
+        offer.maker.as_ref(),
+        offer_id.as_ref(),
+        &[offer_bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token_interface::close_account(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_interface::CloseAccount {
+                account: account.to_account_info(),
+                destination: maker.clone(),
+                authority: offer.to_account_info(),
+            },
+            signer_seeds,
+        )
+    )
+}
+
+/// Drops the resolved fill from the offer's pending fills, completing the
+/// offer and closing its token accounts once nothing is left or pending
+fn release_pending_fill<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    offer: &mut Account<'info, Offer>,
+    offer_bump: u8,
+    vault_token_account: &InterfaceAccount<'info, TokenAccount>,
+    payment_escrow_token_account: &InterfaceAccount<'info, TokenAccount>,
+    maker: &AccountInfo<'info>,
+) -> Result<()> {
+    let pending_fills = offer.pending_fills
+        .checked_sub(1)
+        .ok_or(SwapError::CalculationError)?;
+
+    if offer.token_amount_remaining == 0 && pending_fills == 0 {
+        msg!("Last fill resolved, closing vault and payment escrow");
+        close_offer_account(token_program, offer, offer_bump, vault_token_account, maker)?;
+        close_offer_account(token_program, offer, offer_bump, payment_escrow_token_account, maker)?;
+        offer.status = OfferStatus::Completed;
+    }
+
+    offer.pending_fills = pending_fills;
+    Ok(())
+}
+
+/// Closes the offer and its whitelist PDAs once its last fill is resolved,
+/// if the maker opted into closing on completion
+fn close_if_completed<'info>(
+    offer: &Account<'info, Offer>,
+    whitelist: &AccountInfo<'info>,
+    maker: &AccountInfo<'info>,
+) -> Result<()> {
+    if offer.status == OfferStatus::Completed && offer.close_on_completion {
+        close_offer_accounts(offer, whitelist.clone(), maker.clone())?;
+    }
+    Ok(())
+}
+
+/// Gives back `amount` input tokens of a fill the taker didn't receive to the
+/// whitelist allocation and recorded taker fills the fill was counted against
+///
+/// # Errors
+/// * `SwapError::TakerFillRequired` - If the fill was recorded but the taker fill account is missing
+fn return_taker_allocation<'info>(
+    fill: &Fill,
+    whitelist: &AccountInfo<'info>,
+    taker_fill: Option<&mut Box<Account<'info, TakerFill>>>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    if fill.consumed_allocation {
+        if let Some(mut offer_whitelist) = Whitelist::load(whitelist)? {
+            offer_whitelist.return_allocation(&fill.taker, amount)?;
+            offer_whitelist.store(whitelist)?;
+        }
+    }
+
+    if fill.recorded_taker_fill {
+        let taker_fill = taker_fill.ok_or(SwapError::TakerFillRequired)?;
+        taker_fill.filled_amount = taker_fill.filled_amount
+            .checked_sub(amount)
+            .ok_or(SwapError::CalculationError)?;
+    }
+    Ok(())
+}
+
+/// Settles an escrowed fill, releasing both legs
+///
+/// # Arguments
+/// * `ctx` - ResolveFill context
+///
+/// # Flow
+/// 1. Transfers the reserved input tokens from the vault to the taker
+/// 2. Pays the escrowed payment to the maker and the fee to the fee wallet
+/// 3. Completes the offer and closes its token accounts once nothing is left or pending
+/// 4. Closes the offer and whitelist PDAs on completion if the maker opted in
+/// 5. Closes the fill with rent returned to the taker
+///
+/// # Errors
+/// * `SwapError::UnauthorizedMaker` - If caller isn't offer maker
+/// * `SwapError::FillAcceptanceExpired` - If the acceptance deadline has passed
+/// * `SwapError::FillDisputed` - If the fill is waiting for the arbitrator
+pub fn settle(ctx: Context<ResolveFill>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.authority.key() == ctx.accounts.offer.maker, SwapError::UnauthorizedMaker);
+    require!(current_time <= ctx.accounts.fill.accept_deadline, SwapError::FillAcceptanceExpired);
+    require!(!ctx.accounts.fill.disputed, SwapError::FillDisputed);
+
+    let offer_bump = ctx.bumps.offer;
+    let accounts = &mut *ctx.accounts;
+    let fill = &accounts.fill;
+
+    transfer_from_offer(
+        &accounts.token_program,
+        &accounts.offer,
+        offer_bump,
+        &accounts.vault_token_account,
+        &accounts.taker_receive_token_account,
+        &accounts.input_token_mint,
+        fill.input_token_amount,
+    )?;
+    transfer_from_offer(
+        &accounts.token_program,
+        &accounts.offer,
+        offer_bump,
+        &accounts.payment_escrow_token_account,
+        &accounts.fee_token_account,
+        &accounts.output_token_mint,
+        fill.fee_amount,
+    )?;
+    transfer_from_offer(
+        &accounts.token_program,
+        &accounts.offer,
+        offer_bump,
+        &accounts.payment_escrow_token_account,
+        &accounts.maker_receive_token_account,
+        &accounts.output_token_mint,
+        fill.payment_amount,
+    )?;
+
+    release_pending_fill(
+        &accounts.token_program,
+        &mut accounts.offer,
+        offer_bump,
+        &accounts.vault_token_account,
+        &accounts.payment_escrow_token_account,
+        &accounts.maker,
+    )?;
+
+    let offer = &ctx.accounts.offer;
+    emit!(FillSettled {
+        offer_id: offer.offer_id,
+        fill_id: ctx.accounts.fill.fill_id,
+        maker: offer.maker,
+        taker: ctx.accounts.fill.taker,
+        input_token_amount: ctx.accounts.fill.input_token_amount,
+        payment_amount: ctx.accounts.fill.payment_amount,
+        fee_amount: ctx.accounts.fill.fee_amount,
+        remaining_amount: offer.token_amount_remaining,
+        timestamp: current_time,
+    });
+
+    close_if_completed(&ctx.accounts.offer, &ctx.accounts.whitelist, &ctx.accounts.maker)
+}
+
+/// Rejects an escrowed fill, refunding the taker
+///
+/// The reserved input tokens are released back to the offer, and the
+/// whitelist allocation or recorded taker fills the fill used up are given
+/// back so the taker can take them again.
+/// A disputed fill can only be rejected once the arbitrator let its
+/// dispute deadline pass.
+///
+/// # Arguments
+/// * `ctx` - RejectFill context
+///
+/// # Errors
+/// * `SwapError::CannotRejectFill` - If a non-maker rejects before the acceptance deadline
+/// * `SwapError::FillDisputed` - If the fill is waiting for the arbitrator
+/// * `SwapError::TakerFillRequired` - If the fill was recorded but the taker fill account is missing
+pub fn reject(ctx: Context<RejectFill>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let is_maker = ctx.accounts.authority.key() == ctx.accounts.offer.maker;
+    let is_timed_out = current_time > ctx.accounts.fill.accept_deadline;
+    require!(is_maker || is_timed_out, SwapError::CannotRejectFill);
+    require!(
+        !ctx.accounts.fill.disputed || current_time > ctx.accounts.fill.dispute_deadline()?,
+        SwapError::FillDisputed
+    );
+
+    let offer_bump = ctx.bumps.offer;
+    let accounts = &mut *ctx.accounts;
+    let fill = &accounts.fill;
+    let refunded_amount = fill.payment_amount
+        .checked_add(fill.fee_amount)
+        .ok_or(SwapError::CalculationError)?;
+
+    transfer_from_offer(
+        &accounts.token_program,
+        &accounts.offer,
+        offer_bump,
+        &accounts.payment_escrow_token_account,
+        &accounts.taker_refund_token_account,
+        &accounts.output_token_mint,
+        refunded_amount,
+    )?;
+
+    let input_token_amount = fill.input_token_amount;
+    let payment_amount = fill.payment_amount;
+    return_taker_allocation(
+        &accounts.fill,
+        &accounts.whitelist,
+        accounts.taker_fill.as_mut(),
+        input_token_amount,
+    )?;
+
+    let offer = &mut accounts.offer;
+    offer.token_amount_remaining = offer.token_amount_remaining
+        .checked_add(input_token_amount)
+        .ok_or(SwapError::CalculationError)?;
+    offer.expected_fulfilled_amount = offer.expected_fulfilled_amount
+        .checked_sub(payment_amount)
+        .ok_or(SwapError::CalculationError)?;
+    release_pending_fill(
+        &accounts.token_program,
+        &mut accounts.offer,
+        offer_bump,
+        &accounts.vault_token_account,
+        &accounts.payment_escrow_token_account,
+        &accounts.maker,
+    )?;
+
+    let offer = &ctx.accounts.offer;
+    emit!(FillRejected {
+        offer_id: offer.offer_id,
+        fill_id: ctx.accounts.fill.fill_id,
+        maker: offer.maker,
+        taker: ctx.accounts.fill.taker,
+        input_token_amount,
+        refunded_amount,
+        remaining_amount: offer.token_amount_remaining,
+        timestamp: current_time,
+    });
+
+    close_if_completed(&ctx.accounts.offer, &ctx.accounts.whitelist, &ctx.accounts.maker)
+}
+
+/// Disputes an escrowed fill, freezing it until the arbitrator resolves it
+/// or its dispute deadline passes
+///
+/// # Arguments
+/// * `ctx` - RaiseDispute context
+///
+/// # Errors
+/// * `SwapError::NoArbitrator` - If the offer has no arbitrator
+/// * `SwapError::CannotDisputeFill` - If the caller is neither the maker nor the taker
+/// * `SwapError::DisputeWindowClosed` - If the acceptance deadline has passed
+/// * `SwapError::FillDisputed` - If the fill is already disputed
+pub fn dispute(ctx: Context<RaiseDispute>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let arbitrator = ctx.accounts.offer.arbitrator.ok_or(SwapError::NoArbitrator)?;
+    let party = ctx.accounts.party.key();
+    require!(
+        party == ctx.accounts.offer.maker || party == ctx.accounts.fill.taker,
+        SwapError::CannotDisputeFill
+    );
+    require!(current_time <= ctx.accounts.fill.accept_deadline, SwapError::DisputeWindowClosed);
+    require!(!ctx.accounts.fill.disputed, SwapError::FillDisputed);
+
+    ctx.accounts.fill.disputed = true;
+
+    emit!(DisputeRaised {
+        offer_id: ctx.accounts.offer.offer_id,
+        fill_id: ctx.accounts.fill.fill_id,
+        raised_by: party,
+        arbitrator,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// Resolves a disputed fill by splitting its escrow between maker and taker
+///
+/// The protocol fee is charged on the payment released to the maker at the
+/// current `FeeConfig` percentage, the rest of the escrowed payment and fee
+/// is refunded to the taker. Input tokens returned to the maker are given
+/// back to the taker's allocation like on a rejection.
+///
+/// # Arguments
+/// * `ctx` - ResolveFill context
+/// * `input_to_taker` - Reserved input tokens released to the taker, the rest returns to the maker
+/// * `payment_to_maker` - Escrowed output tokens released to the maker
+///
+/// # Errors
+/// * `SwapError::UnauthorizedArbitrator` - If caller isn't the offer arbitrator
+/// * `SwapError::FillNotDisputed` - If the fill is not disputed
+/// * `SwapError::DisputeAccountsRequired` - If the fee config or maker token account is missing
+/// * `SwapError::InvalidDisputeSplit` - If the split exceeds the escrowed amounts
+/// * `SwapError::TakerFillRequired` - If the fill was recorded but the taker fill account is missing
+pub fn resolve(ctx: Context<ResolveFill>, input_to_taker: u64, payment_to_maker: u64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let arbitrator = ctx.accounts.authority.key();
+    require!(ctx.accounts.offer.arbitrator == Some(arbitrator), SwapError::UnauthorizedArbitrator);
+    require!(ctx.accounts.fill.disputed, SwapError::FillNotDisputed);
+
+    let accounts = &ctx.accounts;
+    let fill = &accounts.fill;
+    let fee_config = accounts.fee_config.as_ref().ok_or(SwapError::DisputeAccountsRequired)?;
+    let maker_token_account = accounts.maker_token_account.as_ref().ok_or(SwapError::DisputeAccountsRequired)?;
+
+    let input_to_maker = fill.input_token_amount
+        .checked_sub(input_to_taker)
+        .ok_or(SwapError::InvalidDisputeSplit)?;
+    let fee_amount = fee_on(payment_to_maker, fee_config.fee_percentage)?;
+    let refund_to_taker = fill.payment_amount
+        .checked_add(fill.fee_amount)
+        .ok_or(SwapError::CalculationError)?
+        .checked_sub(payment_to_maker)
+        .and_then(|rest| rest.checked_sub(fee_amount))
+        .ok_or(SwapError::InvalidDisputeSplit)?;
+
+    let offer_bump = ctx.bumps.offer;
+    transfer_from_offer(
+        &accounts.token_program,
+        &accounts.offer,
+        offer_bump,
+        &accounts.vault_token_account,
+        &accounts.taker_receive_token_account,
+        &accounts.input_token_mint,
+        input_to_taker,
+    )?;
+    transfer_from_offer(
+        &accounts.token_program,
+        &accounts.offer,
+        offer_bump,
+        &accounts.vault_token_account,
+        maker_token_account,
+        &accounts.input_token_mint,
+        input_to_maker,
+    )?;
+    transfer_from_offer(
+        &accounts.token_program,
+        &accounts.offer,
+        offer_bump,
+        &accounts.payment_escrow_token_account,
+        &accounts.fee_token_account,
+        &accounts.output_token_mint,
+        fee_amount,
+    )?;
+    transfer_from_offer(
+        &accounts.token_program,
+        &accounts.offer,
+        offer_bump,
+        &accounts.payment_escrow_token_account,
+        &accounts.maker_receive_token_account,
+        &accounts.output_token_mint,
+        payment_to_maker,
+    )?;
+    transfer_from_offer(
+        &accounts.token_program,
+        &accounts.offer,
+        offer_bump,
+        &accounts.payment_escrow_token_account,
+        &accounts.taker_refund_token_account,
+        &accounts.output_token_mint,
+        refund_to_taker,
+    )?;
+
+    let escrowed_payment = fill.payment_amount;
+    let accounts = &mut *ctx.accounts;
+    return_taker_allocation(
+        &accounts.fill,
+        &accounts.whitelist,
+        accounts.taker_fill.as_mut(),
+        input_to_maker,
+    )?;
+
+    let offer = &mut accounts.offer;
+    offer.expected_fulfilled_amount = offer.expected_fulfilled_amount
+        .checked_sub(escrowed_payment)
+        .and_then(|fulfilled| fulfilled.checked_add(payment_to_maker))
+        .ok_or(SwapError::CalculationError)?;
+    release_pending_fill(
+        &accounts.token_program,
+        &mut accounts.offer,
+        offer_bump,
+        &accounts.vault_token_account,
+        &accounts.payment_escrow_token_account,
+        &accounts.maker,
+    )?;
+
+    emit!(DisputeResolved {
+        offer_id: ctx.accounts.offer.offer_id,
+        fill_id: ctx.accounts.fill.fill_id,
+        arbitrator,
+        input_to_taker,
+        input_to_maker,
+        payment_to_maker,
+        fee_amount,
+        refund_to_taker,
+        timestamp: current_time,
+    });
+
+    close_if_completed(&ctx.accounts.offer, &ctx.accounts.whitelist, &ctx.accounts.maker)
+}
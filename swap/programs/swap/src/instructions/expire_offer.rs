@@ -0,0 +1,272 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022,
+    token_interface::{self, Mint, TokenAccount, TokenInterface}
+};
+use crate::state::*;
+use crate::error::*;
+
+#[event]
+pub struct OfferExpired {
+    pub offer_id: u64,
+    pub maker: Pubkey,
+    pub cranker: Pubkey,
+    pub returned_amount: u64,
+    pub bounty_amount: u64,
+    pub token_mint: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Account validation structure for the expire offer instruction
+/// Permissionless crank returning the remaining tokens of an
+/// offer past its deadline back to the maker
+#[derive(Accounts)]
+pub struct ExpireOffer<'info> {
+    /// Anyone calling the crank, pays for token account creation
+    /// and receives the expiry bounty if one is configured
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// The expired offer
+    ///
+    /// Constraints:
+    /// - Must be in Ongoing status
+    ///
+    /// Seeds: ["offer", maker_pubkey, offer_id]
+    #[account(
+        mut,
+        constraint = offer.status == OfferStatus::Ongoing @ SwapError::InvalidOfferStatus,
+        seeds = [b"offer", offer.maker.as_ref(), &offer.offer_id.to_le_bytes()],
+        bump,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    /// CHECK: Verified through the constraint with offer.maker
+    #[account(
+        mut,
+        constraint = maker.key() == offer.maker @ SwapError::InvalidMaker
+    )]
+    pub maker: AccountInfo<'info>,
+
+    /// Maker's token account receiving the returned tokens
+    /// For delegated offers, the account whose delegation to the offer is revoked
+    /// Automatically created as an ATA if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = input_token_mint,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Cranker's token account receiving the bounty
+    /// Automatically created as an ATA if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = input_token_mint,
+        associated_token::authority = cranker,
+        associated_token::token_program = token_program,
+    )]
+    pub cranker_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account holding the offered tokens
+    /// Closed after returning tokens
+    /// Only passed for vault offers
+    #[account(
+        mut,
+        associated_token::mint = input_token_mint,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program,
+    )]
+    pub vault_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The mint of the token being returned
+    #[account(
+        constraint = input_token_mint.key() == offer.input_token_mint @ SwapError::InvalidTokenMint
+    )]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Token interface program for Token-2022 support
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Required for ATA initialization
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Expires an offer whose deadline has passed
+///
+/// # Arguments
+/// * `ctx` - ExpireOffer context
+///
+/// # Flow
+/// 1. Checks the offer deadline has passed
+/// 2. Pays the bounty copied on the offer at creation to the cranker
+/// 3. Returns the rest of the remaining tokens to the maker
+/// 4. Closes the vault with rent returned to maker
+/// 5. Marks the offer as Expired
+///
+/// Delegated offers hold no tokens, their delegation is revoked when
+/// possible and they are marked as Expired without paying a bounty.
+///
+/// # Errors
+/// * `SwapError::OfferNotExpired` - If the deadline has not passed yet
+/// * `SwapError::InvalidOfferStatus` - If offer is not in Ongoing status
+/// * `SwapError::InvalidCustodyAccounts` - If the vault is missing for a vault offer
+/// * `SwapError::PendingFills` - If escrowed fills are still waiting for settlement
+pub fn expire(ctx: Context<ExpireOffer>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time > ctx.accounts.offer.deadline, SwapError::OfferNotExpired);
+    require!(ctx.accounts.offer.pending_fills == 0, SwapError::PendingFills);
+
+    let remaining = ctx.accounts.offer.token_amount_remaining;
+    if ctx.accounts.offer.custody == OfferCustody::Delegate {
+        revoke_offer_delegation(&ctx)?;
+        return mark_expired(ctx, remaining, 0, current_time);
+    }
+    let vault_token_account = ctx.accounts.vault_token_account
+        .as_ref()
+        .ok_or(SwapError::InvalidCustodyAccounts)?;
+
+    let bounty_amount = (remaining as u128)
+        .checked_mul(ctx.accounts.offer.expiry_bounty as u128)
+        .ok_or(SwapError::CalculationError)?
+        .checked_div(10000)
+        .ok_or(SwapError::CalculationError)? as u64;
+    let returned_amount = remaining
+        .checked_sub(bounty_amount)
+        .ok_or(SwapError::CalculationError)?;
+
+    let seeds = &[
+        b"offer",
+        ctx.accounts.offer.maker.as_ref(),
+        &ctx.accounts.offer.offer_id.to_le_bytes(),
+        &[ctx.bumps.offer],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    let decimals = ctx.accounts.input_token_mint.decimals;
+
+    if bounty_amount > 0 {
+        msg!("Paying {} tokens bounty to cranker", bounty_amount);
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: vault_token_account.to_account_info(),
+                    mint: ctx.accounts.input_token_mint.to_account_info(),
+                    to: ctx.accounts.cranker_token_account.to_account_info(),
+                    authority: ctx.accounts.offer.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            bounty_amount,
+            decimals,
+        )?;
+    }
+
+    msg!("Returning {} tokens to maker", returned_amount);
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: vault_token_account.to_account_info(),
+                mint: ctx.accounts.input_token_mint.to_account_info(),
+                to: ctx.accounts.maker_token_account.to_account_info(),
+                authority: ctx.accounts.offer.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        returned_amount,
+        decimals,
+    )?;
+
+    token_interface::close_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::CloseAccount {
+                account: vault_token_account.to_account_info(),
+                destination: ctx.accounts.maker.to_account_info(),
+                authority: ctx.accounts.offer.to_account_info(),
+            },
+            signer_seeds,
+        )
+    )?;
+
+    mark_expired(ctx, returned_amount, bounty_amount, current_time)
+}
+
+/// Drops the offer's delegation on the maker's token account if it is still the offer's
+///
+/// Token-2022 lets the offer PDA revoke its own delegation. The original Token
+/// program only accepts the owner, so there it is revoked when the maker cranks
+/// the expiry. Otherwise it stays inert, the offer never moves tokens once expired.
+fn revoke_offer_delegation(ctx: &Context<ExpireOffer>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    if accounts.maker_token_account.delegate != Some(accounts.offer.key()).into() {
+        return Ok(());
+    }
+
+    if accounts.cranker.key() == accounts.offer.maker {
+        msg!("Revoking offer delegation");
+        return token_interface::revoke(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                token_interface::Revoke {
+                    source: accounts.maker_token_account.to_account_info(),
+                    authority: accounts.cranker.to_account_info(),
+                },
+            )
+        );
+    }
+
+    if accounts.token_program.key() == token_2022::ID {
+        msg!("Revoking offer delegation");
+        let seeds = &[
+            b"offer",
+            accounts.offer.maker.as_ref(),
+            &accounts.offer.offer_id.to_le_bytes(),
+            &[ctx.bumps.offer],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        token_interface::revoke(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                token_interface::Revoke {
+                    source: accounts.maker_token_account.to_account_info(),
+                    authority: accounts.offer.to_account_info(),
+                },
+                signer_seeds,
+            )
+        )?;
+    }
+    Ok(())
+}
+
+/// Marks the offer as Expired once its remaining tokens are back with the maker
+fn mark_expired(
+    ctx: Context<ExpireOffer>,
+    returned_amount: u64,
+    bounty_amount: u64,
+    current_time: i64,
+) -> Result<()> {
+    let offer = &mut ctx.accounts.offer;
+    offer.token_amount_remaining = 0;
+    offer.status = OfferStatus::Expired;
+
+    emit!(OfferExpired {
+        offer_id: offer.offer_id,
+        maker: offer.maker,
+        cranker: ctx.accounts.cranker.key(),
+        returned_amount,
+        bounty_amount,
+        token_mint: offer.input_token_mint,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
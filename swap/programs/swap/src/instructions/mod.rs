@@ -1,9 +1,23 @@
+pub mod adjust_offer;
 pub mod admin;
+pub mod admin_multisig;
 pub mod cancel_offer;
+pub mod close_offer;
 pub mod create_offer;
+pub mod escrow_fill;
+pub mod expire_offer;
+pub mod signed_order;
 pub mod taker_offer;
+pub mod update_offer;
 
+pub use adjust_offer::*;
 pub use admin::*;
+pub use admin_multisig::*;
 pub use cancel_offer::*;
+pub use close_offer::*;
 pub use create_offer::*;
-pub use taker_offer::*;
\ No newline at end of file
+pub use escrow_fill::*;
+pub use expire_offer::*;
+pub use signed_order::*;
+pub use taker_offer::*;
+pub use update_offer::*;
\ No newline at end of file
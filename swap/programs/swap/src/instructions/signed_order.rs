@@ -0,0 +1,437 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface}
+};
+use crate::constants::ORDER_NONCES_PER_PAGE;
+use crate::state::*;
+use crate::error::*;
+use crate::ed25519;
+use crate::instructions::taker_offer::price_fill;
+
+#[event]
+pub struct SignedOrderFilled {
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub nonce: u64,
+    pub input_token_mint: Pubkey,
+    pub output_token_mint: Pubkey,
+    pub input_token_amount: u64,
+    pub payment_amount: u64,
+    pub fee_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SignedOrderCancelled {
+    pub maker: Pubkey,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+/// Order signed off-chain by a maker and settled by the taker in one transaction
+///
+/// The maker signs with Ed25519 the program id followed by the Borsh
+/// serialization of the order, and approves the ["order_delegate", maker]
+/// PDA as delegate of their input token account. No offer or vault is created.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct SignedOrder {
+    /// Maker selling the input tokens
+    pub maker: Pubkey,
+
+    /// Mint of the token being sold
+    pub input_token_mint: Pubkey,
+
+    /// Mint of the token requested in return
+    pub output_token_mint: Pubkey,
+
+    /// Amount of input tokens sold, always filled in full
+    pub input_token_amount: u64,
+
+    /// Amount of output tokens the maker receives
+    pub expected_total_amount: u64,
+
+    /// Unix timestamp after which the order can no longer be filled
+    pub deadline: i64,
+
+    /// Maker chosen nonce, each nonce can be filled or cancelled once
+    pub nonce: u64,
+
+    /// Only taker allowed to fill the order, None for anyone
+    pub taker: Option<Pubkey>,
+}
+
+impl SignedOrder {
+    /// Message the maker signs for this order
+    /// Prefixed with the program id so orders can't be replayed on other deployments
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = crate::ID.to_bytes().to_vec();
+        self.serialize(&mut message).map_err(|_| error!(SwapError::InvalidOrderSignature))?;
+        Ok(message)
+    }
+}
+
+/// Account validation structure for filling a signed order
+/// The taker submits the maker's order right after an Ed25519 program
+/// instruction verifying the maker's signature over it
+#[derive(Accounts)]
+#[instruction(order: SignedOrder)]
+pub struct FillSignedOrder<'info> {
+    /// Taker filling the order, pays for account initialization
+    #[account(mut)]
+    pub taker: Signer<'info>,
+
+    /// CHECK: Verified against the signed order
+    #[account(
+        constraint = maker.key() == order.maker @ SwapError::InvalidMaker
+    )]
+    pub maker: AccountInfo<'info>,
+
+    /// CHECK: PDA approved by the maker as delegate of the input token account
+    /// Seeds: ["order_delegate", maker]
+    #[account(
+        seeds = [b"order_delegate", maker.key().as_ref()],
+        bump,
+    )]
+    pub order_delegate: AccountInfo<'info>,
+
+    /// Used nonces of the maker for the order's nonce page
+    /// Seeds: ["order_nonces", maker, page]
+    // The page is computed inline, the IDL build can't evaluate `OrderNonces::page_of` in seeds
+    #[account(
+        init_if_needed,
+        payer = taker,
+        space = 8 + 32 + 8 + 128,
+        seeds = [b"order_nonces", maker.key().as_ref(), &(order.nonce / ORDER_NONCES_PER_PAGE).to_le_bytes()],
+        bump,
+    )]
+    pub order_nonces: Box<Account<'info, OrderNonces>>,
+
+    /// Admin configuration providing the take pause flag
+    #[account(
+        seeds = [b"admin_config"],
+        bump,
+    )]
+    pub admin_config: Box<Account<'info, AdminConfig>>,
+
+    /// Fee configuration providing the fee percentage and wallet
+    #[account(
+        seeds = [b"fee"],
+        bump,
+    )]
+    pub fee_config: Box<Account<'info, FeeConfig>>,
+
+    /// Whitelist configuration deciding if mint whitelist is enforced
+    #[account(
+        seeds = [b"whitelist_config"],
+        bump
+    )]
+    pub whitelist_config: Box<Account<'info, WhitelistConfig>>,
+
+    /// Mint whitelist, checked like for vault offers
+    #[account(
+        seeds = [b"mint_whitelist"],
+        bump
+    )]
+    pub mint_whitelist: Box<Account<'info, MintWhitelist>>,
+
+    /// Maker's token account the input tokens are taken from
+    ///
+    /// Constraints:
+    /// - Must be owned by maker
+    /// - Must match the order's input mint
+    #[account(
+        mut,
+        constraint = maker_token_account.owner == maker.key() @ SwapError::InvalidTokenAccount,
+        constraint = maker_token_account.mint == input_token_mint.key() @ SwapError::InvalidTokenMint
+    )]
+    pub maker_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Maker's token account for receiving payment
+    /// Automatically created as an ATA if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = output_token_mint,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program,
+    )]
+    pub maker_receive_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Taker's token account for sending payment
+    #[account(
+        mut,
+        constraint = taker_payment_token_account.owner == taker.key() @ SwapError::InvalidTokenAccount,
+        constraint = taker_payment_token_account.mint == output_token_mint.key() @ SwapError::InvalidTokenMint
+    )]
+    pub taker_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Taker's token account for receiving the input tokens
+    /// Automatically created as an ATA if it doesn't exist
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = input_token_mint,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program,
+    )]
+    pub taker_receive_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Protocol fee receiving account
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = output_token_mint,
+        associated_token::authority = fee_wallet,
+        associated_token::token_program = token_program,
+    )]
+    pub fee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Must match the configured fee wallet
+    #[account(address = fee_config.fee_address)]
+    pub fee_wallet: AccountInfo<'info>,
+
+    /// Mint of the token being sold
+    #[account(
+        constraint = input_token_mint.key() == order.input_token_mint @ SwapError::InvalidTokenMint
+    )]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Mint of the token being requested
+    #[account(
+        constraint = output_token_mint.key() == order.output_token_mint @ SwapError::InvalidTokenMint
+    )]
+    pub output_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: Optional ["mint_config", input_token_mint] PDA, checked in the handler
+    #[account(
+        seeds = [b"mint_config", input_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub input_mint_config: UncheckedAccount<'info>,
+
+    /// CHECK: Optional ["mint_config", output_token_mint] PDA, checked in the handler
+    #[account(
+        seeds = [b"mint_config", output_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub output_mint_config: UncheckedAccount<'info>,
+
+    /// CHECK: Optional ["listed_mint", input_token_mint] PDA, checked in the handler
+    #[account(
+        seeds = [b"listed_mint", input_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub input_listed_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Optional ["listed_mint", output_token_mint] PDA, checked in the handler
+    #[account(
+        seeds = [b"listed_mint", output_token_mint.key().as_ref()],
+        bump,
+    )]
+    pub output_listed_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar, used to find the Ed25519 instruction verifying the order
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Required program interfaces
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Account validation structure for cancelling a signed order
+/// Lets the maker burn a nonce so an order signed with it can't be filled
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct CancelSignedOrder<'info> {
+    /// Maker who signed the order
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    /// Used nonces of the maker for the nonce's page
+    /// Seeds: ["order_nonces", maker, page]
+    #[account(
+        init_if_needed,
+        payer = maker,
+        space = 8 + 32 + 8 + 128,
+        seeds = [b"order_nonces", maker.key().as_ref(), &(nonce / ORDER_NONCES_PER_PAGE).to_le_bytes()],
+        bump,
+    )]
+    pub order_nonces: Account<'info, OrderNonces>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Fills a maker's signed order in full
+///
+/// # Arguments
+/// * `ctx` - FillSignedOrder context
+/// * `order` - Order signed by the maker
+/// * `max_fee_amount` - Maximum protocol fee the taker accepts to pay
+///
+/// # Flow
+/// 1. Validates the order and the maker's signature
+/// 2. Consumes the order nonce
+/// 3. Transfers the fee and payment from the taker
+/// 4. Transfers the input tokens from the maker using the delegate PDA
+///
+/// # Errors
+/// * `SwapError::TakePaused` - If taking offers is paused
+/// * `SwapError::OrderExpired` - If the order deadline has passed
+/// * `SwapError::OrderTakerMismatch` - If the order is reserved for another taker
+/// * `SwapError::InvalidAmount` - If either order amount is zero
+/// * `SwapError::InvalidOrderSignature` - If no Ed25519 instruction verifies the maker's signature
+/// * `SwapError::OrderNonceUsed` - If the order was already filled or cancelled
+/// * `SwapError::InsufficientDelegation` - If the delegate PDA can't move the input tokens
+/// * `SwapError::MaxFeeExceeded` - If the fee exceeds `max_fee_amount`
+/// * `SwapError::MintNotWhitelisted` - If either mint isn't whitelisted while required
+/// * `SwapError::MintDenied` - If either mint is denied
+/// * `SwapError::MintHalted` - If trading either mint is halted
+/// * `SwapError::TradeBelowMinimum` - If an amount is below its mint's minimum trade size
+/// * `SwapError::TradeAboveMaximum` - If an amount is above its mint's maximum trade size
+pub fn fill_order(ctx: Context<FillSignedOrder>, order: SignedOrder, max_fee_amount: u64) -> Result<()> {
+    require!(!ctx.accounts.admin_config.take_paused, SwapError::TakePaused);
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time <= order.deadline, SwapError::OrderExpired);
+    if let Some(taker) = order.taker {
+        require!(taker == ctx.accounts.taker.key(), SwapError::OrderTakerMismatch);
+    }
+    require!(order.input_token_amount > 0 && order.expected_total_amount > 0, SwapError::InvalidAmount);
+
+    ctx.accounts.mint_whitelist.validate_mints(
+        &ctx.accounts.whitelist_config,
+        &[order.input_token_mint, order.output_token_mint],
+        &[
+            ctx.accounts.input_listed_mint.to_account_info(),
+            ctx.accounts.output_listed_mint.to_account_info(),
+        ],
+    )?;
+    MintConfig::validate(&[
+        ctx.accounts.input_mint_config.to_account_info(),
+        ctx.accounts.output_mint_config.to_account_info(),
+    ])?;
+
+    require!(
+        ed25519::has_preceding_signature(&ctx.accounts.instructions_sysvar, &order.maker, &order.message()?)?,
+        SwapError::InvalidOrderSignature
+    );
+
+    let order_nonces = &mut ctx.accounts.order_nonces;
+    order_nonces.maker = order.maker;
+    order_nonces.page = OrderNonces::page_of(order.nonce);
+    order_nonces.consume(order.nonce)?;
+
+    let maker_token_account = &ctx.accounts.maker_token_account;
+    require!(
+        maker_token_account.delegate == Some(ctx.accounts.order_delegate.key()).into()
+            && maker_token_account.delegated_amount >= order.input_token_amount
+            && maker_token_account.amount >= order.input_token_amount,
+        SwapError::InsufficientDelegation
+    );
+
+    // The whole order is filled, so the payment is exactly the signed amount
+    let (fee_amount, payment_amount) = price_fill(
+        order.input_token_amount,
+        order.input_token_amount,
+        order.expected_total_amount,
+        ctx.accounts.fee_config.fee_percentage,
+    )?;
+    require!(fee_amount <= max_fee_amount, SwapError::MaxFeeExceeded);
+    ListedMint::validate_trade(&ctx.accounts.input_listed_mint, order.input_token_amount, false)?;
+    ListedMint::validate_trade(&ctx.accounts.output_listed_mint, payment_amount, false)?;
+
+    let output_decimals = ctx.accounts.output_token_mint.decimals;
+
+    // Protocol fee transfer
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.taker_payment_token_account.to_account_info(),
+                mint: ctx.accounts.output_token_mint.to_account_info(),
+                to: ctx.accounts.fee_token_account.to_account_info(),
+                authority: ctx.accounts.taker.to_account_info(),
+            },
+        ),
+        fee_amount,
+        output_decimals,
+    )?;
+
+    // Maker payment transfer
+    token_interface::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.taker_payment_token_account.to_account_info(),
+                mint: ctx.accounts.output_token_mint.to_account_info(),
+                to: ctx.accounts.maker_receive_token_account.to_account_info(),
+                authority: ctx.accounts.taker.to_account_info(),
+            },
+        ),
+        payment_amount,
+        output_decimals,
+    )?;
+
+    // Input tokens from the maker, signed by the delegate PDA
+    let maker_key = ctx.accounts.maker.key();
+    let seeds = &[
+        b"order_delegate",
+        maker_key.as_ref(),
+        &[ctx.bumps.order_delegate],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::TransferChecked {
+                from: ctx.accounts.maker_token_account.to_account_info(),
+                mint: ctx.accounts.input_token_mint.to_account_info(),
+                to: ctx.accounts.taker_receive_token_account.to_account_info(),
+                authority: ctx.accounts.order_delegate.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        order.input_token_amount,
+        ctx.accounts.input_token_mint.decimals,
+    )?;
+
+    emit!(SignedOrderFilled {
+        maker: order.maker,
+        taker: ctx.accounts.taker.key(),
+        nonce: order.nonce,
+        input_token_mint: order.input_token_mint,
+        output_token_mint: order.output_token_mint,
+        input_token_amount: order.input_token_amount,
+        payment_amount,
+        fee_amount,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// Cancels a signed order by consuming its nonce
+///
+/// # Arguments
+/// * `ctx` - CancelSignedOrder context
+/// * `nonce` - Nonce of the order to cancel
+///
+/// # Errors
+/// * `SwapError::OrderNonceUsed` - If the order was already filled or cancelled
+pub fn cancel_order(ctx: Context<CancelSignedOrder>, nonce: u64) -> Result<()> {
+    let order_nonces = &mut ctx.accounts.order_nonces;
+    order_nonces.maker = ctx.accounts.maker.key();
+    order_nonces.page = OrderNonces::page_of(nonce);
+    order_nonces.consume(nonce)?;
+
+    emit!(SignedOrderCancelled {
+        maker: ctx.accounts.maker.key(),
+        nonce,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -5,6 +5,9 @@ use anchor_spl::{
 };
 use crate::state::*;
 use crate::error::*;
+use crate::merkle::{self, MerkleProof};
+use crate::ticket::AllowTicket;
+use crate::instructions::close_offer::close_offer_accounts;
 
 #[event]
 pub struct OfferTaken {
@@ -18,6 +21,19 @@ pub struct OfferTaken {
    pub remaining_amount: u64,
    pub input_token_mint: Pubkey,
    pub output_token_mint: Pubkey,
+   pub taker_remaining_allocation: Option<u64>,
+}
+
+#[event]
+pub struct FillPending {
+    pub offer_id: u64,
+    pub fill_id: u64,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub input_token_amount: u64,
+    pub payment_amount: u64,
+    pub fee_amount: u64,
+    pub accept_deadline: i64,
 }
 
 /// Core accounts required for the take offer instruction.
@@ -42,6 +58,22 @@ pub struct CoreAccounts<'info> {
         bump
     )]
     pub admin_config: Box<Account<'info, AdminConfig>>,
+
+    /// Whitelist configuration deciding if mint whitelist is enforced.
+    /// PDA with seeds: ["whitelist_config"]
+    #[account(
+        seeds = [b"whitelist_config"],
+        bump
+    )]
+    pub whitelist_config: Box<Account<'info, WhitelistConfig>>,
+
+    /// Mint whitelist, re-checked so de-listed mints cannot be filled.
+    /// PDA with seeds: ["mint_whitelist"]
+    #[account(
+        seeds = [b"mint_whitelist"],
+        bump
+    )]
+    pub mint_whitelist: Box<Account<'info, MintWhitelist>>,
     
     /// The offer being taken.
     /// PDA with seeds: ["offer", maker_pubkey, offer_id]
@@ -63,18 +95,79 @@ pub struct CoreAccounts<'info> {
     )]
     pub maker: AccountInfo<'info>,
 
-    /// Whitelist of authorized takers.
+    /// CHECK: Listing of the input mint.
+    /// PDA with seeds: ["listed_mint", input_token_mint], checked in the handler
+    #[account(
+        seeds = [b"listed_mint", offer.input_token_mint.as_ref()],
+        bump,
+    )]
+    pub input_listed_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Listing of the output mint.
+    /// PDA with seeds: ["listed_mint", output_token_mint], checked in the handler
+    #[account(
+        seeds = [b"listed_mint", offer.output_token_mint.as_ref()],
+        bump,
+    )]
+    pub output_listed_mint: UncheckedAccount<'info>,
+
+    /// CHECK: Optional config of the input mint.
+    /// PDA with seeds: ["mint_config", input_token_mint], checked in the handler
+    #[account(
+        seeds = [b"mint_config", offer.input_token_mint.as_ref()],
+        bump,
+    )]
+    pub input_mint_config: UncheckedAccount<'info>,
+
+    /// CHECK: Optional config of the output mint.
+    /// PDA with seeds: ["mint_config", output_token_mint], checked in the handler
+    #[account(
+        seeds = [b"mint_config", offer.output_token_mint.as_ref()],
+        bump,
+    )]
+    pub output_mint_config: UncheckedAccount<'info>,
+
+    /// CHECK: Whitelist of authorized takers, checked in the handler.
     /// PDA with seeds: ["whitelist", maker_pubkey, offer_id]
-    /// Constraints:
-    /// - Must include taker's public key
-    /// - Closed on offer completion
+    /// Only initialized if the maker added takers. On restricted offers it must
+    /// include the taker's public key and the taker's allocation is decremented by each fill
     #[account(
         mut,
         seeds = [b"whitelist", offer.maker.as_ref(), &offer.offer_id.to_le_bytes()],
         bump,
-        constraint = whitelist.takers.contains(&taker.key()) @ SwapError::TakerNotWhitelisted,
     )]
-    pub whitelist: Box<Account<'info, Whitelist>>,
+    pub whitelist: UncheckedAccount<'info>,
+
+    /// Cumulative fills of the taker on this offer.
+    /// PDA with seeds: ["taker_fill", offer, taker]
+    /// Only required when taking through a capped Merkle allowlist leaf or an allow ticket
+    #[account(
+        init_if_needed,
+        payer = taker,
+        space = 8 + 32 + 32 + 8,
+        seeds = [b"taker_fill", offer.key().as_ref(), taker.key().as_ref()],
+        bump,
+    )]
+    pub taker_fill: Option<Box<Account<'info, TakerFill>>>,
+
+    /// CHECK: Instructions sysvar, used to find the Ed25519 instruction verifying an allow ticket
+    /// Only required when taking with an allow ticket
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// Escrow record of the fill on offers with an acceptance window.
+    /// PDA with seeds: ["fill", offer, fill_count]
+    /// Only required when the offer has an acceptance window
+    #[account(
+        init,
+        payer = taker,
+        space = 8 + 32 + 8 + 32 + 8 + 8 + 8 + 8 + 1 + 1 + 1,
+        seeds = [b"fill", offer.key().as_ref(), &offer.fill_count.to_le_bytes()],
+        bump,
+    )]
+    pub fill: Option<Box<Account<'info, Fill>>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 /// Token accounts and associated programs for the take offer instruction.
@@ -126,16 +219,29 @@ pub struct TokenAccounts<'info> {
     pub fee_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// Vault holding the offered tokens.
+    /// For delegated offers, the maker's token account the offer PDA is delegate of.
     /// Constraints:
-    /// - Must be owned by offer PDA
+    /// - Must be owned by offer PDA, or by the maker for delegated offers
     /// - Must match input token mint
     #[account(
         mut,
-        constraint = vault_token_account.owner == offer.key() @ SwapError::InvalidTokenAccount,
+        constraint = vault_token_account.owner == offer.key()
+            || vault_token_account.owner == maker.key() @ SwapError::InvalidTokenAccount,
         constraint = vault_token_account.mint == input_token_mint.key() @ SwapError::InvalidTokenMint
     )]
     pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// Offer owned account holding the payments of escrowed fills.
+    /// Only required when the offer has an acceptance window
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = output_token_mint,
+        associated_token::authority = offer,
+        associated_token::token_program = token_program,
+    )]
+    pub payment_escrow_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     /// Mint of the token being offered
     pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
 
@@ -147,17 +253,17 @@ pub struct TokenAccounts<'info> {
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 
-    /// CHECK: Fee wallet validated through fee_token_account constraints
+    /// CHECK: Checked against offer.fee_wallet in the handler
     pub fee_wallet: AccountInfo<'info>,
 
-    /// CHECK: Validated as transaction signer in CoreAccounts
+    /// CHECK: Checked against the signer of CoreAccounts in the handler
     #[account(mut)]
     pub taker: AccountInfo<'info>,
 
-    /// CHECK: Validated through offer.maker constraint in CoreAccounts
+    /// CHECK: Checked against offer.maker in the handler
     pub maker: AccountInfo<'info>,
 
-    /// CHECK: Validated through PDA seeds and constraints in CoreAccounts
+    /// CHECK: Checked against the offer of CoreAccounts in the handler
     pub offer: AccountInfo<'info>,
 }
 
@@ -175,6 +281,21 @@ pub struct AccountRefs<'info, 'ctx> {
     pub token: &'ctx TokenAccounts<'info>,
 }
 
+/// How a taker was allowed to take an offer
+enum TakerAccess {
+    /// Public offer, anyone can take it
+    Open,
+
+    /// Taker is listed in the offer's whitelist PDA
+    Whitelisted,
+
+    /// Taker proved inclusion in the offer's Merkle allowlist
+    Allowlisted { allocation: Option<u64> },
+
+    /// Taker presented an allow ticket signed by the offer's approver
+    Ticketed { max_amount: u64 },
+}
+
 impl<'info> TakeOffer<'info> {
     /// Creates a reference wrapper for convenient account access
     fn refs(&self) -> AccountRefs<'info, '_> {
@@ -190,6 +311,9 @@ impl<'info> TakeOffer<'info> {
 /// # Arguments
 /// * `ctx` - TakeOffer context containing all accounts
 /// * `token_amount` - Amount of input tokens to take
+/// * `max_payment_amount` - Maximum payment the taker accepts to send to the maker
+/// * `max_fee_amount` - Maximum protocol fee the taker accepts to pay
+/// * `merkle_proof` - Proof of inclusion in the offer's Merkle allowlist, if used
 /// 
 /// # Returns
 /// * `Result<()>` - Success or error
@@ -197,43 +321,294 @@ impl<'info> TakeOffer<'info> {
 /// # Flow
 /// 1. Validates all offer conditions
 /// 2. Calculates payment amounts including fees
-/// 3. Processes token transfers
-/// 4. Updates offer state and handles completion
-pub fn process(mut ctx: Context<TakeOffer>, input_token_amount: u64) -> Result<()> {
+/// 3. Checks amounts against the taker's limits
+/// 4. Processes token transfers
+/// 5. Updates offer state and handles completion
+///
+/// # Errors
+/// * `SwapError::MintDenied` - If either mint is denied
+/// * `SwapError::MintHalted` - If trading either mint is halted
+/// * `SwapError::MaxPaymentExceeded` - If payment is above `max_payment_amount`
+/// * `SwapError::MaxFeeExceeded` - If fee is above `max_fee_amount`
+/// * `SwapError::TradeBelowMinimum` - If an amount of a partial fill is below its mint's minimum trade size
+/// * `SwapError::TradeAboveMaximum` - If an amount is above its mint's maximum trade size
+pub fn process(
+    mut ctx: Context<TakeOffer>,
+    input_token_amount: u64,
+    max_payment_amount: u64,
+    max_fee_amount: u64,
+    merkle_proof: Option<MerkleProof>,
+    allow_ticket: Option<AllowTicket>,
+) -> Result<()> {
     msg!("Processing take offer with amount: {}", input_token_amount);
 
     let refs = ctx.accounts.refs();
+    validate_token_accounts(&refs)?;
     
-    validate_offer_conditions(refs.core, input_token_amount)?;
+    let access = validate_offer_conditions(
+        refs.core,
+        input_token_amount,
+        merkle_proof.as_ref(),
+        allow_ticket.as_ref(),
+    )?;
+    MintConfig::validate(&[
+        refs.core.input_mint_config.to_account_info(),
+        refs.core.output_mint_config.to_account_info(),
+    ])?;
+    validate_token_source(&refs, input_token_amount)?;
     let (fee_amount, payment_amount) = calculate_payments(&refs.core.offer, input_token_amount)?;
     msg!("Calculated payments - Fee: {}, Payment: {}", fee_amount, payment_amount);
+
+    require!(payment_amount <= max_payment_amount, SwapError::MaxPaymentExceeded);
+    require!(fee_amount <= max_fee_amount, SwapError::MaxFeeExceeded);
+    validate_trade_sizes(refs.core, input_token_amount, payment_amount)?;
     
     process_payments(refs, fee_amount, payment_amount)?;
-    handle_vault_transfer_and_completion(&mut ctx, input_token_amount, fee_amount, payment_amount)?;
+    handle_vault_transfer_and_completion(&mut ctx, input_token_amount, fee_amount, payment_amount, access)?;
     
     Ok(())
 }
 
+/// Processes a take offer instruction where the taker specifies the payment.
+/// 
+/// # Arguments
+/// * `ctx` - TakeOffer context containing all accounts
+/// * `payment_amount` - Amount of output tokens the taker pays to the maker
+/// * `min_input_token_amount` - Minimum input tokens the taker accepts to receive
+/// * `max_fee_amount` - Maximum protocol fee the taker accepts to pay
+/// * `merkle_proof` - Proof of inclusion in the offer's Merkle allowlist, if used
+/// 
+/// # Returns
+/// * `Result<()>` - Success or error
+/// 
+/// # Flow
+/// 1. Derives the input amount from the payment, rounded down in the maker's favour
+/// 2. Validates all offer conditions for the derived amount
+/// 3. Calculates the protocol fee on the payment
+/// 4. Processes token transfers
+/// 5. Updates offer state and handles completion
+///
+/// # Errors
+/// * `SwapError::InvalidAmount` - If the payment is too small to buy a single input token unit
+/// * `SwapError::MinInputNotMet` - If derived input is below `min_input_token_amount`
+/// * `SwapError::MintDenied` - If either mint is denied
+/// * `SwapError::MintHalted` - If trading either mint is halted
+/// * `SwapError::MaxFeeExceeded` - If fee is above `max_fee_amount`
+/// * `SwapError::TradeBelowMinimum` - If an amount of a partial fill is below its mint's minimum trade size
+/// * `SwapError::TradeAboveMaximum` - If an amount is above its mint's maximum trade size
+pub fn process_exact_output(
+    mut ctx: Context<TakeOffer>,
+    payment_amount: u64,
+    min_input_token_amount: u64,
+    max_fee_amount: u64,
+    merkle_proof: Option<MerkleProof>,
+    allow_ticket: Option<AllowTicket>,
+) -> Result<()> {
+    msg!("Processing exact output take offer with payment: {}", payment_amount);
+
+    let refs = ctx.accounts.refs();
+    validate_token_accounts(&refs)?;
+
+    let input_token_amount = calculate_input_amount(&refs.core.offer, payment_amount)?;
+    require!(input_token_amount > 0, SwapError::InvalidAmount);
+    require!(input_token_amount >= min_input_token_amount, SwapError::MinInputNotMet);
+
+    let access = validate_offer_conditions(
+        refs.core,
+        input_token_amount,
+        merkle_proof.as_ref(),
+        allow_ticket.as_ref(),
+    )?;
+    MintConfig::validate(&[
+        refs.core.input_mint_config.to_account_info(),
+        refs.core.output_mint_config.to_account_info(),
+    ])?;
+    validate_token_source(&refs, input_token_amount)?;
+    let fee_amount = calculate_fee(&refs.core.offer, payment_amount)?;
+    msg!("Calculated input: {}, Fee: {}", input_token_amount, fee_amount);
+
+    require!(fee_amount <= max_fee_amount, SwapError::MaxFeeExceeded);
+    validate_trade_sizes(refs.core, input_token_amount, payment_amount)?;
+
+    process_payments(refs, fee_amount, payment_amount)?;
+    handle_vault_transfer_and_completion(&mut ctx, input_token_amount, fee_amount, payment_amount, access)?;
+
+    Ok(())
+}
+
+/// Checks the fill against the trade size limits of listed mints.
+/// The input amount is checked against the input mint's listing and
+/// the payment against the output mint's listing. Like the offer minimum,
+/// the minimum trade size doesn't apply to the final remainder.
+fn validate_trade_sizes(core: &CoreAccounts, input_token_amount: u64, payment_amount: u64) -> Result<()> {
+    let final_fill = input_token_amount == core.offer.token_amount_remaining;
+    ListedMint::validate_trade(&core.input_listed_mint, input_token_amount, final_fill)?;
+    ListedMint::validate_trade(&core.output_listed_mint, payment_amount, final_fill)
+}
+
 /// Validates all required conditions for taking an offer.
 /// 
 /// # Arguments
 /// * `core` - Reference to core accounts
 /// * `token_amount` - Amount of tokens being taken
+/// * `merkle_proof` - Proof of inclusion in the offer's Merkle allowlist, if used
+/// * `allow_ticket` - Allow ticket signed by the offer's approver, if used
 /// 
 /// # Returns
-/// * `Result<()>` - Success or error
+/// * `Result<TakerAccess>` - How the taker is allowed to take the offer
 /// 
 /// # Checks
-/// - Offer has not expired
+/// - Taking offers isn't paused
+/// - Offer has started and has not expired
 /// - Sufficient tokens are available
+/// - Fill respects the offer's minimum size and all-or-none flag,
+///   except for the final remainder which can always be taken
+/// - Taker is whitelisted, allowlisted or holds a valid allow ticket if the offer is restricted
+/// - Both mints are still whitelisted if the whitelist is required
 /// 
 /// # Errors
+/// * `SwapError::TakePaused` - If taking offers is paused
+/// * `SwapError::OfferNotStarted` - If offer start time has not been reached
 /// * `SwapError::OfferExpired` - If offer deadline has passed
 /// * `SwapError::InsufficientAmount` - If requested amount exceeds available
-fn validate_offer_conditions(core: &CoreAccounts, input_token_amount: u64) -> Result<()> {
+/// * `SwapError::InvalidAmount` - If requested amount is zero
+/// * `SwapError::PartialFillNotAllowed` - If an all-or-none offer is partially taken
+/// * `SwapError::FillBelowMinimum` - If requested amount is below the offer minimum
+/// * `SwapError::TakerNotWhitelisted` - If a restricted offer is taken by a non-whitelisted taker
+/// * `SwapError::AllocationExceeded` - If the fill exceeds the taker's allocation
+/// * `SwapError::InvalidMerkleProof` - If the Merkle proof doesn't match the offer's root
+/// * `SwapError::TakerFillRequired` - If a capped allowlist leaf or a ticket is used without the taker fill account
+/// * `SwapError::InvalidAllowTicket` - If the ticket doesn't match the taker, the offer or a verified signature
+/// * `SwapError::AllowTicketExpired` - If the ticket expiry has passed
+/// * `SwapError::InstructionsSysvarRequired` - If a ticket is used without the instructions sysvar
+/// * `SwapError::MintNotWhitelisted` - If either mint was removed from the mint whitelist
+fn validate_offer_conditions(
+    core: &CoreAccounts,
+    input_token_amount: u64,
+    merkle_proof: Option<&MerkleProof>,
+    allow_ticket: Option<&AllowTicket>,
+) -> Result<TakerAccess> {
+    require!(!core.admin_config.take_paused, SwapError::TakePaused);
     let current_time = Clock::get()?.unix_timestamp;
+    require!(current_time >= core.offer.start_time, SwapError::OfferNotStarted);
     require!(current_time <= core.offer.deadline, SwapError::OfferExpired);
     require!(input_token_amount <= core.offer.token_amount_remaining, SwapError::InsufficientAmount);
+    require!(input_token_amount > 0, SwapError::InvalidAmount);
+
+    if input_token_amount < core.offer.token_amount_remaining {
+        require!(!core.offer.all_or_none, SwapError::PartialFillNotAllowed);
+        require!(input_token_amount >= core.offer.min_fill_amount, SwapError::FillBelowMinimum);
+    }
+    core.mint_whitelist.validate_mints(
+        &core.whitelist_config,
+        &[core.offer.input_token_mint, core.offer.output_token_mint],
+        &[
+            core.input_listed_mint.to_account_info(),
+            core.output_listed_mint.to_account_info(),
+        ],
+    )?;
+
+    if core.offer.visibility == OfferVisibility::Public {
+        return Ok(TakerAccess::Open);
+    }
+
+    let taker = core.taker.key();
+    if let Some(whitelist) = Whitelist::load(&core.whitelist)?.filter(|whitelist| whitelist.entry(&taker).is_some()) {
+        whitelist.validate_taker(&taker, input_token_amount)?;
+        return Ok(TakerAccess::Whitelisted);
+    }
+
+    if let (Some(merkle_proof), Some(root)) = (merkle_proof, core.offer.taker_merkle_root) {
+        let leaf = merkle::leaf_hash(&taker, merkle_proof.allocation);
+        require!(merkle::verify_proof(&root, leaf, &merkle_proof.proof), SwapError::InvalidMerkleProof);
+
+        if let Some(allocation) = merkle_proof.allocation {
+            validate_cumulative_fill(core, input_token_amount, allocation)?;
+        }
+        return Ok(TakerAccess::Allowlisted { allocation: merkle_proof.allocation });
+    }
+
+    if let Some(ticket) = allow_ticket {
+        require!(ticket.offer == core.offer.key(), SwapError::InvalidAllowTicket);
+        require!(ticket.taker == taker, SwapError::InvalidAllowTicket);
+        require!(Clock::get()?.unix_timestamp <= ticket.expiry, SwapError::AllowTicketExpired);
+
+        let instructions_sysvar = core.instructions_sysvar.as_ref().ok_or(SwapError::InstructionsSysvarRequired)?;
+        let approver = core.offer.approver.unwrap_or(core.offer.maker);
+        ticket.verify_signature(instructions_sysvar, &approver)?;
+
+        // Fills are recorded per taker, so replaying the ticket can't exceed its amount
+        validate_cumulative_fill(core, input_token_amount, ticket.max_amount)?;
+        return Ok(TakerAccess::Ticketed { max_amount: ticket.max_amount });
+    }
+
+    err!(SwapError::TakerNotWhitelisted)
+}
+
+/// Checks that the taker's total fills stay within `cap` after this fill
+fn validate_cumulative_fill(core: &CoreAccounts, input_token_amount: u64, cap: u64) -> Result<()> {
+    let taker_fill = core.taker_fill.as_ref().ok_or(SwapError::TakerFillRequired)?;
+    let filled = taker_fill.filled_amount
+        .checked_add(input_token_amount)
+        .ok_or(SwapError::CalculationError)?;
+    require!(filled <= cap, SwapError::AllocationExceeded);
+    Ok(())
+}
+
+/// Binds the token side accounts to the offer validated in `CoreAccounts`.
+/// The ATAs of `TokenAccounts`, the payment escrow included, derive from these
+/// accounts, so they must be checked before any transfer.
+/// 
+/// # Errors
+/// * `SwapError::OfferAccountMismatch` - If the offer or fee wallet isn't the offer's
+/// * `SwapError::InvalidMaker` - If the maker isn't the offer's maker
+/// * `SwapError::InvalidTaker` - If the taker isn't the signing taker
+/// * `SwapError::InvalidTokenMint` - If either mint isn't the offer's
+fn validate_token_accounts(refs: &AccountRefs) -> Result<()> {
+    let offer = &refs.core.offer;
+    let token = refs.token;
+
+    require_keys_eq!(token.offer.key(), offer.key(), SwapError::OfferAccountMismatch);
+    require_keys_eq!(token.maker.key(), offer.maker, SwapError::InvalidMaker);
+    require_keys_eq!(token.taker.key(), refs.core.taker.key(), SwapError::InvalidTaker);
+    require_keys_eq!(token.fee_wallet.key(), offer.fee_wallet, SwapError::OfferAccountMismatch);
+    require_keys_eq!(token.input_token_mint.key(), offer.input_token_mint, SwapError::InvalidTokenMint);
+    require_keys_eq!(token.output_token_mint.key(), offer.output_token_mint, SwapError::InvalidTokenMint);
+    Ok(())
+}
+
+/// Validates that the offered tokens can be moved to the taker.
+/// 
+/// # Arguments
+/// * `refs` - Account references
+/// * `input_token_amount` - Amount of tokens being taken
+/// 
+/// # Checks
+/// - Vault offers take from the vault owned by the offer PDA
+/// - Delegated offers take from the maker's account, which must still
+///   delegate and hold at least the amount taken
+/// 
+/// # Errors
+/// * `SwapError::InvalidTokenAccount` - If the source account doesn't match the offer custody
+/// * `SwapError::InsufficientDelegation` - If the maker revoked the delegation or moved the tokens
+fn validate_token_source(refs: &AccountRefs, input_token_amount: u64) -> Result<()> {
+    let offer = &refs.core.offer;
+    let source = &refs.token.vault_token_account;
+
+    match offer.custody {
+        OfferCustody::Vault => {
+            require!(source.owner == offer.key(), SwapError::InvalidTokenAccount);
+        }
+        OfferCustody::Delegate => {
+            require!(source.owner == offer.maker, SwapError::InvalidTokenAccount);
+            require!(
+                source.delegate == Some(offer.key()).into()
+                    && source.delegated_amount >= input_token_amount
+                    && source.amount >= input_token_amount,
+                SwapError::InsufficientDelegation
+            );
+        }
+    }
     Ok(())
 }
 
@@ -252,17 +627,32 @@ fn validate_offer_conditions(core: &CoreAccounts, input_token_amount: u64) -> Re
 /// - Applies protocol fee percentage
 /// - Returns split between fee and payment amounts
 fn calculate_payments(offer: &Account<Offer>, input_token_amount: u64) -> Result<(u64, u64)> {
+    price_fill(
+        input_token_amount,
+        offer.token_amount,
+        offer.expected_total_amount,
+        offer.fee_percentage,
+    )
+}
+
+/// Prices a fill of `input_token_amount` out of `token_amount` offered
+/// for `expected_total_amount`, shared by offers and signed orders.
+/// 
+/// # Returns
+/// * `Result<(u64, u64)>` - (fee_amount, payment_amount)
+pub(crate) fn price_fill(
+    input_token_amount: u64,
+    token_amount: u64,
+    expected_total_amount: u64,
+    fee_percentage: u64,
+) -> Result<(u64, u64)> {
     let expected_payment = (input_token_amount as u128)
-        .checked_mul(offer.expected_total_amount as u128)
+        .checked_mul(expected_total_amount as u128)
         .ok_or(SwapError::CalculationError)?
-        .checked_div(offer.token_amount as u128)
+        .checked_div(token_amount as u128)
         .ok_or(SwapError::CalculationError)? as u64;
 
-    let fee_amount = expected_payment
-        .checked_mul(offer.fee_percentage)
-        .ok_or(SwapError::CalculationError)?
-        .checked_div(10000)
-        .ok_or(SwapError::CalculationError)?;
+    let fee_amount = fee_on(expected_payment, fee_percentage)?;
 
         
     // let payment_after_fee = expected_payment.checked_add(fee_amount).unwrap();
@@ -270,6 +660,47 @@ fn calculate_payments(offer: &Account<Offer>, input_token_amount: u64) -> Result
     Ok((fee_amount, expected_payment))
 }
 
+/// Calculates the input token amount bought by a given payment.
+/// 
+/// # Arguments
+/// * `offer` - Reference to offer account
+/// * `payment_amount` - Amount of output tokens paid to the maker
+/// 
+/// # Returns
+/// * `Result<u64>` - Input token amount, rounded down so the maker never undersells
+fn calculate_input_amount(offer: &Account<Offer>, payment_amount: u64) -> Result<u64> {
+    let input_token_amount = (payment_amount as u128)
+        .checked_mul(offer.token_amount as u128)
+        .ok_or(SwapError::CalculationError)?
+        .checked_div(offer.expected_total_amount as u128)
+        .ok_or(SwapError::CalculationError)?;
+
+    u64::try_from(input_token_amount).map_err(|_| SwapError::CalculationError.into())
+}
+
+/// Calculates the protocol fee charged on a payment.
+/// 
+/// # Arguments
+/// * `offer` - Reference to offer account
+/// * `payment_amount` - Amount of output tokens paid to the maker
+/// 
+/// # Returns
+/// * `Result<u64>` - Fee amount in output tokens
+fn calculate_fee(offer: &Account<Offer>, payment_amount: u64) -> Result<u64> {
+    fee_on(payment_amount, offer.fee_percentage)
+}
+
+/// Calculates the fee in basis points charged on a payment
+pub(crate) fn fee_on(payment_amount: u64, fee_percentage: u64) -> Result<u64> {
+    let fee_amount = payment_amount
+        .checked_mul(fee_percentage)
+        .ok_or(SwapError::CalculationError)?
+        .checked_div(10000)
+        .ok_or(SwapError::CalculationError)?;
+
+    Ok(fee_amount)
+}
+
 /// Processes fee and payment token transfers.
 /// 
 /// # Arguments
@@ -284,10 +715,36 @@ fn calculate_payments(offer: &Account<Offer>, input_token_amount: u64) -> Result
 /// 1. Transfers protocol fee to fee wallet
 /// 2. Transfers payment amount to maker
 /// 
+/// Escrowed offers move both amounts into the payment escrow instead,
+/// they are released by `settle_fill` or refunded by `reject_fill`.
+/// 
 /// # Security
 /// - Uses transfer_checked for safe token transfers
 /// - Validates all accounts and authorities
 fn process_payments(refs: AccountRefs, fee_amount: u64, payment_amount: u64) -> Result<()> {
+    if refs.core.offer.acceptance_window.is_some() {
+        let payment_escrow_token_account = refs.token.payment_escrow_token_account
+            .as_ref()
+            .ok_or(SwapError::EscrowAccountRequired)?;
+        let escrowed_amount = payment_amount
+            .checked_add(fee_amount)
+            .ok_or(SwapError::CalculationError)?;
+
+        token_interface::transfer_checked(
+            CpiContext::new(
+                refs.token.token_program.to_account_info(),
+                token_interface::TransferChecked {
+                    from: refs.token.taker_payment_token_account.to_account_info(),
+                    mint: refs.token.output_token_mint.to_account_info(),
+                    to: payment_escrow_token_account.to_account_info(),
+                    authority: refs.core.taker.to_account_info(),
+                },
+            ),
+            escrowed_amount,
+            refs.token.output_token_mint.decimals,
+        )?;
+        return Ok(());
+    }
 
     // Protocol fee transfer
     token_interface::transfer_checked(
@@ -327,18 +784,25 @@ fn process_payments(refs: AccountRefs, fee_amount: u64, payment_amount: u64) ->
 /// # Arguments
 /// * `ctx` - TakeOffer context
 /// * `token_amount` - Amount being taken
+/// * `access` - How the taker was allowed to take the offer
 /// 
 /// # Returns
 /// * `Result<()>` - Success or error
 /// 
 /// # Implementation
-/// 1. Transfers tokens from vault to taker
+/// 1. Transfers tokens from vault, or the maker's delegated account, to taker
 /// 2. For full takes:
-///    - Closes vault account
+///    - Closes vault account of vault offers
 ///    - Updates protocol statistics
 ///    - Marks offer as completed
+///    - Closes offer and whitelist PDAs if the maker opted in
 /// 3. For partial takes:
 ///    - Updates remaining token amount
+/// 4. For restricted offers:
+///    - Deducts the fill from the taker's whitelist allocation
+///    - Records the fill for capped allowlist and ticket takers
+/// 5. For escrowed offers:
+///    - Reserves the tokens in the vault and records a pending fill instead of steps 1 and 2
 /// 
 /// # Security
 /// - Properly manages PDA signing
@@ -349,6 +813,7 @@ fn handle_vault_transfer_and_completion(
     input_token_amount: u64,
     fee_amount: u64, 
     payment_amount: u64,
+    access: TakerAccess,
 ) -> Result<()> {
     // Get current values
     let current_amount = ctx.accounts.core.offer.token_amount_remaining;
@@ -366,6 +831,34 @@ fn handle_vault_transfer_and_completion(
         .checked_add(payment_amount)
         .ok_or(SwapError::CalculationError)?;
 
+    // Deduct the fill from the taker's allocation on restricted offers
+    let taker_key = ctx.accounts.core.taker.key();
+    let offer_key = ctx.accounts.core.offer.key();
+    let recorded_taker_fill = ctx.accounts.core.taker_fill.is_some();
+    if let Some(taker_fill) = ctx.accounts.core.taker_fill.as_mut() {
+        taker_fill.offer = offer_key;
+        taker_fill.taker = taker_key;
+        taker_fill.filled_amount = taker_fill.filled_amount
+            .checked_add(input_token_amount)
+            .ok_or(SwapError::CalculationError)?;
+    }
+    let taker_remaining_allocation = match access {
+        TakerAccess::Open => None,
+        TakerAccess::Whitelisted => {
+            let mut whitelist = Whitelist::load(&ctx.accounts.core.whitelist)?.ok_or(SwapError::TakerNotWhitelisted)?;
+            let remaining_allocation = whitelist.consume_allocation(&taker_key, input_token_amount)?;
+            whitelist.store(&ctx.accounts.core.whitelist)?;
+            remaining_allocation
+        }
+        TakerAccess::Allowlisted { allocation: None } => None,
+        TakerAccess::Allowlisted { allocation: Some(cap) } | TakerAccess::Ticketed { max_amount: cap } => {
+            let taker_fill = ctx.accounts.core.taker_fill.as_ref().ok_or(SwapError::TakerFillRequired)?;
+            Some(cap.checked_sub(taker_fill.filled_amount).ok_or(SwapError::AllocationExceeded)?)
+        }
+    };
+    // Escrowed fills remember what they used up so a rejection can give it back
+    let consumed_allocation = matches!(access, TakerAccess::Whitelisted) && taker_remaining_allocation.is_some();
+
     // Get the offer and update state before transfers
     let offer = &mut ctx.accounts.core.offer;
     offer.token_amount_remaining = new_remaining;
@@ -373,57 +866,84 @@ fn handle_vault_transfer_and_completion(
     
     msg!("New remaining amount: {}", new_remaining);
 
-    // Prepare PDA signer seeds
-    let maker_ref = offer.maker.as_ref();
-    let offer_id = offer.offer_id.to_le_bytes();
-    let offer_bump = ctx.bumps.core.offer;
+    // Escrowed offers keep the input tokens reserved in the vault until the maker settles
+    let mut pending_fill = None;
+    if let Some(acceptance_window) = offer.acceptance_window {
+        let fill_id = offer.fill_count;
+        let accept_deadline = Clock::get()?.unix_timestamp
+            .checked_add(acceptance_window)
+            .ok_or(SwapError::CalculationError)?;
+        offer.fill_count = fill_id.checked_add(1).ok_or(SwapError::CalculationError)?;
+        offer.pending_fills = offer.pending_fills.checked_add(1).ok_or(SwapError::CalculationError)?;
+
+        let fill = ctx.accounts.core.fill.as_mut().ok_or(SwapError::EscrowAccountRequired)?;
+        fill.offer = offer_key;
+        fill.fill_id = fill_id;
+        fill.taker = taker_key;
+        fill.input_token_amount = input_token_amount;
+        fill.payment_amount = payment_amount;
+        fill.fee_amount = fee_amount;
+        fill.accept_deadline = accept_deadline;
+        fill.disputed = false;
+        fill.consumed_allocation = consumed_allocation;
+        fill.recorded_taker_fill = recorded_taker_fill;
+        pending_fill = Some((fill_id, accept_deadline));
+    } else {
+        // Prepare PDA signer seeds
+        let maker_ref = offer.maker.as_ref();
+        let offer_id = offer.offer_id.to_le_bytes();
+        let offer_bump = ctx.bumps.core.offer;
     
-    let seeds = &[
-        b"offer".as_ref(),
-        maker_ref,
-        offer_id.as_ref(),
-        &[offer_bump],
-    ];
-    let signer_seeds = &[&seeds[..]];
-
-    msg!("Transferring {} tokens from vault to taker", input_token_amount);
-
-    // Perform the vault transfer with transfer_checked
-    token_interface::transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token.token_program.to_account_info(),
-            token_interface::TransferChecked {
-                from: ctx.accounts.token.vault_token_account.to_account_info(),
-                mint: ctx.accounts.token.input_token_mint.to_account_info(),
-                to: ctx.accounts.token.taker_receive_token_account.to_account_info(),
-                authority: offer.to_account_info(),
-            },
-            signer_seeds
-        ),
-        input_token_amount,
-        input_decimals,
-    )?;
-
-    msg!("Transfer completed successfully");
-
-    // Handle offer completion if this was a full take
-    if new_remaining == 0 {
-        msg!("Full take detected, closing vault");
-        
-        token_interface::close_account(
+        let seeds = &[
+            b"offer".as_ref(),
+            maker_ref,
+            offer_id.as_ref(),
+            &[offer_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        msg!("Transferring {} tokens from vault to taker", input_token_amount);
+
+        // Perform the vault transfer with transfer_checked
+        token_interface::transfer_checked(
             CpiContext::new_with_signer(
                 ctx.accounts.token.token_program.to_account_info(),
-                token_interface::CloseAccount {
-                    account: ctx.accounts.token.vault_token_account.to_account_info(),
-                    destination: ctx.accounts.core.maker.to_account_info(),
+                token_interface::TransferChecked {
+                    from: ctx.accounts.token.vault_token_account.to_account_info(),
+                    mint: ctx.accounts.token.input_token_mint.to_account_info(),
+                    to: ctx.accounts.token.taker_receive_token_account.to_account_info(),
                     authority: offer.to_account_info(),
                 },
                 signer_seeds
             ),
+            input_token_amount,
+            input_decimals,
         )?;
 
-        offer.status = OfferStatus::Completed;
-        msg!("Offer marked as completed");
+        msg!("Transfer completed successfully");
+
+        // Handle offer completion if this was a full take
+        if new_remaining == 0 {
+            // Delegated offers have no vault, the maker's account stays open
+            if offer.custody == OfferCustody::Vault {
+                msg!("Full take detected, closing vault");
+
+                token_interface::close_account(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token.token_program.to_account_info(),
+                        token_interface::CloseAccount {
+                            account: ctx.accounts.token.vault_token_account.to_account_info(),
+                            destination: ctx.accounts.core.maker.to_account_info(),
+                            authority: offer.to_account_info(),
+                        },
+                        signer_seeds
+                    ),
+                )?;
+            }
+
+            offer.status = OfferStatus::Completed;
+            msg!("Offer marked as completed");
+        }
     }
 
     emit!(OfferTaken {
@@ -436,8 +956,30 @@ fn handle_vault_transfer_and_completion(
         remaining_amount: new_remaining,
         input_token_mint: ctx.accounts.token.input_token_mint.key(),
         output_token_mint: ctx.accounts.token.output_token_mint.key(),
+        taker_remaining_allocation,
     });
 
+    if let Some((fill_id, accept_deadline)) = pending_fill {
+        emit!(FillPending {
+            offer_id: offer.offer_id,
+            fill_id,
+            maker: offer.maker,
+            taker: taker_key,
+            input_token_amount,
+            payment_amount,
+            fee_amount,
+            accept_deadline,
+        });
+    }
+
+    if offer.status == OfferStatus::Completed && offer.close_on_completion {
+        close_offer_accounts(
+            offer,
+            ctx.accounts.core.whitelist.to_account_info(),
+            ctx.accounts.core.maker.to_account_info(),
+        )?;
+    }
+
     msg!("Take offer process completed successfully");
     Ok(())
 }
\ No newline at end of file
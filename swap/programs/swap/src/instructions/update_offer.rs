@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::*;
+
+#[event]
+pub struct OfferUpdated {
+    pub offer_id: u64,
+    pub maker: Pubkey,
+    pub old_expected_amount: u64,
+    pub new_expected_amount: u64,
+    pub old_deadline: i64,
+    pub new_deadline: i64,
+    pub remaining_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TakerMerkleRootUpdated {
+    pub offer_id: u64,
+    pub maker: Pubkey,
+    pub old_root: Option<[u8; 32]>,
+    pub new_root: Option<[u8; 32]>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OfferApproverUpdated {
+    pub offer_id: u64,
+    pub maker: Pubkey,
+    pub old_approver: Option<Pubkey>,
+    pub new_approver: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Account validation structure for the update offer instruction
+/// Allows the maker to reprice and reschedule a live offer
+/// without cancelling it and losing its whitelist
+#[derive(Accounts)]
+pub struct UpdateOffer<'info> {
+    /// Original offer maker, must sign offer updates
+    pub maker: Signer<'info>,
+
+    /// The offer being updated
+    ///
+    /// Constraints:
+    /// - Must be signed by original maker
+    /// - Must be in Ongoing status
+    ///
+    /// Seeds: ["offer", maker_pubkey, offer_id]
+    #[account(
+        mut,
+        constraint = offer.maker == maker.key() @ SwapError::UnauthorizedMaker,
+        constraint = offer.status == OfferStatus::Ongoing @ SwapError::InvalidOfferStatus,
+        seeds = [b"offer", maker.key().as_ref(), &offer.offer_id.to_le_bytes()],
+        bump,
+    )]
+    pub offer: Account<'info, Offer>,
+}
+
+/// Updates the price and/or deadline of a live offer
+///
+/// The price is expressed like at creation, as the amount of output tokens
+/// expected for the full `token_amount`. Fills that already happened keep
+/// their payments recorded in `expected_fulfilled_amount`, only the
+/// remaining tokens are sold at the new price.
+///
+/// # Arguments
+/// * `ctx` - UpdateOffer context
+/// * `new_expected_amount` - New output amount expected for the full token amount, if changed
+/// * `new_deadline` - New Unix timestamp when the offer expires, if changed
+///
+/// # Errors
+/// * `SwapError::UnauthorizedMaker` - If caller isn't offer maker
+/// * `SwapError::InvalidOfferStatus` - If offer is not in Ongoing status
+/// * `SwapError::InvalidAmount` - If the new expected amount is zero
+/// * `SwapError::InvalidDeadline` - If the new deadline is not in the future
+/// * `SwapError::InvalidStartTime` - If the new deadline is not after the offer start time
+pub fn update_offer_terms(
+    ctx: Context<UpdateOffer>,
+    new_expected_amount: Option<u64>,
+    new_deadline: Option<i64>,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let offer = &mut ctx.accounts.offer;
+
+    let old_expected_amount = offer.expected_total_amount;
+    let old_deadline = offer.deadline;
+
+    if let Some(expected_amount) = new_expected_amount {
+        require!(expected_amount > 0, SwapError::InvalidAmount);
+        offer.expected_total_amount = expected_amount;
+    }
+
+    if let Some(deadline) = new_deadline {
+        require!(deadline > current_time, SwapError::InvalidDeadline);
+        require!(deadline > offer.start_time, SwapError::InvalidStartTime);
+        offer.deadline = deadline;
+    }
+
+    emit!(OfferUpdated {
+        offer_id: offer.offer_id,
+        maker: offer.maker,
+        old_expected_amount,
+        new_expected_amount: offer.expected_total_amount,
+        old_deadline,
+        new_deadline: offer.deadline,
+        remaining_amount: offer.token_amount_remaining,
+        timestamp: current_time,
+    });
+
+    Ok(())
+}
+
+/// Sets or clears the Merkle root of an offer's off-chain taker allowlist
+///
+/// # Arguments
+/// * `ctx` - UpdateOffer context
+/// * `root` - New Merkle root, None to disable the allowlist
+///
+/// # Errors
+/// * `SwapError::UnauthorizedMaker` - If caller isn't offer maker
+/// * `SwapError::InvalidOfferStatus` - If offer is not in Ongoing status
+pub fn update_taker_merkle_root(
+    ctx: Context<UpdateOffer>,
+    root: Option<[u8; 32]>,
+) -> Result<()> {
+    let offer = &mut ctx.accounts.offer;
+    let old_root = offer.taker_merkle_root;
+    offer.taker_merkle_root = root;
+
+    emit!(TakerMerkleRootUpdated {
+        offer_id: offer.offer_id,
+        maker: offer.maker,
+        old_root,
+        new_root: root,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Sets or clears the key signing off-chain allow tickets for an offer
+///
+/// Changing the approver invalidates tickets signed by the previous one.
+///
+/// # Arguments
+/// * `ctx` - UpdateOffer context
+/// * `approver` - New approver, None to let the maker sign tickets
+///
+/// # Errors
+/// * `SwapError::UnauthorizedMaker` - If caller isn't offer maker
+/// * `SwapError::InvalidOfferStatus` - If offer is not in Ongoing status
+pub fn update_offer_approver(
+    ctx: Context<UpdateOffer>,
+    approver: Option<Pubkey>,
+) -> Result<()> {
+    let offer = &mut ctx.accounts.offer;
+    let old_approver = offer.approver;
+    offer.approver = approver;
+
+    emit!(OfferApproverUpdated {
+        offer_id: offer.offer_id,
+        maker: offer.maker,
+        old_approver,
+        new_approver: approver,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,11 +1,18 @@
 use anchor_lang::prelude::*;
+pub mod constants;
+pub mod ed25519;
 pub mod instructions;
 pub mod error;
+pub mod merkle;
 pub mod state;
+pub mod ticket;
 
+pub use constants::*;
 pub use instructions::*;
 pub use error::*;
+pub use merkle::MerkleProof;
 pub use state::*;
+pub use ticket::AllowTicket;
 
 declare_id!("BDurA1PZPYYD3SnhRDxnd592fCUDYWFHGGakLVbixp5S");
 
@@ -53,40 +60,266 @@ pub mod swap {
         instructions::admin::fee_address_update(ctx, new_address)
     }
 
+    pub fn update_expiry_bounty(
+        ctx: Context<UpdateExpiryBounty>,
+        new_bounty: u64
+    ) -> Result<()> {
+        instructions::admin::expiry_bounty_update(ctx, new_bounty)
+    }
+
     pub fn toggle_require_whitelist(
         ctx: Context<ToggleRequireWhitelist>
     ) -> Result<()> {
         instructions::admin::update_toggle_whitelist(ctx)
     }
 
+    pub fn update_max_whitelist_takers(
+        ctx: Context<UpdateMaxWhitelistTakers>,
+        max_takers: u32
+    ) -> Result<()> {
+        instructions::admin::max_whitelist_takers_update(ctx, max_takers)
+    }
+
+    pub fn propose_admin(
+        ctx: Context<ProposeAdmin>,
+        new_admin: Pubkey
+    ) -> Result<()> {
+        instructions::admin::admin_propose(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::admin::admin_accept(ctx)
+    }
+
+    pub fn migrate_admin_config(ctx: Context<MigrateAdminConfig>) -> Result<()> {
+        instructions::admin::admin_config_migrate(ctx)
+    }
+
+    pub fn grant_role(
+        ctx: Context<ManageRoles>,
+        role: AdminRole,
+        holder: Pubkey
+    ) -> Result<()> {
+        instructions::admin::role_grant(ctx, role, holder)
+    }
+
+    pub fn revoke_role(ctx: Context<ManageRoles>, role: AdminRole) -> Result<()> {
+        instructions::admin::role_revoke(ctx, role)
+    }
+
+    pub fn pause(ctx: Context<SetPause>, create: bool, take: bool) -> Result<()> {
+        instructions::admin::protocol_pause(ctx, create, take)
+    }
+
+    pub fn unpause(ctx: Context<SetPause>, create: bool, take: bool) -> Result<()> {
+        instructions::admin::protocol_unpause(ctx, create, take)
+    }
+
+    pub fn set_mint_halted(ctx: Context<SetMintConfig>, halted: bool) -> Result<()> {
+        instructions::admin::mint_halt_update(ctx, halted)
+    }
+
+    pub fn set_mint_denied(ctx: Context<SetMintConfig>, denied: bool) -> Result<()> {
+        instructions::admin::mint_deny_update(ctx, denied)
+    }
+
+    pub fn list_mint(
+        ctx: Context<ListMint>,
+        min_trade_amount: u64,
+        max_trade_amount: u64
+    ) -> Result<()> {
+        instructions::admin::mint_list(ctx, min_trade_amount, max_trade_amount)
+    }
+
+    pub fn delist_mint(ctx: Context<DelistMint>) -> Result<()> {
+        instructions::admin::mint_delist(ctx)
+    }
+
+    // Admin multisig functions
+    pub fn enable_admin_multisig(
+        ctx: Context<EnableAdminMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8
+    ) -> Result<()> {
+        instructions::admin_multisig::multisig_enable(ctx, signers, threshold)
+    }
+
+    pub fn create_admin_proposal(
+        ctx: Context<CreateAdminProposal>,
+        action: AdminAction
+    ) -> Result<()> {
+        instructions::admin_multisig::proposal_create(ctx, action)
+    }
+
+    pub fn approve_admin_proposal(ctx: Context<ApproveAdminProposal>) -> Result<()> {
+        instructions::admin_multisig::proposal_approve(ctx)
+    }
+
+    pub fn execute_admin_proposal(ctx: Context<ExecuteAdminProposal>) -> Result<()> {
+        instructions::admin_multisig::proposal_execute(ctx)
+    }
+
     // Maker Functions
+    #[allow(clippy::too_many_arguments)]
     pub fn create_offer_and_send_tokens_to_vault(
         ctx: Context<CreateOffer>,
         offer_id:u64,
         token_amount: u64,
         expected_total_amount: u64,
         deadline: i64,
+        visibility: OfferVisibility,
+        close_on_completion: bool,
+        start_time: i64,
+        min_fill_amount: u64,
+        all_or_none: bool,
+        custody: OfferCustody,
+        acceptance_window: Option<i64>,
+        arbitrator: Option<Pubkey>,
     ) -> Result<()> {
-        instructions::create_offer::initialize_offer(ctx, offer_id, token_amount, expected_total_amount, deadline)
+        instructions::create_offer::initialize_offer(
+            ctx,
+            offer_id,
+            token_amount,
+            expected_total_amount,
+            deadline,
+            visibility,
+            close_on_completion,
+            start_time,
+            min_fill_amount,
+            all_or_none,
+            custody,
+            acceptance_window,
+            arbitrator,
+        )
     }
 
     pub fn manage_whitelist(
         ctx: Context<ManageWhitelist>,
-        takers: Vec<Pubkey>,
+        takers: Vec<WhitelistEntry>,
     ) -> Result<()> {
         instructions::create_offer::manage_takers(ctx, takers)
     }
 
+    pub fn update_offer(
+        ctx: Context<UpdateOffer>,
+        new_expected_total_amount: Option<u64>,
+        new_deadline: Option<i64>,
+    ) -> Result<()> {
+        instructions::update_offer::update_offer_terms(ctx, new_expected_total_amount, new_deadline)
+    }
+
+    pub fn set_taker_merkle_root(
+        ctx: Context<UpdateOffer>,
+        root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::update_offer::update_taker_merkle_root(ctx, root)
+    }
+
+    pub fn set_offer_approver(
+        ctx: Context<UpdateOffer>,
+        approver: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::update_offer::update_offer_approver(ctx, approver)
+    }
+
+    pub fn increase_offer(
+        ctx: Context<AdjustOffer>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::adjust_offer::increase_offer_amount(ctx, amount)
+    }
+
+    pub fn decrease_offer(
+        ctx: Context<AdjustOffer>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::adjust_offer::decrease_offer_amount(ctx, amount)
+    }
+
+    pub fn add_takers(
+        ctx: Context<ManageWhitelist>,
+        takers: Vec<WhitelistEntry>,
+    ) -> Result<()> {
+        instructions::create_offer::add_whitelist_takers(ctx, takers)
+    }
+
+    pub fn remove_takers(
+        ctx: Context<RemoveTakers>,
+        takers: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::create_offer::remove_whitelist_takers(ctx, takers)
+    }
+
     ///if incase, it's completed shouldn't be able to call this
-    pub fn cancel_offer(ctx: Context<CancelOffer>) -> Result<()> {
-        instructions::cancel_offer::update_cancel_offer(ctx)
+    pub fn cancel_offer(ctx: Context<CancelOffer>, close_accounts: bool) -> Result<()> {
+        instructions::cancel_offer::update_cancel_offer(ctx, close_accounts)
+    }
+
+    pub fn settle_fill(ctx: Context<ResolveFill>) -> Result<()> {
+        instructions::escrow_fill::settle(ctx)
+    }
+
+    pub fn reject_fill(ctx: Context<RejectFill>) -> Result<()> {
+        instructions::escrow_fill::reject(ctx)
+    }
+
+    pub fn raise_dispute(ctx: Context<RaiseDispute>) -> Result<()> {
+        instructions::escrow_fill::dispute(ctx)
+    }
+
+    pub fn resolve_dispute(
+        ctx: Context<ResolveFill>,
+        input_to_taker: u64,
+        payment_to_maker: u64,
+    ) -> Result<()> {
+        instructions::escrow_fill::resolve(ctx, input_to_taker, payment_to_maker)
+    }
+
+    pub fn close_offer(ctx: Context<CloseOffer>) -> Result<()> {
+        instructions::close_offer::close_finished_offer(ctx)
+    }
+
+    // Crank Function
+    pub fn expire_offer(ctx: Context<ExpireOffer>) -> Result<()> {
+        instructions::expire_offer::expire(ctx)
     }
 
     // Taker Function
     pub fn take_offer(
         ctx: Context<TakeOffer>, 
-        input_token_amount: u64 //token_a
+        input_token_amount: u64, //token_a
+        max_payment_amount: u64, //token_b
+        max_fee_amount: u64, //token_b
+        merkle_proof: Option<MerkleProof>,
+        allow_ticket: Option<AllowTicket>,
+    ) -> Result<()> {
+        instructions::taker_offer::process(ctx, input_token_amount, max_payment_amount, max_fee_amount, merkle_proof, allow_ticket)
+    }
+
+    pub fn take_offer_exact_output(
+        ctx: Context<TakeOffer>,
+        payment_amount: u64, //token_b
+        min_input_token_amount: u64, //token_a
+        max_fee_amount: u64, //token_b
+        merkle_proof: Option<MerkleProof>,
+        allow_ticket: Option<AllowTicket>,
+    ) -> Result<()> {
+        instructions::taker_offer::process_exact_output(ctx, payment_amount, min_input_token_amount, max_fee_amount, merkle_proof, allow_ticket)
+    }
+
+    // Signed order functions
+    pub fn fill_signed_order(
+        ctx: Context<FillSignedOrder>,
+        order: SignedOrder,
+        max_fee_amount: u64, //token_b
+    ) -> Result<()> {
+        instructions::signed_order::fill_order(ctx, order, max_fee_amount)
+    }
+
+    pub fn cancel_signed_order(
+        ctx: Context<CancelSignedOrder>,
+        nonce: u64,
     ) -> Result<()> {
-        instructions::taker_offer::process(ctx, input_token_amount)
+        instructions::signed_order::cancel_order(ctx, nonce)
     }
 }
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Prefix of leaf hashes, keeps leaves and inner nodes from colliding
+const LEAF_PREFIX: &[u8] = &[0];
+
+/// Prefix of inner node hashes
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Proof that a taker is part of an offer's Merkle allowlist
+/// Passed by the taker when the offer stores a taker Merkle root
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct MerkleProof {
+    /// Input token amount the taker is allowed to take in total
+    /// Must match the leaf, None for uncapped leaves
+    pub allocation: Option<u64>,
+
+    /// Sibling hashes from the leaf up to the root
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Hashes an allowlist leaf for a taker and its optional allocation
+pub fn leaf_hash(taker: &Pubkey, allocation: Option<u64>) -> [u8; 32] {
+    match allocation {
+        Some(allocation) => hashv(&[LEAF_PREFIX, taker.as_ref(), &allocation.to_le_bytes()]),
+        None => hashv(&[LEAF_PREFIX, taker.as_ref()]),
+    }
+    .to_bytes()
+}
+
+/// Hashes two nodes in sorted order so proofs don't need leaf positions
+fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Verifies that `leaf` is included in the tree with the given `root`
+pub fn verify_proof(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| node_hash(&node, sibling));
+    computed == *root
+}
+
+/// Off-chain helper building allowlist trees and proofs that match the on-chain hashing
+///
+/// Leaves are built with `leaf_hash`, an odd node at the end of a level
+/// is carried up unchanged. Not built for the on-chain program.
+#[cfg(not(target_os = "solana"))]
+pub struct MerkleTree {
+    /// Tree levels from the leaves up to the root
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+#[cfg(not(target_os = "solana"))]
+impl MerkleTree {
+    /// Builds a tree from taker entries, each with an optional allocation
+    pub fn new(entries: &[(Pubkey, Option<u64>)]) -> Self {
+        let leaves = entries
+            .iter()
+            .map(|(taker, allocation)| leaf_hash(taker, *allocation))
+            .collect();
+        Self::from_leaves(leaves)
+    }
+
+    /// Builds a tree from already hashed leaves
+    pub fn from_leaves(leaves: Vec<[u8; 32]>) -> Self {
+        let mut levels = vec![leaves];
+        while levels.last().is_some_and(|level| level.len() > 1) {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Self { levels }
+    }
+
+    /// Root to store on the offer, None for an empty tree
+    pub fn root(&self) -> Option<[u8; 32]> {
+        self.levels.last().and_then(|level| level.first().copied())
+    }
+
+    /// Proof for the leaf at `index`, None if out of range
+    pub fn proof(&self, mut index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.levels[0].len() {
+            return None;
+        }
+
+        let mut proof = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling = index ^ 1;
+            if sibling < level.len() {
+                proof.push(level[sibling]);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_proof_verifies_against_the_root() {
+        let entries: Vec<(Pubkey, Option<u64>)> = (0..5u64)
+            .map(|i| (Pubkey::new_unique(), if i % 2 == 0 { Some(100 * (i + 1)) } else { None }))
+            .collect();
+        let tree = MerkleTree::new(&entries);
+        let root = tree.root().unwrap();
+
+        for (index, (taker, allocation)) in entries.iter().enumerate() {
+            let proof = tree.proof(index).unwrap();
+            assert!(verify_proof(&root, leaf_hash(taker, *allocation), &proof));
+            // A leaf with a different allocation doesn't match the same proof
+            assert!(!verify_proof(&root, leaf_hash(taker, allocation.map_or(Some(1), |_| None)), &proof));
+        }
+        assert!(tree.proof(entries.len()).is_none());
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::constants::MAX_ADMIN_SIGNERS;
+use crate::state::AdminRole;
+
+/// Account structure recording an admin action awaiting multisig approval
+#[account]
+pub struct AdminProposal {
+    /// Sequential id of the proposal
+    pub proposal_id: u64,
+
+    /// Multisig member who created the proposal
+    pub proposer: Pubkey,
+
+    /// Admin action applied once the proposal is executed
+    pub action: AdminAction,
+
+    /// Multisig members who approved the proposal
+    /// Only approvals from current members count towards the threshold
+    pub approvals: Vec<Pubkey>,
+
+    /// Whether the action has been applied
+    pub executed: bool,
+
+    /// Unix timestamp when the proposal was created
+    pub created_at: i64,
+}
+
+impl AdminProposal {
+    /// Account size for a proposal carrying `action`
+    /// Space breakdown:
+    /// - 8 bytes discriminator
+    /// - 8 bytes proposal id
+    /// - 32 bytes proposer pubkey
+    /// - serialized action length
+    /// - 4 bytes vector length
+    /// - 32 * MAX_ADMIN_SIGNERS bytes for approvals
+    /// - 1 byte executed flag
+    /// - 8 bytes creation time
+    pub fn space(action: &AdminAction) -> usize {
+        let action_len = action.try_to_vec().map(|bytes| bytes.len()).unwrap_or_default();
+        8 + 8 + 32 + action_len + 4 + (32 * MAX_ADMIN_SIGNERS) + 1 + 8
+    }
+
+    /// Number of approvals from members of `signers`
+    pub fn approval_count(&self, signers: &[Pubkey]) -> usize {
+        self.approvals.iter().filter(|key| signers.contains(key)).count()
+    }
+}
+
+/// Admin actions that can be proposed to the multisig
+/// Each mirrors the single admin instruction of the same name
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub enum AdminAction {
+    UpdateFee { new_fee: u64 },
+    UpdateFeeAddress { new_address: Pubkey },
+    UpdateExpiryBounty { new_bounty: u64 },
+    ToggleRequireWhitelist,
+    UpdateMaxWhitelistTakers { max_takers: u32 },
+    AddMints { new_mints: Vec<Pubkey> },
+    RemoveMints { remove_mints: Vec<Pubkey> },
+    /// Replaces the multisig signers and threshold
+    SetMultisig { signers: Vec<Pubkey>, threshold: u8 },
+    GrantRole { role: AdminRole, holder: Pubkey },
+    RevokeRole { role: AdminRole },
+}
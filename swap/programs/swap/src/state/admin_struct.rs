@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
+use crate::constants::{MAX_ADMIN_SIGNERS, MAX_WHITELIST_TAKERS};
+use crate::error::*;
+use crate::state::ListedMint;
 
 /// Account structure storing the whitelist of permitted token mints
 /// This controls which tokens can be used in the protocol
+/// Capped at 50 mints, further mints are listed through `ListedMint` PDAs
 #[account]
 pub struct MintWhitelist {
     /// Vector of public keys representing allowed token mints
@@ -9,6 +13,32 @@ pub struct MintWhitelist {
     pub mints: Vec<Pubkey>,
 }
 
+impl MintWhitelist {
+    /// Ensures every mint is whitelisted when whitelist checking is required
+    /// A mint is whitelisted if it's in the vector or its ["listed_mint", mint] PDA,
+    /// passed at the same index in `listed_mints`, exists
+    ///
+    /// # Errors
+    /// * `SwapError::MintNotWhitelisted` - If any mint is missing from the whitelist
+    pub fn validate_mints(
+        &self,
+        whitelist_config: &WhitelistConfig,
+        mints: &[Pubkey],
+        listed_mints: &[AccountInfo],
+    ) -> Result<()> {
+        if !whitelist_config.require_whitelist {
+            return Ok(());
+        }
+        for (mint, listed_mint) in mints.iter().zip(listed_mints) {
+            require!(
+                self.mints.contains(mint) || ListedMint::load(listed_mint)?.is_some(),
+                SwapError::MintNotWhitelisted
+            );
+        }
+        Ok(())
+    }
+}
+
 /// Account structure storing fee configuration for the protocol
 /// Controls both the fee amount and where fees are sent
 #[account]
@@ -22,6 +52,26 @@ pub struct FeeConfig {
     pub fee_address: Pubkey,
 }
 
+/// Account structure storing the bounty paid for expiring offers
+/// Kept apart from `FeeConfig` so the fee PDA layout is unchanged
+#[account]
+pub struct ExpiryConfig {
+    /// Bounty paid to whoever expires an offer, in basis points of the returned tokens
+    /// Taken from the maker's remaining tokens, 0 disables the bounty
+    pub expiry_bounty: u64,
+}
+
+impl ExpiryConfig {
+    /// Reads the bounty behind the ["expiry_config"] PDA
+    /// Returns 0 until the fee manager configures one
+    pub fn load_bounty(info: &AccountInfo) -> Result<u64> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(0);
+        }
+        Ok(ExpiryConfig::try_deserialize(&mut &info.try_borrow_data()?[..])?.expiry_bounty)
+    }
+}
+
 /// Account structure controlling whether token mint whitelist is enforced
 /// Provides global toggle for whitelist functionality
 #[account]
@@ -32,10 +82,132 @@ pub struct WhitelistConfig {
     pub require_whitelist: bool,
 }
 
+/// Account structure storing protocol-wide limits for offer taker whitelists
+#[account]
+pub struct TakerWhitelistConfig {
+    /// Maximum number of takers a single offer whitelist can hold
+    pub max_takers: u32,
+}
+
+impl TakerWhitelistConfig {
+    /// Reads the maximum behind the ["taker_whitelist_config"] PDA
+    /// Returns MAX_WHITELIST_TAKERS until the admin configures one
+    pub fn load_max_takers(info: &AccountInfo) -> Result<usize> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(MAX_WHITELIST_TAKERS);
+        }
+        Ok(TakerWhitelistConfig::try_deserialize(&mut &info.try_borrow_data()?[..])?.max_takers as usize)
+    }
+}
+
+/// Registry of the accounts holding each delegated admin role
+/// Roles are granted and revoked by the super-admin, the `AdminConfig` authority
+#[account]
+pub struct AdminRoles {
+    /// Account allowed to update the fee percentage, fee address and expiry bounty
+    pub fee_manager: Option<Pubkey>,
+
+    /// Account allowed to manage the mint whitelist and its requirement
+    pub listing_manager: Option<Pubkey>,
+
+    /// Account allowed to pause the protocol in an emergency
+    pub pauser: Option<Pubkey>,
+}
+
+impl AdminRoles {
+    /// Account size
+    /// Space breakdown:
+    /// - 8 bytes discriminator
+    /// - 33 bytes optional fee manager
+    /// - 33 bytes optional listing manager
+    /// - 33 bytes optional pauser
+    pub const SPACE: usize = 8 + 33 + 33 + 33;
+
+    /// Account currently holding `role`
+    pub fn holder(&self, role: AdminRole) -> Option<Pubkey> {
+        match role {
+            AdminRole::FeeManager => self.fee_manager,
+            AdminRole::ListingManager => self.listing_manager,
+            AdminRole::Pauser => self.pauser,
+        }
+    }
+
+    /// Whether `key` holds `role`
+    pub fn has_role(&self, role: AdminRole, key: &Pubkey) -> bool {
+        self.holder(role) == Some(*key)
+    }
+
+    /// Assigns `role` to `holder`, None revokes it
+    pub fn set_holder(&mut self, role: AdminRole, holder: Option<Pubkey>) {
+        match role {
+            AdminRole::FeeManager => self.fee_manager = holder,
+            AdminRole::ListingManager => self.listing_manager = holder,
+            AdminRole::Pauser => self.pauser = holder,
+        }
+    }
+}
+
+/// Enum of the admin roles delegated by the super-admin
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum AdminRole {
+    FeeManager,
+    ListingManager,
+    Pauser,
+}
+
 /// Configuration account for admin operations and offer tracking
 #[account]
 pub struct AdminConfig {
     /// Admin's public key for authorization
     pub admin: Pubkey,
+
+    /// Admin proposed by the current admin, becomes admin once it accepts
+    pub pending_admin: Option<Pubkey>,
+
+    /// Members of the admin multisig, empty in single admin mode
+    pub signers: Vec<Pubkey>,
+
+    /// Approvals a proposal needs before it can be executed
+    /// 0 keeps the config in single admin mode
+    pub threshold: u8,
+
+    /// Number of admin proposals created, used as the next proposal id
+    pub proposal_count: u64,
+
+    /// Whether creating new offers is paused
+    pub create_paused: bool,
+
+    /// Whether taking offers and filling signed orders is paused
+    /// Cancelling offers stays available so makers can always withdraw
+    pub take_paused: bool,
+}
+
+impl AdminConfig {
+    /// Account size of the original config holding only the admin
+    pub const LEGACY_SPACE: usize = 8 + 32;
+
+    /// Account size, fields added after the admin must decode from zeroed bytes
+    /// so that `migrate_admin_config` can grow legacy accounts
+    /// Space breakdown:
+    /// - 8 bytes discriminator
+    /// - 32 bytes admin pubkey
+    /// - 33 bytes optional pending admin
+    /// - 4 bytes vector length
+    /// - 32 * MAX_ADMIN_SIGNERS bytes for multisig signers
+    /// - 1 byte threshold
+    /// - 8 bytes proposal count
+    /// - 1 byte create paused flag
+    /// - 1 byte take paused flag
+    pub const SPACE: usize = 8 + 32 + 33 + 4 + (32 * MAX_ADMIN_SIGNERS) + 1 + 8 + 1 + 1;
+
+    /// Whether admin actions must go through multisig proposals
+    pub fn is_multisig(&self) -> bool {
+        self.threshold > 0
+    }
+
+    /// Whether `key` is a member of the admin multisig
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
 }
 
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::constants::DISPUTE_RESOLUTION_PERIOD;
+use crate::error::*;
+
+/// Account structure recording a fill escrowed until the maker accepts it
+/// The input tokens stay reserved in the offer vault and the taker's
+/// payment and fee are held in the offer's payment escrow account
+#[account]
+pub struct Fill {
+    /// The offer the fill was made on
+    pub offer: Pubkey,
+
+    /// Sequential id of the fill on the offer
+    pub fill_id: u64,
+
+    /// The taker who made the fill, receives the rent back
+    pub taker: Pubkey,
+
+    /// Input tokens reserved for the taker
+    pub input_token_amount: u64,
+
+    /// Output tokens owed to the maker on settlement
+    pub payment_amount: u64,
+
+    /// Protocol fee paid to the fee wallet on settlement
+    pub fee_amount: u64,
+
+    /// Unix timestamp until which the maker can settle the fill
+    /// Anyone can reject it after this time
+    pub accept_deadline: i64,
+
+    /// Whether a dispute froze the fill until the arbitrator resolves it
+    pub disputed: bool,
+
+    /// Whether the fill was deducted from the taker's capped whitelist allocation
+    pub consumed_allocation: bool,
+
+    /// Whether the fill was added to the taker's recorded fills
+    pub recorded_taker_fill: bool,
+}
+
+impl Fill {
+    /// Unix timestamp until which only the arbitrator can resolve a dispute on the fill
+    /// Disputes are raised before the acceptance deadline, so every dispute gets the full period
+    pub fn dispute_deadline(&self) -> Result<i64> {
+        self.accept_deadline
+            .checked_add(DISPUTE_RESOLUTION_PERIOD)
+            .ok_or(SwapError::CalculationError.into())
+    }
+}
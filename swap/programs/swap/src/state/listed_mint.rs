@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::error::*;
+
+/// Account structure listing a single mint for trading
+/// One ["listed_mint", mint] PDA exists per listed mint, so listings are
+/// not limited by the size of the `MintWhitelist` vector
+#[account]
+pub struct ListedMint {
+    /// The listed mint
+    pub mint: Pubkey,
+
+    /// Unix timestamp when the mint was first listed
+    pub listed_at: i64,
+
+    /// Listing manager who listed the mint
+    pub listed_by: Pubkey,
+
+    /// Smallest amount of the mint a single trade can move
+    pub min_trade_amount: u64,
+
+    /// Largest amount of the mint a single trade can move, 0 disables the limit
+    pub max_trade_amount: u64,
+}
+
+impl ListedMint {
+    /// Loads the listing behind a ["listed_mint", mint] PDA
+    /// Returns None if the mint isn't listed
+    pub fn load(info: &AccountInfo) -> Result<Option<ListedMint>> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(ListedMint::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+
+    /// Ensures `amount` is within the listing's trade size limits
+    /// The final fill of an offer takes whatever remains, so only the maximum applies to it
+    ///
+    /// # Errors
+    /// * `SwapError::TradeBelowMinimum` - If amount is below the minimum trade size
+    /// * `SwapError::TradeAboveMaximum` - If amount is above the maximum trade size
+    pub fn validate_trade_amount(&self, amount: u64, final_fill: bool) -> Result<()> {
+        require!(final_fill || amount >= self.min_trade_amount, SwapError::TradeBelowMinimum);
+        require!(
+            self.max_trade_amount == 0 || amount <= self.max_trade_amount,
+            SwapError::TradeAboveMaximum
+        );
+        Ok(())
+    }
+
+    /// Checks `amount` against the listing behind a ["listed_mint", mint] PDA, if any
+    ///
+    /// # Errors
+    /// * `SwapError::TradeBelowMinimum` - If amount is below the minimum trade size
+    /// * `SwapError::TradeAboveMaximum` - If amount is above the maximum trade size
+    pub fn validate_trade(info: &AccountInfo, amount: u64, final_fill: bool) -> Result<()> {
+        match ListedMint::load(info)? {
+            Some(listing) => listing.validate_trade_amount(amount, final_fill),
+            None => Ok(()),
+        }
+    }
+}
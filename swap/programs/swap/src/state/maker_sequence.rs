@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use crate::error::*;
+
+/// Account structure tracking the offer ids a maker has used
+/// Offer ids must increase, so a closed offer's PDA can't be recreated and
+/// inherit the tickets, taker fills and fills bound to its address
+#[account]
+pub struct MakerSequence {
+    /// The maker whose offer ids are tracked
+    pub maker: Pubkey,
+
+    /// Smallest offer id the maker can create next
+    pub next_offer_id: u64,
+}
+
+impl MakerSequence {
+    /// Reserves `offer_id` for a new offer
+    ///
+    /// # Errors
+    /// * `SwapError::OfferIdAlreadyUsed` - If the id isn't above every id the maker used before
+    /// * `SwapError::SequenceOverflow` - If the id is u64::MAX
+    pub fn consume(&mut self, maker: Pubkey, offer_id: u64) -> Result<()> {
+        require!(offer_id >= self.next_offer_id, SwapError::OfferIdAlreadyUsed);
+        self.maker = maker;
+        self.next_offer_id = offer_id.checked_add(1).ok_or(SwapError::SequenceOverflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::error::*;
+
+/// Account structure storing per-mint trading restrictions
+/// Mints without a config PDA are unrestricted
+#[account]
+pub struct MintConfig {
+    /// The mint the config applies to
+    pub mint: Pubkey,
+
+    /// Whether trading the mint is temporarily halted
+    pub halted: bool,
+
+    /// Whether the mint is on the compliance denylist
+    pub denied: bool,
+}
+
+impl MintConfig {
+    /// Ensures none of the mints behind the given ["mint_config", mint] PDAs
+    /// is halted or denied
+    /// PDAs that were never created are skipped
+    ///
+    /// # Errors
+    /// * `SwapError::MintDenied` - If a mint is denied
+    /// * `SwapError::MintHalted` - If a mint is halted
+    pub fn validate(mint_configs: &[AccountInfo]) -> Result<()> {
+        for info in mint_configs {
+            if info.owner != &crate::ID || info.data_is_empty() {
+                continue;
+            }
+            let config = MintConfig::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+            require!(!config.denied, SwapError::MintDenied);
+            require!(!config.halted, SwapError::MintHalted);
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,21 @@
 pub mod offer;
 pub mod whitelist;
 pub mod admin_struct;
+pub mod taker_fill;
+pub mod order_nonces;
+pub mod fill;
+pub mod admin_proposal;
+pub mod mint_config;
+pub mod listed_mint;
+pub mod maker_sequence;
 
 pub use offer::*;
 pub use whitelist::*;
-pub use admin_struct::*;
\ No newline at end of file
+pub use admin_struct::*;
+pub use taker_fill::*;
+pub use order_nonces::*;
+pub use fill::*;
+pub use admin_proposal::*;
+pub use mint_config::*;
+pub use listed_mint::*;
+pub use maker_sequence::*;
\ No newline at end of file
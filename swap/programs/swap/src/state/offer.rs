@@ -2,6 +2,9 @@ use anchor_lang::prelude::*;
 
 /// Account structure representing a token swap offer
 /// Stores all details about an offer including its current state and parameters
+///
+/// The layout differs from the original program's offers and no migration exists,
+/// upgrading an existing deployment requires its open offers to be cancelled first
 #[account]
 pub struct Offer {
     /// Unique identifier for the offer
@@ -37,12 +40,59 @@ pub struct Offer {
     /// Offer cannot be taken after this time
     pub deadline: i64,
 
+    /// Unix timestamp from when this offer can be taken
+    /// Offer cannot be taken before this time
+    pub start_time: i64,
+
     /// Current status of the offer
     /// Controls what operations are permitted
     pub status: OfferStatus,
 
     pub fee_percentage: u64,        
     pub fee_wallet: Pubkey, 
+
+    /// Who is allowed to take this offer
+    /// Restricted offers require the taker to be in the offer's whitelist PDA
+    pub visibility: OfferVisibility,
+
+    /// Whether the offer and whitelist PDAs are closed to the maker on the final fill
+    pub close_on_completion: bool,
+
+    /// Smallest input token amount a single fill can take
+    /// The final remainder can always be taken even if it is smaller
+    pub min_fill_amount: u64,
+
+    /// Whether the whole remaining amount must be taken in a single fill
+    pub all_or_none: bool,
+
+    /// Merkle root of an off-chain taker allowlist for restricted offers
+    /// Takers proving inclusion can take the offer without being in the whitelist PDA
+    pub taker_merkle_root: Option<[u8; 32]>,
+
+    /// Key signing off-chain allow tickets for restricted offers
+    /// None lets the maker sign the tickets
+    pub approver: Option<Pubkey>,
+
+    /// Where the offered tokens are held until they are taken
+    pub custody: OfferCustody,
+
+    /// Seconds the maker has to settle an escrowed fill
+    /// None pays the maker immediately on take
+    pub acceptance_window: Option<i64>,
+
+    /// Number of escrowed fills made on the offer, used as the next fill id
+    pub fill_count: u64,
+
+    /// Number of escrowed fills waiting to be settled or rejected
+    pub pending_fills: u64,
+
+    /// Key allowed to split the escrow of disputed fills
+    /// None disables disputes on the offer
+    pub arbitrator: Option<Pubkey>,
+
+    /// Expiry bounty copied from the expiry config at creation, in basis points
+    /// Paid to whoever expires the offer
+    pub expiry_bounty: u64,
 }
 
 /// Enum representing the possible states of an offer
@@ -69,4 +119,27 @@ pub enum OfferStatus {
     /// Offer reached its deadline without being fully filled
     /// No further actions can be taken
     Expired,
-}
\ No newline at end of file
+}
+
+/// Enum controlling which takers may fill an offer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum OfferVisibility {
+    /// Any taker can fill the offer, no whitelist PDA is needed
+    Public,
+
+    /// Only takers listed in the ["whitelist", maker, offer_id] PDA can fill the offer
+    Restricted,
+}
+
+/// Enum controlling where the offered tokens are held
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum OfferCustody {
+    /// Tokens are moved into a vault ATA owned by the offer PDA
+    Vault,
+
+    /// Tokens stay in the maker's token account, the offer PDA is
+    /// approved as delegate for the remaining amount
+    /// A token account has a single delegate, so approving another
+    /// delegate on it revokes the offer's delegation
+    Delegate,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::constants::ORDER_NONCES_PER_PAGE;
+use crate::error::*;
+
+/// Account structure tracking the used nonces of a maker's signed orders
+/// Each PDA covers `ORDER_NONCES_PER_PAGE` consecutive nonces, one bit each
+#[account]
+pub struct OrderNonces {
+    /// The maker whose nonces are tracked
+    pub maker: Pubkey,
+
+    /// Page index, covers nonces from `page * ORDER_NONCES_PER_PAGE`
+    pub page: u64,
+
+    /// One bit per nonce of the page, set once the nonce is filled or cancelled
+    pub bitmap: [u8; 128],
+}
+
+impl OrderNonces {
+    /// Page of the bitmap PDA tracking `nonce`
+    pub fn page_of(nonce: u64) -> u64 {
+        nonce / ORDER_NONCES_PER_PAGE
+    }
+
+    /// Marks `nonce` as used
+    ///
+    /// # Errors
+    /// * `SwapError::OrderNonceUsed` - If the nonce was already filled or cancelled
+    pub fn consume(&mut self, nonce: u64) -> Result<()> {
+        let bit = (nonce % ORDER_NONCES_PER_PAGE) as usize;
+        let mask = 1u8 << (bit % 8);
+        require!(self.bitmap[bit / 8] & mask == 0, SwapError::OrderNonceUsed);
+        self.bitmap[bit / 8] |= mask;
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+/// Account structure tracking how much a taker has filled on an offer
+/// Used to enforce allocations that are not stored in the offer whitelist
+#[account]
+pub struct TakerFill {
+    /// The offer the fills were made on
+    pub offer: Pubkey,
+
+    /// The taker who made the fills
+    pub taker: Pubkey,
+
+    /// Total input tokens taken by this taker on the offer
+    pub filled_amount: u64,
+}
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
+use crate::error::*;
 
 /// Account structure representing a whitelist of allowed takers for a specific offer
 /// Controls which addresses can take (accept) a particular offer
+///
+/// Entries now carry allocations, whitelists created by the original program can't be
+/// decoded and their offers must be cancelled before an existing deployment is upgraded
 #[account]
 pub struct Whitelist {
     /// Maker who controls the whitelist
@@ -11,8 +15,118 @@ pub struct Whitelist {
     /// Links the whitelist to its specific offer
     pub offer: Pubkey,
 
-    /// Vector of public keys representing addresses allowed to take the offer
+    /// Vector of takers allowed to take the offer, with their allocations
     /// Only these addresses can execute trades against the offer when whitelist is enabled
     /// Empty vector means no takers are currently whitelisted
-    pub takers: Vec<Pubkey>,
-}
\ No newline at end of file
+    pub takers: Vec<WhitelistEntry>,
+}
+
+/// A single taker allowed to take an offer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct WhitelistEntry {
+    /// Public key of the allowed taker
+    pub taker: Pubkey,
+
+    /// Input token amount this taker can still take
+    /// None means the taker is not capped
+    pub allocation: Option<u64>,
+}
+
+impl Whitelist {
+    /// Loads the whitelist behind a ["whitelist", maker, offer_id] PDA
+    /// Returns None if the offer has no whitelist
+    pub fn load(info: &AccountInfo) -> Result<Option<Whitelist>> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Whitelist::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+
+    /// Writes a loaded whitelist back into its PDA
+    /// Its takers must still fit the account
+    pub fn store(&self, info: &AccountInfo) -> Result<()> {
+        let mut data = info.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut data[..])
+    }
+
+    /// Account size for a whitelist holding `taker_count` takers:
+    /// - 8 bytes discriminator
+    /// - 32 bytes maker pubkey
+    /// - 32 bytes offer pubkey
+    /// - 4 bytes vector length
+    /// - 32 bytes taker pubkey + 9 bytes optional allocation per taker
+    pub fn space(taker_count: usize) -> usize {
+        8 + 32 + 32 + 4 + (32 + 9) * taker_count
+    }
+
+    /// Returns the whitelist entry of a taker, if the taker is whitelisted
+    pub fn entry(&self, taker: &Pubkey) -> Option<&WhitelistEntry> {
+        self.takers.iter().find(|entry| entry.taker == *taker)
+    }
+
+    /// Ensures a taker is whitelisted and allowed to take `amount` input tokens
+    ///
+    /// # Errors
+    /// * `SwapError::TakerNotWhitelisted` - If taker is not in the whitelist
+    /// * `SwapError::AllocationExceeded` - If amount exceeds the taker's remaining allocation
+    pub fn validate_taker(&self, taker: &Pubkey, amount: u64) -> Result<()> {
+        let entry = self.entry(taker).ok_or(SwapError::TakerNotWhitelisted)?;
+        if let Some(allocation) = entry.allocation {
+            require!(amount <= allocation, SwapError::AllocationExceeded);
+        }
+        Ok(())
+    }
+
+    /// Adds a taker to the whitelist
+    ///
+    /// # Errors
+    /// * `SwapError::TakerAlreadyWhitelisted` - If the taker is already whitelisted
+    /// * `SwapError::WhitelistFull` - If the whitelist already holds `max_takers` takers
+    pub fn add_taker(&mut self, entry: WhitelistEntry, max_takers: usize) -> Result<()> {
+        require!(self.entry(&entry.taker).is_none(), SwapError::TakerAlreadyWhitelisted);
+        require!(self.takers.len() < max_takers, SwapError::WhitelistFull);
+        self.takers.push(entry);
+        Ok(())
+    }
+
+    /// Removes a taker from the whitelist
+    ///
+    /// # Errors
+    /// * `SwapError::TakerNotWhitelisted` - If the taker is not whitelisted
+    pub fn remove_taker(&mut self, taker: &Pubkey) -> Result<()> {
+        let index = self.takers
+            .iter()
+            .position(|entry| entry.taker == *taker)
+            .ok_or(SwapError::TakerNotWhitelisted)?;
+        self.takers.remove(index);
+        Ok(())
+    }
+
+    /// Deducts a fill from a taker's allocation
+    /// Returns the taker's remaining allocation, None if uncapped
+    pub fn consume_allocation(&mut self, taker: &Pubkey, amount: u64) -> Result<Option<u64>> {
+        let entry = self.takers
+            .iter_mut()
+            .find(|entry| entry.taker == *taker)
+            .ok_or(SwapError::TakerNotWhitelisted)?;
+
+        if let Some(allocation) = entry.allocation {
+            let remaining = allocation
+                .checked_sub(amount)
+                .ok_or(SwapError::AllocationExceeded)?;
+            entry.allocation = Some(remaining);
+        }
+        Ok(entry.allocation)
+    }
+
+    /// Gives back part of a fill that was deducted from a taker's allocation
+    /// Takers removed since, or no longer capped, are left unchanged
+    pub fn return_allocation(&mut self, taker: &Pubkey, amount: u64) -> Result<()> {
+        if let Some(entry) = self.takers.iter_mut().find(|entry| entry.taker == *taker) {
+            if let Some(allocation) = entry.allocation {
+                entry.allocation = Some(allocation.checked_add(amount).ok_or(SwapError::CalculationError)?);
+            }
+        }
+        Ok(())
+    }
+}
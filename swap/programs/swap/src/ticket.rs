@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::ed25519;
+use crate::error::*;
+
+/// Off-chain allow ticket signed by the offer's approver
+///
+/// The Borsh serialization of the ticket is the message signed with Ed25519.
+/// The taker submits the ticket with `take_offer`, preceded by an Ed25519
+/// program instruction verifying the approver's signature over it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct AllowTicket {
+    /// Offer the ticket is valid for
+    pub offer: Pubkey,
+
+    /// Taker allowed to take the offer
+    pub taker: Pubkey,
+
+    /// Input token amount the taker is allowed to take in total
+    pub max_amount: u64,
+
+    /// Unix timestamp after which the ticket can no longer be used
+    pub expiry: i64,
+}
+
+impl AllowTicket {
+    /// Message the approver signs for this ticket
+    pub fn message(&self) -> Result<Vec<u8>> {
+        self.try_to_vec().map_err(|_| error!(SwapError::InvalidAllowTicket))
+    }
+
+    /// Verifies that the instruction right before the current one is an
+    /// Ed25519 program instruction checking `signer`'s signature over this ticket
+    ///
+    /// # Errors
+    /// * `SwapError::InvalidAllowTicket` - If no matching Ed25519 instruction precedes the take
+    pub fn verify_signature(&self, instructions_sysvar: &AccountInfo, signer: &Pubkey) -> Result<()> {
+        require!(
+            ed25519::has_preceding_signature(instructions_sysvar, signer, &self.message()?)?,
+            SwapError::InvalidAllowTicket
+        );
+        Ok(())
+    }
+}
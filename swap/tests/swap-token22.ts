@@ -829,7 +829,15 @@ describe("swap program - create offer", () => {
           new anchor.BN(offerId), // Offer ID
           amountTokenAForSale, // Amount of Token A for sale
           totalTokenBExpected, // Total amount of Token B expected
-          deadlineUnixTimestamp // Offer deadline
+          deadlineUnixTimestamp, // Offer deadline
+          { restricted: {} }, // Offer visibility
+          false, // Close offer on completion
+          new anchor.BN(0), // Offer start time
+          new anchor.BN(0), // Minimum fill amount
+          false, // All or none
+          { vault: {} }, // Offer custody
+          null, // Acceptance window
+          null // Arbitrator
         )
         .accounts({
           maker: maker2.publicKey, // Offer creator's public key
@@ -951,7 +959,15 @@ describe("swap program - create offer", () => {
           new anchor.BN(offer1Id), // Offer ID
           amountTokenAForSaleOffer1, // Amount of Token A for sale
           totalTokenBExpectedOffer1, // Total amount of Token B expected
-          deadlineUnixTimestampOffer1 // Offer deadline
+          deadlineUnixTimestampOffer1, // Offer deadline
+          { restricted: {} }, // Offer visibility
+          false, // Close offer on completion
+          new anchor.BN(0), // Offer start time
+          new anchor.BN(0), // Minimum fill amount
+          false, // All or none
+          { vault: {} }, // Offer custody
+          null, // Acceptance window
+          null // Arbitrator
         )
         .accounts({
           maker: maker2.publicKey, // Offer creator's public key
@@ -1073,7 +1089,15 @@ describe("swap program - create offer", () => {
           new anchor.BN(offer2Id), // Offer ID
           amountTokenAForSaleOffer2, // Amount of Token A for sale
           totalTokenBExpectedOffer2, // Total amount of Token B expected
-          deadlineUnixTimestampOffer2 // Offer deadline
+          deadlineUnixTimestampOffer2, // Offer deadline
+          { restricted: {} }, // Offer visibility
+          false, // Close offer on completion
+          new anchor.BN(0), // Offer start time
+          new anchor.BN(0), // Minimum fill amount
+          false, // All or none
+          { vault: {} }, // Offer custody
+          null, // Acceptance window
+          null // Arbitrator
         )
         .accounts({
           maker: maker2.publicKey, // Offer creator's public key
@@ -1168,7 +1192,7 @@ describe("swap program - create offer", () => {
         taker.publicKey,
         new PublicKey("4sijvjMmXG8sdzwsLJevQ1dSXpY6a9T7fPRkVLeqr6Wm"),
         new PublicKey("7ZK3Y3izGJDGEd2CAvXpotCZinKuYk71YABoHxBEdo4G"),
-      ])
+      ].map((taker) => ({ taker, allocation: null })))
       .accountsPartial({
         maker: maker2.publicKey,
         whitelist: whitelist,
@@ -1186,7 +1210,7 @@ describe("swap program - create offer", () => {
       new PublicKey("7ZK3Y3izGJDGEd2CAvXpotCZinKuYk71YABoHxBEdo4G"),
     ];
     assert.deepEqual(
-      takerWhitelistFetched.takers.map((m) => m.toBase58()),
+      takerWhitelistFetched.takers.map((m) => m.taker.toBase58()),
       newWhitelist.map((m) => m.toBase58()),
       "Add takerwhitelist whitelist mismatch"
     );
@@ -1209,7 +1233,7 @@ describe("swap program - create offer", () => {
           taker.publicKey,
           new PublicKey("4sijvjMmXG8sdzwsLJevQ1dSXpY6a9T7fPRkVLeqr6Wm"),
           new PublicKey("7ZK3Y3izGJDGEd2CAvXpotCZinKuYk71YABoHxBEdo4G"),
-        ])
+        ].map((taker) => ({ taker, allocation: null })))
         .accountsPartial({
           maker: maker2.publicKey,
           whitelist: whitelist,
@@ -1242,7 +1266,7 @@ describe("swap program - create offer", () => {
       .manageWhitelist([
         taker.publicKey,
         new PublicKey("4sijvjMmXG8sdzwsLJevQ1dSXpY6a9T7fPRkVLeqr6Wm")
-      ])
+      ].map((taker) => ({ taker, allocation: null })))
       .accountsPartial({
         maker: maker2.publicKey,
         offer: offer,
@@ -1259,7 +1283,7 @@ describe("swap program - create offer", () => {
       new PublicKey("4sijvjMmXG8sdzwsLJevQ1dSXpY6a9T7fPRkVLeqr6Wm"),
     ];
     assert.deepEqual(
-      takerWhitelistFetched.takers.map((m) => m.toBase58()),
+      takerWhitelistFetched.takers.map((m) => m.taker.toBase58()),
       newWhitelist.map((m) => m.toBase58()),
       "Add takerwhitelist whitelist mismatch"
     );
@@ -1271,7 +1295,7 @@ describe("swap program - create offer", () => {
       await program.methods
         .manageWhitelist([
           new PublicKey("7ZK3Y3izGJDGEd2CAvXpotCZinKuYk71YABoHxBEdo4G"),
-        ])
+        ].map((taker) => ({ taker, allocation: null })))
         .accountsPartial({
           maker: maker2.publicKey,
           offer: offer,
@@ -1387,7 +1411,7 @@ describe("swap program - create offer", () => {
 
     try {
       const takeOfferTx = await program.methods
-        .takeOffer(new anchor.BN("500"))
+        .takeOffer(new anchor.BN("500"), new anchor.BN("250"), new anchor.BN("10"), null, null)
         .accountsPartial({
           core: {
             taker: taker.publicKey,
@@ -1487,7 +1511,7 @@ describe("swap program - create offer", () => {
       // Execute cancel transaction
       try {
         const cancelTx = await program.methods
-          .cancelOffer()
+          .cancelOffer(false)
           .accountsPartial({
             maker: maker2.publicKey,
             makerTokenAccount: makerTokenAccount,
@@ -1602,7 +1626,15 @@ describe("swap program - create offer", () => {
           new anchor.BN(offerIdPartial),
           amountTokenAForSale,
           totalTokenBExpected,
-          deadlineUnixTimestamp
+          deadlineUnixTimestamp,
+          { restricted: {} }, // Offer visibility
+          false, // Close offer on completion
+          new anchor.BN(0), // Offer start time
+          new anchor.BN(0), // Minimum fill amount
+          false, // All or none
+          { vault: {} }, // Offer custody
+          null, // Acceptance window
+          null // Arbitrator
         )
         .accountsPartial({
           maker: maker2.publicKey,
@@ -1645,7 +1677,7 @@ describe("swap program - create offer", () => {
       )[0];
 
       await program.methods
-        .manageWhitelist([taker.publicKey])
+        .manageWhitelist([taker.publicKey].map((taker) => ({ taker, allocation: null })))
         .accounts({
           maker: maker2.publicKey,
           offer: offer,
@@ -1704,7 +1736,7 @@ describe("swap program - create offer", () => {
 
       // 7. EXECUTE PARTIAL TAKE
       await program.methods
-        .takeOffer(partialAmount)
+        .takeOffer(partialAmount, new anchor.BN("100"), new anchor.BN("5"), null, null)
         .accountsPartial({
           core: {
             taker: taker.publicKey,
@@ -1777,7 +1809,7 @@ describe("swap program - create offer", () => {
 
       try {
         await program.methods
-          .cancelOffer()
+          .cancelOffer(false)
           .accountsPartial({
             maker: maker2.publicKey,
             offer: offer,
@@ -1,15 +1,33 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { Swap } from "../target/types/swap";
-import { Keypair, PublicKey, SystemProgram, AccountInfo, sendAndConfirmTransaction } from "@solana/web3.js";
-import { Account, ASSOCIATED_TOKEN_PROGRAM_ID, createMint, getAccount, getAssociatedTokenAddress, getAssociatedTokenAddressSync, getMint, getOrCreateAssociatedTokenAccount, mintTo, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, PublicKey, SystemProgram, AccountInfo, sendAndConfirmTransaction, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
+import { Account, approve, ASSOCIATED_TOKEN_PROGRAM_ID, createMint, getAccount, getAssociatedTokenAddress, getAssociatedTokenAddressSync, getMint, getOrCreateAssociatedTokenAccount, mintTo, revoke, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { assert, expect } from "chai";
 import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
+import { createHash } from "crypto";
 
 function isAnchorError(error: any): error is { error: any; errorLogs: string[] } {
   return error && typeof error === "object" && "error" in error && "errorLogs" in error;
 }
 
+async function expectAnchorError(promise: Promise<unknown>, code: string) {
+  try {
+    await promise;
+  } catch (err) {
+    if (isAnchorError(err)) {
+      assert.strictEqual(err.error.errorCode.code, code);
+      return;
+    }
+    throw err;
+  }
+  assert.fail(`Expected the transaction to fail with ${code}`);
+}
+
+function sleep(ms: number) {
+  return new Promise(resolve => setTimeout(resolve, ms));
+}
+
 describe("swap program - create offer", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
@@ -39,6 +57,182 @@ describe("swap program - create offer", () => {
   let takerReceiveTokenAccount: PublicKey;
   let feeTokenAccount: PublicKey;
   const offerId = 356756;
+  const feeWallet = new PublicKey('B5WFNofBtPcFUS9oR2oAuxTHsSCUVp3C4VjFtejKEUnv');
+
+  type OfferOptions = {
+    visibility?: any;
+    deadline?: number;
+    closeOnCompletion?: boolean;
+    startTime?: number;
+    minFillAmount?: number;
+    allOrNone?: boolean;
+    custody?: any;
+    acceptanceWindow?: number;
+    arbitrator?: PublicKey;
+    outputMint?: PublicKey;
+  };
+
+  type TakeOptions = {
+    taker?: Keypair;
+    whitelist?: PublicKey;
+    fill?: PublicKey | null;
+    takerFill?: PublicKey;
+    instructionsSysvar?: PublicKey;
+    paymentEscrow?: PublicKey | null;
+    tokenOffer?: PublicKey;
+    tokenSource?: PublicKey;
+    outputMint?: PublicKey;
+  };
+
+  const now = () => Math.floor(Date.now() / 1000);
+
+  const offerAddress = (id: number, offerMaker: PublicKey = maker.publicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('offer'), offerMaker.toBuffer(), Buffer.from(new anchor.BN(id).toArray('le', 8))],
+      program.programId
+    )[0];
+
+  const whitelistAddress = (id: number, offerMaker: PublicKey = maker.publicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('whitelist'), offerMaker.toBuffer(), Buffer.from(new anchor.BN(id).toArray('le', 8))],
+      program.programId
+    )[0];
+
+  const fillAddress = (offerKey: PublicKey, fillId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('fill'), offerKey.toBuffer(), Buffer.from(new anchor.BN(fillId).toArray('le', 8))],
+      program.programId
+    )[0];
+
+  const mintPda = (seed: string, mint: PublicKey) =>
+    PublicKey.findProgramAddressSync([Buffer.from(seed), mint.toBuffer()], program.programId)[0];
+
+  const ata = (mint: PublicKey, owner: PublicKey) =>
+    getAssociatedTokenAddressSync(mint, owner, true, TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID);
+
+  // Events emitted by a confirmed transaction
+  const transactionEvents = async (signature: string) => {
+    const transaction = await connection.getTransaction(signature, { commitment: 'confirmed', maxSupportedTransactionVersion: 0 });
+    const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+    return Array.from(parser.parseLogs(transaction.meta.logMessages));
+  };
+
+  const tokenBalance = async (account: PublicKey) =>
+    Number((await connection.getTokenAccountBalance(account, 'confirmed')).value.amount);
+
+  // Creates an offer of mint_a by `maker`, public and vault held unless told otherwise
+  const createTestOffer = (id: number, tokenAmount: number, expectedAmount: number, options: OfferOptions = {}) =>
+    program.methods
+      .createOfferAndSendTokensToVault(
+        new anchor.BN(id),
+        new anchor.BN(tokenAmount),
+        new anchor.BN(expectedAmount),
+        new anchor.BN(options.deadline ?? now() + 3600),
+        options.visibility ?? { public: {} },
+        options.closeOnCompletion ?? false,
+        new anchor.BN(options.startTime ?? 0),
+        new anchor.BN(options.minFillAmount ?? 0),
+        options.allOrNone ?? false,
+        options.custody ?? { vault: {} },
+        options.acceptanceWindow === undefined ? null : new anchor.BN(options.acceptanceWindow),
+        options.arbitrator ?? null
+      )
+      .accountsPartial({
+        maker: maker.publicKey,
+        offer: offerAddress(id),
+        makerTokenAccount: makerTokenAccount.address,
+        // Delegated offers have no vault
+        vaultTokenAccount: options.custody?.delegate ? null : ata(mint_a.publicKey, offerAddress(id)),
+        inputTokenMint: mint_a.publicKey,
+        outputTokenMint: options.outputMint ?? mint_b.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([maker])
+      .rpc();
+
+  // Accounts taking an offer of `maker` that sells mint_a
+  // The whitelist PDA of the offer is always passed, whether or not it exists
+  const takeAccounts = async (offerKey: PublicKey, options: TakeOptions = {}) => {
+    const offerTaker = options.taker ?? taker;
+    const outputMint = options.outputMint ?? mint_b.publicKey;
+    const tokenOffer = options.tokenOffer ?? offerKey;
+    const offerWhitelist = options.whitelist ?? whitelistAddress((await program.account.offer.fetch(offerKey)).offerId.toNumber());
+    return {
+      core: {
+        taker: offerTaker.publicKey,
+        offer: offerKey,
+        maker: maker.publicKey,
+        inputListedMint: mintPda('listed_mint', mint_a.publicKey),
+        outputListedMint: mintPda('listed_mint', mint_b.publicKey),
+        inputMintConfig: mintPda('mint_config', mint_a.publicKey),
+        outputMintConfig: mintPda('mint_config', outputMint),
+        whitelist: offerWhitelist,
+        takerFill: options.takerFill ?? null,
+        instructionsSysvar: options.instructionsSysvar ?? null,
+        fill: options.fill ?? null,
+      },
+      token: {
+        takerPaymentTokenAccount: ata(outputMint, offerTaker.publicKey),
+        vaultTokenAccount: options.tokenSource ?? ata(mint_a.publicKey, offerKey),
+        paymentEscrowTokenAccount: options.paymentEscrow ?? null,
+        inputTokenMint: mint_a.publicKey,
+        outputTokenMint: outputMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        feeWallet: feeWallet,
+        taker: offerTaker.publicKey,
+        maker: maker.publicKey,
+        offer: tokenOffer,
+      }
+    };
+  };
+
+  // Takes `amount` of mint_a from an offer of `maker`
+  const takeTestOffer = async (offerKey: PublicKey, amount: number, maxPayment: number, maxFee: number, options: TakeOptions = {}) =>
+    program.methods
+      .takeOffer(new anchor.BN(amount), new anchor.BN(maxPayment), new anchor.BN(maxFee), null, null)
+      .accountsPartial(await takeAccounts(offerKey, options))
+      .signers([options.taker ?? taker])
+      .rpc();
+
+  // Settles or resolves an escrowed fill of an offer of `maker`
+  const resolveFillAccounts = async (offerKey: PublicKey, fillKey: PublicKey, authority: PublicKey, fillTaker: PublicKey = taker.publicKey, takerFill: PublicKey | null = null) => ({
+    authority,
+    offer: offerKey,
+    fill: fillKey,
+    maker: maker.publicKey,
+    taker: fillTaker,
+    whitelist: whitelistAddress((await program.account.offer.fetch(offerKey)).offerId.toNumber()),
+    takerFill,
+    vaultTokenAccount: ata(mint_a.publicKey, offerKey),
+    paymentEscrowTokenAccount: ata(mint_b.publicKey, offerKey),
+    makerReceiveTokenAccount: ata(mint_b.publicKey, maker.publicKey),
+    takerReceiveTokenAccount: ata(mint_a.publicKey, fillTaker),
+    takerRefundTokenAccount: ata(mint_b.publicKey, fillTaker),
+    feeTokenAccount: ata(mint_b.publicKey, feeWallet),
+    feeWallet: feeWallet,
+    feeConfig: null,
+    makerTokenAccount: null,
+    inputTokenMint: mint_a.publicKey,
+    outputTokenMint: mint_b.publicKey,
+    tokenProgram: TOKEN_PROGRAM_ID,
+  });
+
+  // Rejects an escrowed fill of an offer of `maker`, only touching the refund accounts
+  const rejectFillAccounts = async (offerKey: PublicKey, fillKey: PublicKey, authority: PublicKey, fillTaker: PublicKey = taker.publicKey, takerFill: PublicKey | null = null) => ({
+    authority,
+    offer: offerKey,
+    fill: fillKey,
+    maker: maker.publicKey,
+    taker: fillTaker,
+    whitelist: whitelistAddress((await program.account.offer.fetch(offerKey)).offerId.toNumber()),
+    takerFill,
+    vaultTokenAccount: ata(mint_a.publicKey, offerKey),
+    paymentEscrowTokenAccount: ata(mint_b.publicKey, offerKey),
+    takerRefundTokenAccount: ata(mint_b.publicKey, fillTaker),
+    inputTokenMint: mint_a.publicKey,
+    outputTokenMint: mint_b.publicKey,
+    tokenProgram: TOKEN_PROGRAM_ID,
+  });
 
 
   before(async () => {
@@ -576,7 +770,15 @@ describe("swap program - create offer", () => {
           new anchor.BN(offerId), // Offer ID
           amountTokenAForSale, // Amount of Token A for sale
           totalTokenBExpected, // Total amount of Token B expected
-          deadlineUnixTimestamp // Offer deadline
+          deadlineUnixTimestamp, // Offer deadline
+          { restricted: {} }, // Offer visibility
+          false, // Close offer on completion
+          new anchor.BN(0), // Offer start time
+          new anchor.BN(0), // Minimum fill amount
+          false, // All or none
+          { vault: {} }, // Offer custody
+          null, // Acceptance window
+          null // Arbitrator
         )
         .accounts({
           maker: maker.publicKey, // Offer creator's public key
@@ -676,7 +878,15 @@ describe("swap program - create offer", () => {
           new anchor.BN(offer1Id), // Offer ID
           amountTokenAForSaleOffer1, // Amount of Token A for sale
           totalTokenBExpectedOffer1, // Total amount of Token B expected
-          deadlineUnixTimestampOffer1 // Offer deadline
+          deadlineUnixTimestampOffer1, // Offer deadline
+          { restricted: {} }, // Offer visibility
+          false, // Close offer on completion
+          new anchor.BN(0), // Offer start time
+          new anchor.BN(0), // Minimum fill amount
+          false, // All or none
+          { vault: {} }, // Offer custody
+          null, // Acceptance window
+          null // Arbitrator
         )
         .accounts({
           maker: maker2.publicKey, // Offer creator's public key
@@ -764,7 +974,15 @@ describe("swap program - create offer", () => {
           new anchor.BN(offer2Id), // Offer ID
           amountTokenAForSaleOffer2, // Amount of Token A for sale
           totalTokenBExpectedOffer2, // Total amount of Token B expected
-          deadlineUnixTimestampOffer2 // Offer deadline
+          deadlineUnixTimestampOffer2, // Offer deadline
+          { restricted: {} }, // Offer visibility
+          false, // Close offer on completion
+          new anchor.BN(0), // Offer start time
+          new anchor.BN(0), // Minimum fill amount
+          false, // All or none
+          { vault: {} }, // Offer custody
+          null, // Acceptance window
+          null // Arbitrator
         )
         .accounts({
           maker: maker2.publicKey, // Offer creator's public key
@@ -830,7 +1048,7 @@ describe("swap program - create offer", () => {
     )[0];
 
     await program.methods.manageWhitelist(
-      [taker.publicKey, new PublicKey('4sijvjMmXG8sdzwsLJevQ1dSXpY6a9T7fPRkVLeqr6Wm'), new PublicKey('7ZK3Y3izGJDGEd2CAvXpotCZinKuYk71YABoHxBEdo4G')]
+      [taker.publicKey, new PublicKey('4sijvjMmXG8sdzwsLJevQ1dSXpY6a9T7fPRkVLeqr6Wm'), new PublicKey('7ZK3Y3izGJDGEd2CAvXpotCZinKuYk71YABoHxBEdo4G')].map((taker) => ({ taker, allocation: null }))
     ).accountsPartial({
       maker: maker.publicKey,
       whitelist: whitelist,
@@ -842,7 +1060,7 @@ describe("swap program - create offer", () => {
     const takerWhitelistFetched = await program.account.whitelist.fetch(whitelist);
     const newWhitelist = [taker.publicKey, new PublicKey('4sijvjMmXG8sdzwsLJevQ1dSXpY6a9T7fPRkVLeqr6Wm'), new PublicKey('7ZK3Y3izGJDGEd2CAvXpotCZinKuYk71YABoHxBEdo4G')]
     assert.deepEqual(
-      takerWhitelistFetched.takers.map(m => m.toBase58()),
+      takerWhitelistFetched.takers.map(m => m.taker.toBase58()),
       newWhitelist.map(m => m.toBase58()),
       "Add takerwhitelist whitelist mismatch"
     );
@@ -858,7 +1076,7 @@ describe("swap program - create offer", () => {
 
     try {
       await program.methods.manageWhitelist(
-        [taker.publicKey, new PublicKey('4sijvjMmXG8sdzwsLJevQ1dSXpY6a9T7fPRkVLeqr6Wm'), new PublicKey('7ZK3Y3izGJDGEd2CAvXpotCZinKuYk71YABoHxBEdo4G')]
+        [taker.publicKey, new PublicKey('4sijvjMmXG8sdzwsLJevQ1dSXpY6a9T7fPRkVLeqr6Wm'), new PublicKey('7ZK3Y3izGJDGEd2CAvXpotCZinKuYk71YABoHxBEdo4G')].map((taker) => ({ taker, allocation: null }))
       ).accountsPartial({
         maker: maker2.publicKey,
         whitelist: whitelist,
@@ -886,7 +1104,7 @@ describe("swap program - create offer", () => {
     )[0];
     
     await program.methods.manageWhitelist(
-      [taker.publicKey]
+      [taker.publicKey].map((taker) => ({ taker, allocation: null }))
     ).accounts({
       maker: maker.publicKey,
       offer: offer,
@@ -897,7 +1115,7 @@ describe("swap program - create offer", () => {
     const takerWhitelistFetched = await program.account.whitelist.fetch(whitelist);
     const newWhitelist = [taker.publicKey]
     assert.deepEqual(
-      takerWhitelistFetched.takers.map(m => m.toBase58()),
+      takerWhitelistFetched.takers.map(m => m.taker.toBase58()),
       newWhitelist.map(m => m.toBase58()),
       "Add takerwhitelist whitelist mismatch"
     );
@@ -908,7 +1126,7 @@ describe("swap program - create offer", () => {
 
     try {
       await program.methods.manageWhitelist(
-        [new PublicKey('7ZK3Y3izGJDGEd2CAvXpotCZinKuYk71YABoHxBEdo4G')]
+        [new PublicKey('7ZK3Y3izGJDGEd2CAvXpotCZinKuYk71YABoHxBEdo4G')].map((taker) => ({ taker, allocation: null }))
       ).accounts({
         maker: maker2.publicKey,
         offer: offer,
@@ -1015,7 +1233,11 @@ describe("swap program - create offer", () => {
     
     try {
         const takeOfferTx = await program.methods.takeOffer(
-            new anchor.BN('500')
+            new anchor.BN('500'), // Amount of Token A to take
+            new anchor.BN('250'), // Max Token B payment to maker
+            new anchor.BN('10'), // Max Token B protocol fee
+            null, // Merkle proof
+            null // Allow ticket
         ).accountsPartial({
             core: {
                 taker: taker.publicKey,
@@ -1089,7 +1311,7 @@ it("maker cancels the offer and close vault&whitelist PDAs", async () => {
 
            // Execute cancel transaction
       try {
-        const cancelTx = await program.methods.cancelOffer()
+        const cancelTx = await program.methods.cancelOffer(false)
           .accounts({
               maker: maker.publicKey,
               makerTokenAccount: makerTokenAccount.address,
@@ -1187,7 +1409,15 @@ it("taker partially accepts an offer and maker cancels remaining", async () => {
               new anchor.BN(offerIdPartial),
               amountTokenAForSale,
               totalTokenBExpected,
-              deadlineUnixTimestamp
+              deadlineUnixTimestamp,
+              { restricted: {} }, // Offer visibility
+              false, // Close offer on completion
+              new anchor.BN(0), // Offer start time
+              new anchor.BN(0), // Minimum fill amount
+              false, // All or none
+              { vault: {} }, // Offer custody
+              null, // Acceptance window
+              null // Arbitrator
           )
           .accountsPartial({
               maker: maker.publicKey,
@@ -1219,7 +1449,7 @@ it("taker partially accepts an offer and maker cancels remaining", async () => {
           program.programId
       )[0];
 
-      await program.methods.manageWhitelist([taker.publicKey])
+      await program.methods.manageWhitelist([{ taker: taker.publicKey, allocation: new anchor.BN(300) }])
           .accounts({
               maker: maker.publicKey,
               offer: offer,
@@ -1263,7 +1493,7 @@ it("taker partially accepts an offer and maker cancels remaining", async () => {
       console.log('Offer PDA:', offer.toBase58());
 
       // 7. EXECUTE PARTIAL TAKE
-      await program.methods.takeOffer(partialAmount)
+      await program.methods.takeOffer(partialAmount, new anchor.BN('100'), new anchor.BN('5'), null, null)
           .accountsPartial({
               core: {
                   taker: taker.publicKey,
@@ -1311,6 +1541,14 @@ it("taker partially accepts an offer and maker cancels remaining", async () => {
           "Taker should receive exactly 200 new tokens"
       );
 
+      // Verify the fill was deducted from the taker's allocation
+      const whitelistAfterTake = await program.account.whitelist.fetch(whitelist);
+      assert.equal(
+          whitelistAfterTake.takers[0].allocation.toString(),
+          "100",
+          "Taker allocation should decrease by 200"
+      );
+
       // 9. CANCEL REMAINING: Maker cancels remaining amount
       const makerBalanceBeforeCancel = await connection.getTokenAccountBalance(
           makerTokenAccount.address
@@ -1319,7 +1557,7 @@ it("taker partially accepts an offer and maker cancels remaining", async () => {
       console.log(makerBalanceBeforeCancel);
 
       try {
-        await program.methods.cancelOffer()
+        await program.methods.cancelOffer(false)
           .accountsPartial({
             maker: maker.publicKey,
             offer: offer,
@@ -1361,4 +1599,1288 @@ it("taker partially accepts an offer and maker cancels remaining", async () => {
       throw error;
   }
 });
+
+it("taker fills a public offer without a whitelist", async () => {
+  const offerIdPublic = 356758;
+  const amountTokenAForSale = new anchor.BN('500');
+  const totalTokenBExpected = new anchor.BN('250');
+  const deadlineUnixTimestamp = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+  const publicOffer = PublicKey.findProgramAddressSync(
+    [Buffer.from('offer'), maker.publicKey.toBuffer(), Buffer.from(new anchor.BN(offerIdPublic).toArray('le', 8))],
+    program.programId
+  )[0];
+  const publicVault = getAssociatedTokenAddressSync(
+    mint_a.publicKey,
+    publicOffer,
+    true,
+    TOKEN_PROGRAM_ID,
+    ASSOCIATED_TOKEN_PROGRAM_ID
+  );
+
+  await program.methods
+    .createOfferAndSendTokensToVault(
+      new anchor.BN(offerIdPublic),
+      amountTokenAForSale,
+      totalTokenBExpected,
+      deadlineUnixTimestamp,
+      { public: {} }, // Offer visibility
+      false, // Close offer on completion
+      new anchor.BN(0), // Offer start time
+      new anchor.BN(0), // Minimum fill amount
+      false, // All or none
+      { vault: {} }, // Offer custody
+      null, // Acceptance window
+      null // Arbitrator
+    )
+    .accountsPartial({
+      maker: maker.publicKey,
+      offer: publicOffer,
+      makerTokenAccount: makerTokenAccount.address,
+      inputTokenMint: mint_a.publicKey,
+      outputTokenMint: mint_b.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([maker])
+    .rpc();
+
+  const takerTokenABefore = await connection.getTokenAccountBalance(takerReceiveTokenAccount);
+
+  // No whitelist PDA exists for this offer, its address is still passed
+  await program.methods.takeOffer(new anchor.BN('100'), new anchor.BN('50'), new anchor.BN('5'), null, null)
+    .accountsPartial({
+      core: {
+        taker: taker.publicKey,
+        offer: publicOffer,
+        maker: maker.publicKey,
+        whitelist: whitelistAddress(offerIdPublic),
+      },
+      token: {
+        takerPaymentTokenAccount: takerPaymentTokenAccount,
+        vaultTokenAccount: publicVault,
+        inputTokenMint: mint_a.publicKey,
+        outputTokenMint: mint_b.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        feeWallet: new PublicKey('B5WFNofBtPcFUS9oR2oAuxTHsSCUVp3C4VjFtejKEUnv'),
+        taker: taker.publicKey,
+        maker: maker.publicKey,
+        offer: publicOffer,
+      }
+    })
+    .signers([taker])
+    .rpc();
+
+  const takerTokenAAfter = await connection.getTokenAccountBalance(takerReceiveTokenAccount);
+  assert.equal(
+    Number(takerTokenAAfter.value.amount) - Number(takerTokenABefore.value.amount),
+    100,
+    "Taker should receive 100 tokens from the public offer"
+  );
+
+  // Cancelling must also work without a whitelist PDA, and closes the offer on request
+  await program.methods.cancelOffer(true)
+    .accountsPartial({
+      maker: maker.publicKey,
+      offer: publicOffer,
+      whitelist: whitelistAddress(offerIdPublic),
+      makerTokenAccount: makerTokenAccount.address,
+      inputTokenMint: mint_a.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([maker])
+    .rpc();
+
+  assert.isNull(await connection.getAccountInfo(publicOffer), "Offer account should be closed");
+});
+it("escrowed fill is held until the maker settles it", async () => {
+  const escrowOffer = offerAddress(400001);
+  const paymentEscrow = ata(mint_b.publicKey, escrowOffer);
+  await createTestOffer(400001, 500, 250, { acceptanceWindow: 3600 });
+
+  const takerTokenABefore = await tokenBalance(ata(mint_a.publicKey, taker.publicKey));
+  const makerTokenBBefore = await tokenBalance(ata(mint_b.publicKey, maker.publicKey));
+
+  const fill = fillAddress(escrowOffer, 0);
+  await takeTestOffer(escrowOffer, 200, 100, 3, { fill, paymentEscrow });
+
+  // Payment and fee wait in the escrow, the input tokens stay in the vault
+  assert.equal(await tokenBalance(paymentEscrow), 103, "Payment and fee should be escrowed");
+  assert.equal(await tokenBalance(ata(mint_a.publicKey, escrowOffer)), 500, "Vault should keep the reserved tokens");
+  const fillAccount = await program.account.fill.fetch(fill);
+  assert.equal(fillAccount.inputTokenAmount.toNumber(), 200);
+  assert.equal(fillAccount.paymentAmount.toNumber(), 100);
+  assert.equal(fillAccount.feeAmount.toNumber(), 3);
+  assert.equal((await program.account.offer.fetch(escrowOffer)).pendingFills.toNumber(), 1);
+
+  await program.methods.settleFill()
+    .accountsPartial(await resolveFillAccounts(escrowOffer, fill, maker.publicKey))
+    .signers([maker])
+    .rpc();
+
+  assert.equal(await tokenBalance(ata(mint_a.publicKey, taker.publicKey)) - takerTokenABefore, 200);
+  assert.equal(await tokenBalance(ata(mint_b.publicKey, maker.publicKey)) - makerTokenBBefore, 100);
+  assert.equal(await tokenBalance(paymentEscrow), 0, "Escrow should be released");
+  assert.isNull(await connection.getAccountInfo(fill), "Fill should be closed");
+  assert.equal((await program.account.offer.fetch(escrowOffer)).pendingFills.toNumber(), 0);
+});
+
+it("maker rejects an escrowed fill and the taker is refunded", async () => {
+  const escrowOffer = offerAddress(400001);
+  const paymentEscrow = ata(mint_b.publicKey, escrowOffer);
+  const takerTokenBBefore = await tokenBalance(ata(mint_b.publicKey, taker.publicKey));
+
+  const fill = fillAddress(escrowOffer, 1);
+  await takeTestOffer(escrowOffer, 100, 50, 2, { fill, paymentEscrow });
+  assert.equal((await program.account.offer.fetch(escrowOffer)).tokenAmountRemaining.toNumber(), 200);
+
+  await program.methods.rejectFill()
+    .accountsPartial(await rejectFillAccounts(escrowOffer, fill, maker.publicKey))
+    .signers([maker])
+    .rpc();
+
+  assert.equal(await tokenBalance(ata(mint_b.publicKey, taker.publicKey)), takerTokenBBefore, "Taker should be refunded");
+  assert.equal((await program.account.offer.fetch(escrowOffer)).tokenAmountRemaining.toNumber(), 300);
+  assert.isNull(await connection.getAccountInfo(fill), "Fill should be closed");
+});
+
+it("anyone rejects an escrowed fill once its acceptance window passed", async () => {
+  const escrowOffer = offerAddress(400002);
+  const paymentEscrow = ata(mint_b.publicKey, escrowOffer);
+  await createTestOffer(400002, 500, 250, { acceptanceWindow: 2 });
+
+  const fill = fillAddress(escrowOffer, 0);
+  await takeTestOffer(escrowOffer, 100, 50, 2, { fill, paymentEscrow });
+
+  await expectAnchorError(
+    program.methods.rejectFill()
+      .accountsPartial(await rejectFillAccounts(escrowOffer, fill, maker2.publicKey))
+      .signers([maker2])
+      .rpc(),
+    'CannotRejectFill'
+  );
+
+  await sleep(4000);
+
+  await program.methods.rejectFill()
+    .accountsPartial(await rejectFillAccounts(escrowOffer, fill, maker2.publicKey))
+    .signers([maker2])
+    .rpc();
+  assert.equal(await tokenBalance(paymentEscrow), 0, "Escrowed payment should be refunded");
+  assert.equal((await program.account.offer.fetch(escrowOffer)).pendingFills.toNumber(), 0);
+});
+
+it("Fail: take an escrowed offer paying into another offer's escrow", async () => {
+  const escrowOffer = offerAddress(400001);
+  const otherOffer = offerAddress(400002);
+  const offerAccount = await program.account.offer.fetch(escrowOffer);
+
+  await expectAnchorError(
+    takeTestOffer(escrowOffer, 100, 50, 2, {
+      fill: fillAddress(escrowOffer, offerAccount.fillCount.toNumber()),
+      paymentEscrow: ata(mint_b.publicKey, otherOffer),
+      tokenOffer: otherOffer,
+    }),
+    'OfferAccountMismatch'
+  );
+});
+it("Fail: expiry bounty above the maximum", async () => {
+  await expectAnchorError(
+    program.methods.updateExpiryBounty(new anchor.BN(501))
+      .accounts({ admin: admin.publicKey })
+      .signers([admin])
+      .rpc(),
+    'InvalidExpiryBounty'
+  );
+});
+
+it("updates the expiry bounty", async () => {
+  await program.methods.updateExpiryBounty(new anchor.BN(100))
+    .accounts({ admin: admin.publicKey })
+    .signers([admin])
+    .rpc();
+
+  const expiryConfig = PublicKey.findProgramAddressSync([Buffer.from('expiry_config')], program.programId)[0];
+  const expiryConfigFetched = await program.account.expiryConfig.fetch(expiryConfig);
+  assert.equal(expiryConfigFetched.expiryBounty.toNumber(), 100);
+});
+
+const expireAccounts = (offerKey: PublicKey, cranker: PublicKey) => ({
+  cranker,
+  offer: offerKey,
+  maker: maker.publicKey,
+  vaultTokenAccount: ata(mint_a.publicKey, offerKey),
+  inputTokenMint: mint_a.publicKey,
+  tokenProgram: TOKEN_PROGRAM_ID,
+});
+
+it("Fail: expire an offer before its deadline", async () => {
+  await createTestOffer(400011, 1000, 500);
+
+  await expectAnchorError(
+    program.methods.expireOffer()
+      .accountsPartial(expireAccounts(offerAddress(400011), maker2.publicKey))
+      .signers([maker2])
+      .rpc(),
+    'OfferNotExpired'
+  );
+});
+
+it("cranker expires an offer and receives the bounty copied at creation", async () => {
+  const expiringOffer = offerAddress(400012);
+  await createTestOffer(400012, 1000, 500, { deadline: now() + 4 });
+  assert.equal((await program.account.offer.fetch(expiringOffer)).expiryBounty.toNumber(), 100);
+
+  // Raising the bounty afterwards doesn't change what the offer pays
+  await program.methods.updateExpiryBounty(new anchor.BN(500))
+    .accounts({ admin: admin.publicKey })
+    .signers([admin])
+    .rpc();
+
+  await sleep(6000);
+
+  const makerBalanceBefore = await tokenBalance(makerTokenAccount.address);
+  const crankerBalanceBefore = await tokenBalance(maker2TokenAccount.address);
+  await program.methods.expireOffer()
+    .accountsPartial(expireAccounts(expiringOffer, maker2.publicKey))
+    .signers([maker2])
+    .rpc();
+
+  assert.equal(await tokenBalance(maker2TokenAccount.address) - crankerBalanceBefore, 10, "Cranker should receive a 1% bounty");
+  assert.equal(await tokenBalance(makerTokenAccount.address) - makerBalanceBefore, 990);
+  assert.isNull(await connection.getAccountInfo(ata(mint_a.publicKey, expiringOffer)), "Vault should be closed");
+  const offerAccount = await program.account.offer.fetch(expiringOffer);
+  assert.equal(JSON.stringify(offerAccount.status), JSON.stringify({ expired: {} }));
+
+  await program.methods.updateExpiryBounty(new anchor.BN(0))
+    .accounts({ admin: admin.publicKey })
+    .signers([admin])
+    .rpc();
+});
+it("Fail: add more takers than the admin configured maximum", async () => {
+  const restrictedOffer = offerAddress(400021);
+  await createTestOffer(400021, 500, 250, { visibility: { restricted: {} } });
+
+  await program.methods.updateMaxWhitelistTakers(2)
+    .accounts({ admin: admin.publicKey })
+    .signers([admin])
+    .rpc();
+
+  const takers = [Keypair.generate(), Keypair.generate(), Keypair.generate()]
+    .map((account) => ({ taker: account.publicKey, allocation: null }));
+
+  await expectAnchorError(
+    program.methods.addTakers(takers)
+      .accounts({ maker: maker.publicKey, offer: restrictedOffer })
+      .signers([maker])
+      .rpc(),
+    'WhitelistFull'
+  );
+  await expectAnchorError(
+    program.methods.manageWhitelist(takers)
+      .accounts({ maker: maker.publicKey, offer: restrictedOffer })
+      .signers([maker])
+      .rpc(),
+    'WhitelistFull'
+  );
+
+  // Within the configured maximum the takers are added
+  await program.methods.addTakers(takers.slice(0, 2))
+    .accounts({ maker: maker.publicKey, offer: restrictedOffer })
+    .signers([maker])
+    .rpc();
+  const whitelistFetched = await program.account.whitelist.fetch(whitelistAddress(400021));
+  assert.equal(whitelistFetched.takers.length, 2);
+
+  await program.methods.updateMaxWhitelistTakers(50)
+    .accounts({ admin: admin.publicKey })
+    .signers([admin])
+    .rpc();
+});
+it("halted and denied mints cannot be traded", async () => {
+  const haltedOffer = offerAddress(400031);
+  await createTestOffer(400031, 500, 250);
+
+  const setMintHalted = (mint: PublicKey, halted: boolean) =>
+    program.methods.setMintHalted(halted)
+      .accounts({ admin: admin.publicKey, mint })
+      .signers([admin])
+      .rpc();
+  const setMintDenied = (mint: PublicKey, denied: boolean) =>
+    program.methods.setMintDenied(denied)
+      .accounts({ admin: admin.publicKey, mint })
+      .signers([admin])
+      .rpc();
+
+  await setMintHalted(mint_b.publicKey, true);
+  await expectAnchorError(takeTestOffer(haltedOffer, 100, 50, 2), 'MintHalted');
+  await expectAnchorError(createTestOffer(400032, 500, 250), 'MintHalted');
+
+  await setMintHalted(mint_b.publicKey, false);
+  await takeTestOffer(haltedOffer, 100, 50, 2);
+
+  await setMintDenied(mint_a.publicKey, true);
+  await expectAnchorError(takeTestOffer(haltedOffer, 100, 50, 2), 'MintDenied');
+  await expectAnchorError(createTestOffer(400032, 500, 250), 'MintDenied');
+
+  await setMintDenied(mint_a.publicKey, false);
+  await createTestOffer(400032, 500, 250);
+});
+it("admin transfer revokes the roles of the outgoing admin", async () => {
+  const newAdmin = Keypair.generate();
+  const airdropTx = await connection.requestAirdrop(newAdmin.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+  await connection.confirmTransaction(airdropTx, 'confirmed');
+  const adminRoles = PublicKey.findProgramAddressSync([Buffer.from('admin_roles')], program.programId)[0];
+
+  await program.methods.proposeAdmin(newAdmin.publicKey)
+    .accounts({ admin: admin.publicKey })
+    .signers([admin])
+    .rpc();
+  await program.methods.acceptAdmin()
+    .accounts({ newAdmin: newAdmin.publicKey })
+    .signers([newAdmin])
+    .rpc();
+
+  const rolesAfterTransfer = await program.account.adminRoles.fetch(adminRoles);
+  assert.isNull(rolesAfterTransfer.feeManager, "Fee manager role should be revoked");
+  assert.isNull(rolesAfterTransfer.listingManager, "Listing manager role should be revoked");
+  assert.isNull(rolesAfterTransfer.pauser, "Pauser role should be revoked");
+
+  await expectAnchorError(
+    program.methods.updateFeePercentage(new anchor.BN(300))
+      .accounts({ admin: admin.publicKey })
+      .signers([admin])
+      .rpc(),
+    'UnauthorizedAdmin'
+  );
+
+  // Hand control back and restore the roles the following tests rely on
+  await program.methods.proposeAdmin(admin.publicKey)
+    .accounts({ admin: newAdmin.publicKey })
+    .signers([newAdmin])
+    .rpc();
+  await program.methods.acceptAdmin()
+    .accounts({ newAdmin: admin.publicKey })
+    .signers([admin])
+    .rpc();
+  for (const role of [{ feeManager: {} }, { listingManager: {} }, { pauser: {} }]) {
+    await program.methods.grantRole(role as any, admin.publicKey)
+      .accounts({ admin: admin.publicKey })
+      .signers([admin])
+      .rpc();
+  }
+  const rolesRestored = await program.account.adminRoles.fetch(adminRoles);
+  assert(rolesRestored.feeManager.equals(admin.publicKey), "Fee manager role should be granted again");
+});
+it("arbitrator resolves a disputed escrowed fill", async () => {
+  const arbitrator = Keypair.generate();
+  const airdropTx = await connection.requestAirdrop(arbitrator.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+  await connection.confirmTransaction(airdropTx, 'confirmed');
+
+  const disputedOffer = offerAddress(400041);
+  const paymentEscrow = ata(mint_b.publicKey, disputedOffer);
+  await createTestOffer(400041, 500, 250, { acceptanceWindow: 3600, arbitrator: arbitrator.publicKey });
+
+  const fill = fillAddress(disputedOffer, 0);
+  await takeTestOffer(disputedOffer, 200, 100, 3, { fill, paymentEscrow });
+
+  const raiseDispute = (party: Keypair) =>
+    program.methods.raiseDispute()
+      .accounts({ party: party.publicKey, offer: disputedOffer, fill })
+      .signers([party])
+      .rpc();
+
+  await expectAnchorError(raiseDispute(maker2), 'CannotDisputeFill');
+  await raiseDispute(taker);
+  assert.isTrue((await program.account.fill.fetch(fill)).disputed, "Fill should be disputed");
+  await expectAnchorError(raiseDispute(maker), 'FillDisputed');
+
+  // A disputed fill is frozen for the maker
+  await expectAnchorError(
+    program.methods.settleFill()
+      .accountsPartial(await resolveFillAccounts(disputedOffer, fill, maker.publicKey))
+      .signers([maker])
+      .rpc(),
+    'FillDisputed'
+  );
+  await expectAnchorError(
+    program.methods.rejectFill()
+      .accountsPartial(await rejectFillAccounts(disputedOffer, fill, maker.publicKey))
+      .signers([maker])
+      .rpc(),
+    'FillDisputed'
+  );
+
+  const resolveDispute = async (authority: Keypair, inputToTaker: number, paymentToMaker: number) =>
+    program.methods.resolveDispute(new anchor.BN(inputToTaker), new anchor.BN(paymentToMaker))
+      .accountsPartial({
+        ...(await resolveFillAccounts(disputedOffer, fill, authority.publicKey)),
+        feeConfig: feeConfig,
+        makerTokenAccount: makerTokenAccount.address,
+      })
+      .signers([authority])
+      .rpc();
+
+  await expectAnchorError(resolveDispute(maker, 100, 50), 'UnauthorizedArbitrator');
+  await expectAnchorError(resolveDispute(arbitrator, 201, 50), 'InvalidDisputeSplit');
+  await expectAnchorError(resolveDispute(arbitrator, 100, 101), 'InvalidDisputeSplit');
+
+  const takerTokenABefore = await tokenBalance(ata(mint_a.publicKey, taker.publicKey));
+  const takerTokenBBefore = await tokenBalance(ata(mint_b.publicKey, taker.publicKey));
+  const makerTokenABefore = await tokenBalance(makerTokenAccount.address);
+  const makerTokenBBefore = await tokenBalance(ata(mint_b.publicKey, maker.publicKey));
+
+  // Half of the fill goes through, the fee is charged on the released payment only
+  await resolveDispute(arbitrator, 100, 50);
+
+  assert.equal(await tokenBalance(ata(mint_a.publicKey, taker.publicKey)) - takerTokenABefore, 100);
+  assert.equal(await tokenBalance(makerTokenAccount.address) - makerTokenABefore, 100);
+  assert.equal(await tokenBalance(ata(mint_b.publicKey, maker.publicKey)) - makerTokenBBefore, 50);
+  assert.equal(await tokenBalance(ata(mint_b.publicKey, taker.publicKey)) - takerTokenBBefore, 52);
+  assert.equal(await tokenBalance(paymentEscrow), 0, "Escrow should be emptied");
+  assert.isNull(await connection.getAccountInfo(fill), "Fill should be closed");
+});
+it("offer with a future start time can't be taken or end before it starts", async () => {
+  const futureOffer = offerAddress(400051);
+  const startTime = now() + 600;
+  await createTestOffer(400051, 500, 250, { startTime });
+
+  await expectAnchorError(takeTestOffer(futureOffer, 100, 50, 2), 'OfferNotStarted');
+
+  await expectAnchorError(
+    program.methods.updateOffer(null, new anchor.BN(startTime - 300))
+      .accounts({ maker: maker.publicKey, offer: futureOffer })
+      .signers([maker])
+      .rpc(),
+    'InvalidStartTime'
+  );
+
+  const newDeadline = new anchor.BN(startTime + 7200);
+  await program.methods.updateOffer(null, newDeadline)
+    .accounts({ maker: maker.publicKey, offer: futureOffer })
+    .signers([maker])
+    .rpc();
+  assert.equal((await program.account.offer.fetch(futureOffer)).deadline.toString(), newDeadline.toString());
+});
+it("closing a whitelisted offer also closes its whitelist", async () => {
+  const filledOffer = offerAddress(400061);
+  await createTestOffer(400061, 500, 250, { visibility: { restricted: {} }, closeOnCompletion: true });
+  await program.methods.addTakers([{ taker: taker.publicKey, allocation: null }])
+    .accounts({ maker: maker.publicKey, offer: filledOffer })
+    .signers([maker])
+    .rpc();
+
+  // The final fill closes the offer together with its whitelist
+  await takeTestOffer(filledOffer, 500, 250, 10);
+  assert.isNull(await connection.getAccountInfo(filledOffer), "Offer should be closed");
+  assert.isNull(await connection.getAccountInfo(whitelistAddress(400061)), "Whitelist should be closed");
+
+  const cancelledOffer = offerAddress(400062);
+  await createTestOffer(400062, 500, 250, { visibility: { restricted: {} } });
+  await program.methods.addTakers([{ taker: taker.publicKey, allocation: null }])
+    .accounts({ maker: maker.publicKey, offer: cancelledOffer })
+    .signers([maker])
+    .rpc();
+
+  const cancelOffer = (offerWhitelist: PublicKey) =>
+    program.methods.cancelOffer(true)
+      .accountsPartial({
+        maker: maker.publicKey,
+        offer: cancelledOffer,
+        whitelist: offerWhitelist,
+        makerTokenAccount: makerTokenAccount.address,
+        inputTokenMint: mint_a.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([maker])
+      .rpc();
+
+  // Another offer's whitelist can't stand in for the existing one
+  await expectAnchorError(cancelOffer(whitelistAddress(400061)), 'ConstraintSeeds');
+
+  await cancelOffer(whitelistAddress(400062));
+  assert.isNull(await connection.getAccountInfo(cancelledOffer), "Offer should be closed");
+  assert.isNull(await connection.getAccountInfo(whitelistAddress(400062)), "Whitelist should be closed");
+});
+it("Fail: recreate an offer with an id the maker already used", async () => {
+  await expectAnchorError(createTestOffer(400062, 500, 250), 'OfferIdAlreadyUsed');
+  await expectAnchorError(createTestOffer(400001, 500, 250), 'OfferIdAlreadyUsed');
+
+  const sequence = PublicKey.findProgramAddressSync(
+    [Buffer.from('maker_sequence'), maker.publicKey.toBuffer()],
+    program.programId
+  )[0];
+  assert.equal((await program.account.makerSequence.fetch(sequence)).nextOfferId.toNumber(), 400063);
+});
+it("listed mint trade sizes bound every fill except the final remainder", async () => {
+  const listedMint = mintPda('listed_mint', mint_a.publicKey);
+  const listMint = (authority: Keypair, min: number, max: number) =>
+    program.methods.listMint(new anchor.BN(min), new anchor.BN(max))
+      .accounts({ admin: authority.publicKey, mint: mint_a.publicKey })
+      .signers([authority])
+      .rpc();
+
+  await expectAnchorError(listMint(maker, 100, 300), 'UnauthorizedAdmin');
+  await expectAnchorError(listMint(admin, 300, 100), 'InvalidTradeLimits');
+
+  await listMint(admin, 100, 300);
+  const listing = await program.account.listedMint.fetch(listedMint);
+  assert.equal(listing.mint.toBase58(), mint_a.publicKey.toBase58());
+  assert.equal(listing.minTradeAmount.toNumber(), 100);
+  assert.equal(listing.maxTradeAmount.toNumber(), 300);
+
+  // Offers whose smallest allowed fill is above the maximum could never be taken
+  await expectAnchorError(createTestOffer(400071, 500, 250, { allOrNone: true }), 'TradeAboveMaximum');
+  await expectAnchorError(createTestOffer(400071, 500, 250, { minFillAmount: 400 }), 'TradeAboveMaximum');
+
+  const limitedOffer = offerAddress(400071);
+  await createTestOffer(400071, 350, 175);
+
+  await expectAnchorError(takeTestOffer(limitedOffer, 40, 20, 1), 'TradeBelowMinimum');
+  await expectAnchorError(takeTestOffer(limitedOffer, 350, 175, 6), 'TradeAboveMaximum');
+  await takeTestOffer(limitedOffer, 300, 150, 5);
+
+  // The 50 token remainder is below the minimum but can still be taken
+  await takeTestOffer(limitedOffer, 50, 25, 1);
+  assert.equal((await program.account.offer.fetch(limitedOffer)).tokenAmountRemaining.toNumber(), 0);
+
+  await expectAnchorError(
+    program.methods.delistMint()
+      .accountsPartial({ admin: maker.publicKey, listedMint })
+      .signers([maker])
+      .rpc(),
+    'UnauthorizedAdmin'
+  );
+  await program.methods.delistMint()
+    .accountsPartial({ admin: admin.publicKey, listedMint })
+    .signers([admin])
+    .rpc();
+  assert.isNull(await connection.getAccountInfo(listedMint), "Listing should be closed");
+});
+it("mints must stay on the mint whitelist to create and take offers", async () => {
+  const setRequireWhitelist = async (required: boolean) => {
+    if ((await program.account.whitelistConfig.fetch(whitelistConfig)).requireWhitelist !== required) {
+      await program.methods.toggleRequireWhitelist()
+        .accounts({ admin: admin.publicKey })
+        .signers([admin])
+        .rpc();
+    }
+  };
+  await setRequireWhitelist(true);
+
+  const listedOffer = offerAddress(400101);
+  await createTestOffer(400101, 500, 250);
+
+  await program.methods.removeMintsFromWhitelist([mint_b.publicKey])
+    .accounts({ admin: admin.publicKey })
+    .signers([admin])
+    .rpc();
+
+  // Delisting the output mint blocks new offers and freezes fills of existing ones
+  await expectAnchorError(createTestOffer(400102, 500, 250), 'MintNotWhitelisted');
+  await expectAnchorError(takeTestOffer(listedOffer, 100, 50, 2), 'MintNotWhitelisted');
+
+  await program.methods.addMintsToWhitelist([mint_b.publicKey])
+    .accounts({ admin: admin.publicKey })
+    .signers([admin])
+    .rpc();
+
+  await takeTestOffer(listedOffer, 100, 50, 2);
+  await createTestOffer(400102, 500, 250);
+  assert.equal((await program.account.offer.fetch(listedOffer)).tokenAmountRemaining.toNumber(), 400);
+
+  await setRequireWhitelist(false);
+});
+it("taker bounds the payment and fee of a fill", async () => {
+  const boundedOffer = offerAddress(400201);
+  await createTestOffer(400201, 500, 250);
+  const { feePercentage } = await program.account.offer.fetch(boundedOffer);
+  const fee = Math.floor(50 * feePercentage.toNumber() / 10000);
+
+  // Taking 100 of 500 costs 50 plus the fee
+  await expectAnchorError(takeTestOffer(boundedOffer, 100, 49, fee), 'MaxPaymentExceeded');
+  if (fee > 0) {
+    await expectAnchorError(takeTestOffer(boundedOffer, 100, 50, fee - 1), 'MaxFeeExceeded');
+  }
+
+  const makerTokenBBefore = await tokenBalance(ata(mint_b.publicKey, maker.publicKey));
+  const feeBefore = await tokenBalance(ata(mint_b.publicKey, feeWallet));
+  await takeTestOffer(boundedOffer, 100, 50, fee);
+  assert.equal(await tokenBalance(ata(mint_b.publicKey, maker.publicKey)) - makerTokenBBefore, 50);
+  assert.equal(await tokenBalance(ata(mint_b.publicKey, feeWallet)) - feeBefore, fee);
+});
+it("taker pays an exact amount and receives the input rounded down", async () => {
+  const exactOffer = offerAddress(400301);
+  await createTestOffer(400301, 300, 200);
+
+  const takeExactOutput = async (offerKey: PublicKey, payment: number, minInput: number) =>
+    program.methods
+      .takeOfferExactOutput(new anchor.BN(payment), new anchor.BN(minInput), new anchor.BN(10), null, null)
+      .accountsPartial(await takeAccounts(offerKey))
+      .signers([taker])
+      .rpc();
+
+  // Paying 7 buys 10.5 tokens, the half token stays with the maker
+  await expectAnchorError(takeExactOutput(exactOffer, 7, 11), 'MinInputNotMet');
+
+  const takerTokenABefore = await tokenBalance(ata(mint_a.publicKey, taker.publicKey));
+  const makerTokenBBefore = await tokenBalance(ata(mint_b.publicKey, maker.publicKey));
+  await takeExactOutput(exactOffer, 7, 10);
+  assert.equal(await tokenBalance(ata(mint_a.publicKey, taker.publicKey)) - takerTokenABefore, 10);
+  assert.equal(await tokenBalance(ata(mint_b.publicKey, maker.publicKey)) - makerTokenBBefore, 7);
+  assert.equal((await program.account.offer.fetch(exactOffer)).tokenAmountRemaining.toNumber(), 290);
+
+  // A payment too small to buy a single token is rejected
+  const expensiveOffer = offerAddress(400302);
+  await createTestOffer(400302, 100, 300);
+  await expectAnchorError(takeExactOutput(expensiveOffer, 2, 0), 'InvalidAmount');
+});
+it("maker reprices a live offer and later fills pay the new price", async () => {
+  const repricedOffer = offerAddress(400401);
+  await createTestOffer(400401, 500, 250);
+  await takeTestOffer(repricedOffer, 100, 50, 2);
+
+  const updateOffer = (expectedAmount: number | null, deadline: number | null) =>
+    program.methods
+      .updateOffer(
+        expectedAmount === null ? null : new anchor.BN(expectedAmount),
+        deadline === null ? null : new anchor.BN(deadline)
+      )
+      .accounts({ maker: maker.publicKey, offer: repricedOffer })
+      .signers([maker])
+      .rpc();
+
+  await expectAnchorError(updateOffer(0, null), 'InvalidAmount');
+  await expectAnchorError(updateOffer(null, now() - 60), 'InvalidDeadline');
+
+  // Doubling the price doubles the payment of the next fill
+  await updateOffer(500, null);
+  await expectAnchorError(takeTestOffer(repricedOffer, 100, 50, 3), 'MaxPaymentExceeded');
+  await takeTestOffer(repricedOffer, 100, 100, 3);
+
+  const repriced = await program.account.offer.fetch(repricedOffer);
+  assert.equal(repriced.expectedTotalAmount.toNumber(), 500);
+  assert.equal(repriced.expectedFulfilledAmount.toNumber(), 150);
+  assert.equal(repriced.tokenAmountRemaining.toNumber(), 300);
+});
+it("maker tops up and withdraws from an offer at the same price", async () => {
+  const adjustedOffer = offerAddress(400501);
+  const vault = ata(mint_a.publicKey, adjustedOffer);
+  await createTestOffer(400501, 400, 200);
+
+  const adjustAccounts = {
+    maker: maker.publicKey,
+    offer: adjustedOffer,
+    makerTokenAccount: makerTokenAccount.address,
+    vaultTokenAccount: vault,
+    inputTokenMint: mint_a.publicKey,
+    tokenProgram: TOKEN_PROGRAM_ID,
+  };
+
+  await expectAnchorError(
+    program.methods.increaseOffer(new anchor.BN(0)).accountsPartial(adjustAccounts).signers([maker]).rpc(),
+    'InvalidAmount'
+  );
+  await program.methods.increaseOffer(new anchor.BN(200)).accountsPartial(adjustAccounts).signers([maker]).rpc();
+  let adjusted = await program.account.offer.fetch(adjustedOffer);
+  assert.equal(adjusted.tokenAmount.toNumber(), 600);
+  assert.equal(adjusted.expectedTotalAmount.toNumber(), 300);
+  assert.equal(await tokenBalance(vault), 600);
+
+  // Withdrawing everything goes through cancel_offer instead
+  await expectAnchorError(
+    program.methods.decreaseOffer(new anchor.BN(600)).accountsPartial(adjustAccounts).signers([maker]).rpc(),
+    'InsufficientAmount'
+  );
+  await program.methods.decreaseOffer(new anchor.BN(100)).accountsPartial(adjustAccounts).signers([maker]).rpc();
+  adjusted = await program.account.offer.fetch(adjustedOffer);
+  assert.equal(adjusted.tokenAmountRemaining.toNumber(), 500);
+  assert.equal(adjusted.expectedTotalAmount.toNumber(), 250);
+  assert.equal(await tokenBalance(vault), 500);
+});
+it("offers enforce their minimum fill and all-or-none flag except on the final remainder", async () => {
+  await expectAnchorError(createTestOffer(401001, 500, 250, { minFillAmount: 501 }), 'InvalidMinFillAmount');
+
+  const blockOffer = offerAddress(401001);
+  await createTestOffer(401001, 200, 100, { allOrNone: true });
+  await expectAnchorError(takeTestOffer(blockOffer, 100, 50, 2), 'PartialFillNotAllowed');
+  await takeTestOffer(blockOffer, 200, 100, 3);
+  assert.equal((await program.account.offer.fetch(blockOffer)).tokenAmountRemaining.toNumber(), 0);
+
+  const minimumOffer = offerAddress(401002);
+  await createTestOffer(401002, 500, 250, { minFillAmount: 200 });
+  await expectAnchorError(takeTestOffer(minimumOffer, 100, 50, 2), 'FillBelowMinimum');
+  await takeTestOffer(minimumOffer, 400, 200, 6);
+
+  // The 100 token remainder is below the minimum but can still be taken
+  await takeTestOffer(minimumOffer, 100, 50, 2);
+  assert.equal((await program.account.offer.fetch(minimumOffer)).tokenAmountRemaining.toNumber(), 0);
+});
+it("maker adds and removes takers one by one", async () => {
+  const listedOffer = offerAddress(401201);
+  const offerWhitelist = whitelistAddress(401201);
+  await createTestOffer(401201, 500, 250, { visibility: { restricted: {} } });
+  const [taker1, taker2, taker3] = [Keypair.generate(), Keypair.generate(), Keypair.generate()]
+    .map((account) => account.publicKey);
+
+  const addTakers = (takers: PublicKey[]) =>
+    program.methods.addTakers(takers.map((key) => ({ taker: key, allocation: null })))
+      .accounts({ maker: maker.publicKey, offer: listedOffer })
+      .signers([maker])
+      .rpc({ commitment: 'confirmed' });
+  const removeTakers = (takers: PublicKey[]) =>
+    program.methods.removeTakers(takers)
+      .accounts({ maker: maker.publicKey, offer: listedOffer })
+      .signers([maker])
+      .rpc({ commitment: 'confirmed' });
+
+  await expectAnchorError(addTakers([]), 'EmptyTakersList');
+
+  const added = await transactionEvents(await addTakers([taker1, taker2]));
+  assert.deepEqual(
+    added.filter((event) => event.name === 'takerAdded').map((event) => event.data.taker.toBase58()),
+    [taker1.toBase58(), taker2.toBase58()]
+  );
+
+  // Takers already on the list, or repeated within a call, are rejected
+  await expectAnchorError(addTakers([taker2]), 'TakerAlreadyWhitelisted');
+  await expectAnchorError(addTakers([taker3, taker3]), 'TakerAlreadyWhitelisted');
+
+  const makerLamportsBefore = await connection.getBalance(maker.publicKey, 'confirmed');
+  const whitelistLamportsBefore = await connection.getBalance(offerWhitelist, 'confirmed');
+  const removed = await transactionEvents(await removeTakers([taker1]));
+  assert.deepEqual(
+    removed.filter((event) => event.name === 'takerRemoved').map((event) => event.data.taker.toBase58()),
+    [taker1.toBase58()]
+  );
+
+  // The whitelist shrinks to one entry and its freed rent goes back to the maker
+  const oneTakerSpace = 8 + 32 + 32 + 4 + (32 + 9);
+  const whitelistInfo = await connection.getAccountInfo(offerWhitelist, 'confirmed');
+  assert.equal(whitelistInfo.data.length, oneTakerSpace);
+  const refund = whitelistLamportsBefore - await connection.getMinimumBalanceForRentExemption(oneTakerSpace);
+  assert.equal(await connection.getBalance(maker.publicKey, 'confirmed') - makerLamportsBefore, refund);
+  assert.deepEqual(
+    (await program.account.whitelist.fetch(offerWhitelist)).takers.map((entry) => entry.taker.toBase58()),
+    [taker2.toBase58()]
+  );
+
+  await expectAnchorError(removeTakers([taker1]), 'TakerNotWhitelisted');
+});
+it("taker proves membership of the offer's Merkle allowlist", async () => {
+  const sha256 = (...parts: Buffer[]) => createHash('sha256').update(Buffer.concat(parts)).digest();
+  const leafHash = (key: PublicKey, allocation: number | null) =>
+    allocation === null
+      ? sha256(Buffer.from([0]), key.toBuffer())
+      : sha256(Buffer.from([0]), key.toBuffer(), Buffer.from(new anchor.BN(allocation).toArray('le', 8)));
+  const nodeHash = (a: Buffer, b: Buffer) =>
+    Buffer.compare(a, b) <= 0 ? sha256(Buffer.from([1]), a, b) : sha256(Buffer.from([1]), b, a);
+
+  // Three leaves, the taker capped at 150, the odd leaf is carried up unchanged
+  const leaves = [leafHash(taker.publicKey, 150), leafHash(Keypair.generate().publicKey, null), leafHash(maker2.publicKey, 400)];
+  const root = nodeHash(nodeHash(leaves[0], leaves[1]), leaves[2]);
+  const proof = (allocation: number) => ({
+    allocation: new anchor.BN(allocation),
+    proof: [leaves[1], leaves[2]].map((node) => Array.from(node)),
+  });
+
+  const allowlistedOffer = offerAddress(401401);
+  await createTestOffer(401401, 500, 250, { visibility: { restricted: {} } });
+  await program.methods.setTakerMerkleRoot(Array.from(root))
+    .accounts({ maker: maker.publicKey, offer: allowlistedOffer })
+    .signers([maker])
+    .rpc();
+
+  const takerFill = PublicKey.findProgramAddressSync(
+    [Buffer.from('taker_fill'), allowlistedOffer.toBuffer(), taker.publicKey.toBuffer()],
+    program.programId
+  )[0];
+  const takeWithProof = async (amount: number, merkleProof: any, options: TakeOptions = { takerFill }) =>
+    program.methods
+      .takeOffer(new anchor.BN(amount), new anchor.BN(amount), new anchor.BN(5), merkleProof, null)
+      .accountsPartial(await takeAccounts(allowlistedOffer, options))
+      .signers([taker])
+      .rpc();
+
+  await expectAnchorError(takeTestOffer(allowlistedOffer, 100, 50, 2), 'TakerNotWhitelisted');
+  await expectAnchorError(takeWithProof(100, proof(200)), 'InvalidMerkleProof');
+  await expectAnchorError(takeWithProof(100, proof(150), {}), 'TakerFillRequired');
+
+  await takeWithProof(100, proof(150));
+  assert.equal((await program.account.takerFill.fetch(takerFill)).filledAmount.toNumber(), 100);
+
+  // Fills add up against the capped leaf
+  await expectAnchorError(takeWithProof(100, proof(150)), 'AllocationExceeded');
+  await takeWithProof(50, proof(150));
+  assert.equal((await program.account.takerFill.fetch(takerFill)).filledAmount.toNumber(), 150);
+});
+it("taker takes a restricted offer with an allow ticket signed by its approver", async () => {
+  const approver = Keypair.generate();
+  const ticketOffer = offerAddress(401501);
+  await createTestOffer(401501, 500, 250, { visibility: { restricted: {} } });
+  await program.methods.setOfferApprover(approver.publicKey)
+    .accounts({ maker: maker.publicKey, offer: ticketOffer })
+    .signers([maker])
+    .rpc();
+
+  const takerFill = PublicKey.findProgramAddressSync(
+    [Buffer.from('taker_fill'), ticketOffer.toBuffer(), taker.publicKey.toBuffer()],
+    program.programId
+  )[0];
+  const ticket = (options: { taker?: PublicKey; maxAmount?: number; expiry?: number } = {}) => ({
+    offer: ticketOffer,
+    taker: options.taker ?? taker.publicKey,
+    maxAmount: new anchor.BN(options.maxAmount ?? 150),
+    expiry: new anchor.BN(options.expiry ?? now() + 3600),
+  });
+  // Borsh layout of AllowTicket, the message the approver signs
+  const ticketMessage = (allowTicket: ReturnType<typeof ticket>) => Buffer.concat([
+    allowTicket.offer.toBuffer(),
+    allowTicket.taker.toBuffer(),
+    Buffer.from(allowTicket.maxAmount.toArray('le', 8)),
+    Buffer.from(allowTicket.expiry.toTwos(64).toArray('le', 8)),
+  ]);
+  const takeWithTicket = async (
+    amount: number,
+    allowTicket: ReturnType<typeof ticket>,
+    signer: Keypair = approver,
+    options: TakeOptions = { takerFill, instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY }
+  ) =>
+    program.methods
+      .takeOffer(new anchor.BN(amount), new anchor.BN(amount), new anchor.BN(5), null, allowTicket)
+      .accountsPartial(await takeAccounts(ticketOffer, options))
+      .preInstructions([
+        anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: signer.secretKey,
+          message: ticketMessage(allowTicket),
+        }),
+      ])
+      .signers([taker])
+      .rpc();
+
+  // The maker no longer signs tickets once an approver is set
+  await expectAnchorError(takeWithTicket(100, ticket(), maker), 'InvalidAllowTicket');
+  await expectAnchorError(takeWithTicket(100, ticket({ taker: maker2.publicKey })), 'InvalidAllowTicket');
+  await expectAnchorError(takeWithTicket(100, ticket({ expiry: now() - 3600 })), 'AllowTicketExpired');
+  await expectAnchorError(takeWithTicket(100, ticket(), approver, { takerFill }), 'InstructionsSysvarRequired');
+
+  await takeWithTicket(100, ticket());
+  assert.equal((await program.account.takerFill.fetch(takerFill)).filledAmount.toNumber(), 100);
+
+  // Replaying the ticket can't take more than its amount
+  await expectAnchorError(takeWithTicket(100, ticket()), 'AllocationExceeded');
+  await takeWithTicket(50, ticket());
+  assert.equal((await program.account.offer.fetch(ticketOffer)).tokenAmountRemaining.toNumber(), 350);
+});
+it("taker fills an order signed off-chain by the maker", async () => {
+  const orderDelegate = PublicKey.findProgramAddressSync(
+    [Buffer.from('order_delegate'), maker.publicKey.toBuffer()],
+    program.programId
+  )[0];
+  await approve(connection, maker, makerTokenAccount.address, orderDelegate, maker, 1000);
+
+  const order = (nonce: number, options: { taker?: PublicKey | null } = {}) => ({
+    maker: maker.publicKey,
+    inputTokenMint: mint_a.publicKey,
+    outputTokenMint: mint_b.publicKey,
+    inputTokenAmount: new anchor.BN(200),
+    expectedTotalAmount: new anchor.BN(100),
+    deadline: new anchor.BN(now() + 3600),
+    nonce: new anchor.BN(nonce),
+    taker: options.taker === undefined ? taker.publicKey : options.taker,
+  });
+  // Program id followed by the Borsh layout of SignedOrder
+  const orderMessage = (signedOrder: ReturnType<typeof order>) => Buffer.concat([
+    program.programId.toBuffer(),
+    signedOrder.maker.toBuffer(),
+    signedOrder.inputTokenMint.toBuffer(),
+    signedOrder.outputTokenMint.toBuffer(),
+    Buffer.from(signedOrder.inputTokenAmount.toArray('le', 8)),
+    Buffer.from(signedOrder.expectedTotalAmount.toArray('le', 8)),
+    Buffer.from(signedOrder.deadline.toTwos(64).toArray('le', 8)),
+    Buffer.from(signedOrder.nonce.toArray('le', 8)),
+    signedOrder.taker === null ? Buffer.from([0]) : Buffer.concat([Buffer.from([1]), signedOrder.taker.toBuffer()]),
+  ]);
+  const orderNonces = (nonce: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('order_nonces'), maker.publicKey.toBuffer(), Buffer.from(new anchor.BN(Math.floor(nonce / 1024)).toArray('le', 8))],
+      program.programId
+    )[0];
+  const fill = (signedOrder: ReturnType<typeof order>, signer: Keypair = maker) =>
+    program.methods
+      .fillSignedOrder(signedOrder, new anchor.BN(5))
+      .accountsPartial({
+        taker: taker.publicKey,
+        maker: maker.publicKey,
+        orderNonces: orderNonces(signedOrder.nonce.toNumber()),
+        makerTokenAccount: makerTokenAccount.address,
+        takerPaymentTokenAccount: ata(mint_b.publicKey, taker.publicKey),
+        feeWallet: feeWallet,
+        inputTokenMint: mint_a.publicKey,
+        outputTokenMint: mint_b.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .preInstructions([
+        anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: signer.secretKey,
+          message: orderMessage(signedOrder),
+        }),
+      ])
+      .signers([taker])
+      .rpc();
+
+  await expectAnchorError(fill(order(1), maker2), 'InvalidOrderSignature');
+  await expectAnchorError(fill(order(1, { taker: maker2.publicKey })), 'OrderTakerMismatch');
+
+  const takerReceiveBefore = await tokenBalance(ata(mint_a.publicKey, taker.publicKey));
+  const makerReceiveBefore = await tokenBalance(ata(mint_b.publicKey, maker.publicKey));
+  await fill(order(1));
+  assert.equal(await tokenBalance(ata(mint_a.publicKey, taker.publicKey)) - takerReceiveBefore, 200);
+  assert.equal(await tokenBalance(ata(mint_b.publicKey, maker.publicKey)) - makerReceiveBefore, 100);
+  await expectAnchorError(fill(order(1)), 'OrderNonceUsed');
+
+  // Open orders can be filled by anyone until the maker burns their nonce
+  await program.methods.cancelSignedOrder(new anchor.BN(2))
+    .accountsPartial({ maker: maker.publicKey, orderNonces: orderNonces(2) })
+    .signers([maker])
+    .rpc();
+  await expectAnchorError(fill(order(2, { taker: null })), 'OrderNonceUsed');
+});
+it("delegated offers take from the maker's account while the delegation covers the fill", async () => {
+  const delegatedOffer = offerAddress(401701);
+  const makerBalanceBefore = await tokenBalance(makerTokenAccount.address);
+  await createTestOffer(401701, 300, 150, { custody: { delegate: {} } });
+
+  // The tokens stay with the maker, the offer PDA is approved for them
+  let source = await getAccount(connection, makerTokenAccount.address, 'confirmed');
+  assert.equal(Number(source.amount), makerBalanceBefore);
+  assert.equal(source.delegate.toBase58(), delegatedOffer.toBase58());
+  assert.equal(Number(source.delegatedAmount), 300);
+
+  const takerReceiveBefore = await tokenBalance(ata(mint_a.publicKey, taker.publicKey));
+  await takeTestOffer(delegatedOffer, 100, 50, 2, { tokenSource: makerTokenAccount.address });
+  assert.equal(await tokenBalance(ata(mint_a.publicKey, taker.publicKey)) - takerReceiveBefore, 100);
+  assert.equal(makerBalanceBefore - await tokenBalance(makerTokenAccount.address), 100);
+
+  await revoke(connection, maker, makerTokenAccount.address, maker);
+  await expectAnchorError(
+    takeTestOffer(delegatedOffer, 100, 50, 2, { tokenSource: makerTokenAccount.address }),
+    'InsufficientDelegation'
+  );
+
+  // Adjusting the offer approves the offer PDA again for the new remaining amount
+  await program.methods.decreaseOffer(new anchor.BN(50))
+    .accountsPartial({
+      maker: maker.publicKey,
+      offer: delegatedOffer,
+      makerTokenAccount: makerTokenAccount.address,
+      vaultTokenAccount: null,
+      inputTokenMint: mint_a.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([maker])
+    .rpc();
+  source = await getAccount(connection, makerTokenAccount.address, 'confirmed');
+  assert.equal(Number(source.delegatedAmount), 150);
+
+  await takeTestOffer(delegatedOffer, 100, 50, 2, { tokenSource: makerTokenAccount.address });
+  assert.equal((await program.account.offer.fetch(delegatedOffer)).tokenAmountRemaining.toNumber(), 50);
+});
+it("admin transfer only completes when the pending admin accepts", async () => {
+  const candidate = Keypair.generate();
+  const newAdmin = Keypair.generate();
+  const airdropTx = await connection.requestAirdrop(newAdmin.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+  await connection.confirmTransaction(airdropTx, 'confirmed');
+  const propose = (pendingAdmin: PublicKey, signer: Keypair = admin) =>
+    program.methods.proposeAdmin(pendingAdmin)
+      .accounts({ admin: signer.publicKey })
+      .signers([signer])
+      .rpc();
+  const accept = (signer: Keypair) =>
+    program.methods.acceptAdmin()
+      .accounts({ newAdmin: signer.publicKey })
+      .signers([signer])
+      .rpc();
+
+  await expectAnchorError(propose(candidate.publicKey, candidate), 'UnauthorizedAdmin');
+  await expectAnchorError(propose(PublicKey.default), 'InvalidAddress');
+
+  await propose(candidate.publicKey);
+  let config = await program.account.adminConfig.fetch(adminConfig);
+  assert(config.admin.equals(admin.publicKey), "Proposing doesn't transfer the admin role");
+  assert(config.pendingAdmin.equals(candidate.publicKey));
+  await expectAnchorError(accept(newAdmin), 'NotPendingAdmin');
+
+  // Proposing again replaces the pending admin
+  await propose(newAdmin.publicKey);
+  await expectAnchorError(accept(candidate), 'NotPendingAdmin');
+  await accept(newAdmin);
+  config = await program.account.adminConfig.fetch(adminConfig);
+  assert(config.admin.equals(newAdmin.publicKey));
+  assert.isNull(config.pendingAdmin);
+
+  // Hand control back and restore the roles the following tests rely on
+  await propose(admin.publicKey, newAdmin);
+  await accept(admin);
+  for (const role of [{ feeManager: {} }, { listingManager: {} }, { pauser: {} }]) {
+    await program.methods.grantRole(role as any, admin.publicKey)
+      .accounts({ admin: admin.publicKey })
+      .signers([admin])
+      .rpc();
+  }
+});
+it("pauser stops creating and taking offers while cancelling stays open", async () => {
+  const setPause = (paused: boolean, create: boolean, take: boolean, pauser: Keypair = admin) =>
+    (paused ? program.methods.pause(create, take) : program.methods.unpause(create, take))
+      .accounts({ pauser: pauser.publicKey })
+      .signers([pauser])
+      .rpc();
+
+  await expectAnchorError(setPause(true, true, true, taker), 'UnauthorizedAdmin');
+
+  const pausedOffer = offerAddress(402301);
+  await createTestOffer(402301, 500, 250);
+
+  await setPause(true, true, false);
+  await expectAnchorError(createTestOffer(402302, 500, 250), 'CreatePaused');
+  // Existing offers can still be taken while only creation is paused
+  await takeTestOffer(pausedOffer, 100, 50, 2);
+
+  await setPause(true, false, true);
+  let config = await program.account.adminConfig.fetch(adminConfig);
+  assert.isTrue(config.createPaused);
+  assert.isTrue(config.takePaused);
+  await expectAnchorError(takeTestOffer(pausedOffer, 100, 50, 2), 'TakePaused');
+
+  // Unpausing creation leaves taking paused
+  await setPause(false, true, false);
+  await createTestOffer(402302, 500, 250);
+  await expectAnchorError(takeTestOffer(offerAddress(402302), 100, 50, 2), 'TakePaused');
+
+  // Makers can always get their tokens back
+  await program.methods.cancelOffer(true)
+    .accountsPartial({
+      maker: maker.publicKey,
+      offer: pausedOffer,
+      whitelist: whitelistAddress(402301),
+      makerTokenAccount: makerTokenAccount.address,
+      inputTokenMint: mint_a.publicKey,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .signers([maker])
+    .rpc();
+  assert.isNull(await connection.getAccountInfo(pausedOffer), "Offer should be closed");
+
+  await setPause(false, false, true);
+  config = await program.account.adminConfig.fetch(adminConfig);
+  assert.isFalse(config.createPaused);
+  assert.isFalse(config.takePaused);
+  await takeTestOffer(offerAddress(402302), 100, 50, 2);
+});
+it("rejecting an escrowed fill gives the taker's whitelist allocation back", async () => {
+  const escrowOffer = offerAddress(402401);
+  const paymentEscrow = ata(mint_b.publicKey, escrowOffer);
+  await createTestOffer(402401, 500, 250, { visibility: { restricted: {} }, acceptanceWindow: 3600 });
+  await program.methods.addTakers([{ taker: taker.publicKey, allocation: new anchor.BN(200) }])
+    .accounts({ maker: maker.publicKey, offer: escrowOffer })
+    .signers([maker])
+    .rpc();
+  const allocation = async () =>
+    (await program.account.whitelist.fetch(whitelistAddress(402401))).takers[0].allocation.toNumber();
+
+  await takeTestOffer(escrowOffer, 200, 100, 3, { fill: fillAddress(escrowOffer, 0), paymentEscrow });
+  assert.equal(await allocation(), 0);
+  assert.isTrue((await program.account.fill.fetch(fillAddress(escrowOffer, 0))).consumedAllocation);
+
+  await program.methods.rejectFill()
+    .accountsPartial(await rejectFillAccounts(escrowOffer, fillAddress(escrowOffer, 0), maker.publicKey))
+    .signers([maker])
+    .rpc();
+  assert.equal(await allocation(), 200);
+
+  // The whole allocation can be taken again
+  await expectAnchorError(
+    takeTestOffer(escrowOffer, 201, 101, 4, { fill: fillAddress(escrowOffer, 1), paymentEscrow }),
+    'AllocationExceeded'
+  );
+  await takeTestOffer(escrowOffer, 200, 100, 3, { fill: fillAddress(escrowOffer, 1), paymentEscrow });
+  assert.equal(await allocation(), 0);
+});
+it("escrowed offer closes on completion once its last fill settles", async () => {
+  const escrowOffer = offerAddress(402402);
+  const paymentEscrow = ata(mint_b.publicKey, escrowOffer);
+  await createTestOffer(402402, 200, 100, { acceptanceWindow: 3600, closeOnCompletion: true });
+
+  const fill = fillAddress(escrowOffer, 0);
+  await takeTestOffer(escrowOffer, 200, 100, 3, { fill, paymentEscrow });
+  // The offer waits for the pending fill before completing
+  assert.isNotNull(await connection.getAccountInfo(escrowOffer), "Offer should stay open while the fill is pending");
+
+  await program.methods.settleFill()
+    .accountsPartial(await resolveFillAccounts(escrowOffer, fill, maker.publicKey))
+    .signers([maker])
+    .rpc();
+  assert.isNull(await connection.getAccountInfo(escrowOffer), "Offer should be closed");
+  assert.isNull(await connection.getAccountInfo(ata(mint_a.publicKey, escrowOffer)), "Vault should be closed");
+  assert.isNull(await connection.getAccountInfo(paymentEscrow), "Payment escrow should be closed");
+});
+it("taker takes from a whitelist at the largest configurable size", async () => {
+  const setMaxTakers = (maxTakers: number) =>
+    program.methods.updateMaxWhitelistTakers(maxTakers)
+      .accounts({ admin: admin.publicKey })
+      .signers([admin])
+      .rpc();
+  await expectAnchorError(setMaxTakers(81), 'InvalidWhitelistSize');
+  await setMaxTakers(80);
+
+  const fullOffer = offerAddress(402501);
+  await createTestOffer(402501, 500, 250, { visibility: { restricted: {} } });
+
+  // The taker is listed last, so the take scans the whole whitelist
+  const takers = Array.from({ length: 79 }, () => ({ taker: Keypair.generate().publicKey, allocation: null as anchor.BN | null }));
+  takers.push({ taker: taker.publicKey, allocation: new anchor.BN(150) });
+  for (let i = 0; i < takers.length; i += 16) {
+    await program.methods.addTakers(takers.slice(i, i + 16))
+      .accounts({ maker: maker.publicKey, offer: fullOffer })
+      .signers([maker])
+      .rpc();
+  }
+  assert.equal((await program.account.whitelist.fetch(whitelistAddress(402501))).takers.length, 80);
+
+  await takeTestOffer(fullOffer, 100, 50, 2);
+  const whitelistAfter = await program.account.whitelist.fetch(whitelistAddress(402501));
+  assert.equal(whitelistAfter.takers[79].allocation.toNumber(), 50);
+
+  await setMaxTakers(50);
+});
+it("adjusting an offer at a non-divisible price rounds the expected amount up", async () => {
+  const adjustedOffer = offerAddress(402601);
+  await createTestOffer(402601, 300, 100);
+  const adjustAccounts = {
+    maker: maker.publicKey,
+    offer: adjustedOffer,
+    makerTokenAccount: makerTokenAccount.address,
+    vaultTokenAccount: ata(mint_a.publicKey, adjustedOffer),
+    inputTokenMint: mint_a.publicKey,
+    tokenProgram: TOKEN_PROGRAM_ID,
+  };
+
+  // 400 * 100 / 300 = 133.33, the maker's price is kept by rounding up
+  await program.methods.increaseOffer(new anchor.BN(100)).accountsPartial(adjustAccounts).signers([maker]).rpc();
+  let adjusted = await program.account.offer.fetch(adjustedOffer);
+  assert.equal(adjusted.tokenAmount.toNumber(), 400);
+  assert.equal(adjusted.expectedTotalAmount.toNumber(), 134);
+
+  // 350 * 134 / 400 = 117.25
+  await program.methods.decreaseOffer(new anchor.BN(50)).accountsPartial(adjustAccounts).signers([maker]).rpc();
+  adjusted = await program.account.offer.fetch(adjustedOffer);
+  assert.equal(adjusted.tokenAmount.toNumber(), 350);
+  assert.equal(adjusted.expectedTotalAmount.toNumber(), 118);
+});
+it("expiring a delegated offer revokes its delegation on the maker's account", async () => {
+  const delegatedOffer = offerAddress(402701);
+  await createTestOffer(402701, 300, 150, { custody: { delegate: {} }, deadline: now() + 3 });
+  assert.equal(
+    (await getAccount(connection, makerTokenAccount.address, 'confirmed')).delegate.toBase58(),
+    delegatedOffer.toBase58()
+  );
+
+  await sleep(5000);
+  await program.methods.expireOffer()
+    .accountsPartial({ ...expireAccounts(delegatedOffer, maker.publicKey), vaultTokenAccount: null })
+    .signers([maker])
+    .rpc();
+
+  const source = await getAccount(connection, makerTokenAccount.address, 'confirmed');
+  assert.isNull(source.delegate, "Delegation should be revoked");
+  assert.equal(Number(source.delegatedAmount), 0);
+  const offerAccount = await program.account.offer.fetch(delegatedOffer);
+  assert.equal(JSON.stringify(offerAccount.status), JSON.stringify({ expired: {} }));
+});
+// Enabling the multisig can't be undone, so this test has to stay last
+it("admin multisig proposals need the threshold of current members", async () => {
+  const signer2 = Keypair.generate();
+  const signer3 = Keypair.generate();
+  const outsider = Keypair.generate();
+  const feeManager = Keypair.generate();
+
+  await program.methods.grantRole({ feeManager: {} }, feeManager.publicKey)
+    .accounts({ admin: admin.publicKey })
+    .signers([admin])
+    .rpc();
+  await program.methods.enableAdminMultisig([admin.publicKey, signer2.publicKey, signer3.publicKey], 2)
+    .accounts({ admin: admin.publicKey })
+    .signers([admin])
+    .rpc();
+
+  // Enabling the multisig clears every role, a single key can't change the fee alone
+  const adminRoles = PublicKey.findProgramAddressSync([Buffer.from('admin_roles')], program.programId)[0];
+  const roles = await program.account.adminRoles.fetch(adminRoles);
+  assert.isNull(roles.feeManager);
+  assert.isNull(roles.listingManager);
+  assert.isNull(roles.pauser);
+  for (const signer of [feeManager, admin]) {
+    await expectAnchorError(
+      program.methods.updateFeePercentage(new anchor.BN(100))
+        .accounts({ admin: signer.publicKey })
+        .signers([signer])
+        .rpc(),
+      'UnauthorizedAdmin'
+    );
+  }
+
+  const proposalAddress = (id: anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('admin_proposal'), Buffer.from(id.toArray('le', 8))],
+      program.programId
+    )[0];
+
+  const propose = async (action: any) => {
+    const { proposalCount } = await program.account.adminConfig.fetch(adminConfig);
+    const proposal = proposalAddress(proposalCount);
+    await program.methods.createAdminProposal(action)
+      .accountsPartial({ proposer: admin.publicKey, proposal })
+      .signers([admin])
+      .rpc();
+    return proposal;
+  };
+  const approve = (proposal: PublicKey, signer: Keypair) =>
+    program.methods.approveAdminProposal()
+      .accountsPartial({ signer: signer.publicKey, proposal })
+      .signers([signer])
+      .rpc();
+  const execute = (proposal: PublicKey) =>
+    program.methods.executeAdminProposal()
+      .accountsPartial({
+        executor: admin.publicKey,
+        proposal,
+        takerWhitelistConfig: null,
+        expiryConfig: null,
+        adminRoles: null,
+      })
+      .signers([admin])
+      .rpc();
+
+  const feeProposal = await propose({ updateFee: { newFee: new anchor.BN(250) } });
+
+  // Only the proposer approved so far
+  await expectAnchorError(execute(feeProposal), 'ProposalThresholdNotMet');
+  await expectAnchorError(approve(feeProposal, outsider), 'NotMultisigSigner');
+  await expectAnchorError(approve(feeProposal, admin), 'ProposalAlreadyApproved');
+  await approve(feeProposal, signer2);
+
+  // Removing signer2 drops its approval below the threshold
+  const multisigProposal = await propose({ setMultisig: { signers: [admin.publicKey, signer3.publicKey], threshold: 2 } });
+  await approve(multisigProposal, signer3);
+  await execute(multisigProposal);
+  assert.deepEqual(
+    (await program.account.adminConfig.fetch(adminConfig)).signers.map((key) => key.toBase58()),
+    [admin.publicKey.toBase58(), signer3.publicKey.toBase58()]
+  );
+  await expectAnchorError(execute(feeProposal), 'ProposalThresholdNotMet');
+
+  await approve(feeProposal, signer3);
+  await execute(feeProposal);
+  assert.equal((await program.account.feeConfig.fetch(feeConfig)).feePercentage.toNumber(), 250);
+  await expectAnchorError(execute(feeProposal), 'ProposalAlreadyExecuted');
+});
 });
\ No newline at end of file